@@ -53,6 +53,17 @@ impl CfgEvaluator for CargoEnvCfgEvaluator {
     }
 }
 
+// cxx-qt-gen has a matching trait, so that cfgs are evaluated the same for the CXX-Qt generated C++
+impl cxx_qt_gen::CfgEvaluator for CargoEnvCfgEvaluator {
+    fn eval(&self, name: &str, query_value: Option<&str>) -> cxx_qt_gen::CfgResult {
+        match CfgEvaluator::eval(self, name, query_value) {
+            CfgResult::True => cxx_qt_gen::CfgResult::True,
+            CfgResult::False => cxx_qt_gen::CfgResult::False,
+            CfgResult::Undetermined { msg } => cxx_qt_gen::CfgResult::Undetermined { msg },
+        }
+    }
+}
+
 impl CargoEnv {
     fn load() -> Self {
         const CARGO_FEATURE_PREFIX: &str = "CARGO_FEATURE_";
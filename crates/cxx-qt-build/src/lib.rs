@@ -32,7 +32,6 @@ pub use qml_modules::QmlModule;
 
 pub use qt_build_utils::MocArguments;
 use qt_build_utils::SemVer;
-use quote::ToTokens;
 use std::{
     collections::HashSet,
     env,
@@ -41,10 +40,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use cxx_qt_gen::{
-    parse_qt_file, write_cpp, write_rust, CppFragment, CxxQtItem, GeneratedCppBlocks,
-    GeneratedRustBlocks, Parser,
-};
+use cxx_qt_gen::{generate_file, parse_qt_file, BridgeDescription, CppFragment, GeneratedOpt};
 
 // TODO: we need to eventually support having multiple modules defined in a single file. This
 // is currently an issue because we are using the Rust file name to derive the cpp file name
@@ -63,6 +59,7 @@ struct GeneratedCppFilePaths {
 struct GeneratedCpp {
     cxx_qt: Option<CppFragment>,
     cxx: cxx_gen::GeneratedCode,
+    description: Option<BridgeDescription>,
     file_ident: String,
 }
 
@@ -81,14 +78,6 @@ impl GeneratedCpp {
             .map_err(GeneratedError::from)
             .map_err(to_diagnostic)?;
 
-        let mut cxx_qt = None;
-        let mut tokens = proc_macro2::TokenStream::new();
-
-        // Add any attributes in the file into the tokenstream
-        for attr in &file.attrs {
-            tokens.extend(attr.into_token_stream());
-        }
-
         // Match upstream where they use the file name and folders as the ident
         //
         // We need the relative path here as we want the folders
@@ -102,66 +91,23 @@ impl GeneratedCpp {
         // The include path we inject needs any prefix (eg the crate name) too
         let include_ident = format!("{include_prefix}/{file_ident}");
 
-        // Loop through the items looking for any CXX or CXX-Qt blocks
-        let mut found_bridge = false;
-        for item in &file.items {
-            match item {
-                CxxQtItem::Cxx(m) => {
-                    // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
-                    if found_bridge {
-                        panic!(
-                            "Unfortunately only files with either a single cxx or a single cxx_qt module are currently supported.
-                            The file {} has more than one of these.",
-                            rust_file_path.display());
-                    }
-                    found_bridge = true;
-
-                    tokens.extend(m.into_token_stream());
-                }
-                CxxQtItem::CxxQt(m) => {
-                    // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
-                    if found_bridge {
-                        panic!(
-                            "Unfortunately only files with either a single cxx or a single cxx_qt module are currently supported.
-                            The file {} has more than one of these.",
-                            rust_file_path.display());
-                    }
-                    found_bridge = true;
-
-                    let parser = Parser::from(m.clone())
-                        .map_err(GeneratedError::from)
-                        .map_err(to_diagnostic)?;
-                    let generated_cpp = GeneratedCppBlocks::from(&parser)
-                        .map_err(GeneratedError::from)
-                        .map_err(to_diagnostic)?;
-                    let generated_rust = GeneratedRustBlocks::from(&parser)
-                        .map_err(GeneratedError::from)
-                        .map_err(to_diagnostic)?;
-
-                    // TODO: we'll have to extend the C++ data here rather than overwriting
-                    // assuming we share the same file
-                    cxx_qt = Some(write_cpp(&generated_cpp, &include_ident));
-                    let rust_tokens = write_rust(&generated_rust, Some(&include_ident));
-
-                    // We need to do this and can't rely on the macro, as we need to generate the
-                    // CXX bridge Rust code that is then fed into the cxx_gen generation.
-                    tokens.extend(rust_tokens);
-                }
-                CxxQtItem::Item(item) => {
-                    tokens.extend(item.into_token_stream());
-                }
-            }
-        }
+        // Evaluate any cfgs in the bridge in the same way as cxx_gen
+        let mut cxx_qt_opt = GeneratedOpt::default();
+        cxx_qt_opt.cfg_evaluator = Some(Box::new(cfg_evaluator::CargoEnvCfgEvaluator));
+        let generated = generate_file(&file, &include_ident, &cxx_qt_opt)
+            .map_err(GeneratedError::from)
+            .map_err(to_diagnostic)?;
 
         let mut opt = cxx_gen::Opt::default();
         opt.cfg_evaluator = Box::new(cfg_evaluator::CargoEnvCfgEvaluator);
-        let cxx = cxx_gen::generate_header_and_cc(tokens, &opt)
+        let cxx = cxx_gen::generate_header_and_cc(generated.rust, &opt)
             .map_err(GeneratedError::from)
             .map_err(to_diagnostic)?;
 
         Ok(GeneratedCpp {
-            cxx_qt,
+            cxx_qt: generated.cxx_qt,
             cxx,
+            description: generated.description,
             file_ident,
         })
     }
@@ -222,6 +168,19 @@ impl GeneratedCpp {
             cpp_file_paths.qobject = Some(cpp_path);
         }
 
+        // Write a description of the API of the bridge next to the header,
+        // so that other tools can use it without parsing Rust
+        if let Some(description) = &self.description {
+            let json_path = PathBuf::from(format!(
+                "{}/{}.cxxqt.json",
+                header_directory.display(),
+                self.file_ident
+            ));
+            let json = serde_json::to_string_pretty(description)
+                .expect("Could not serialize cxx-qt bridge description");
+            std::fs::write(json_path, json).expect("Could not write cxx-qt bridge description");
+        }
+
         let header_path = PathBuf::from(format!(
             "{}/{}.cxx.h",
             header_directory.display(),
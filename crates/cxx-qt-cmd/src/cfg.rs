@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: CXX Authors
+// SPDX-FileContributor: David Tolnay <dtolnay@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// Modified from
+// https://github.com/dtolnay/cxx/blob/1.0.121/gen/cmd/src/cfg.rs
+
+use cxx_gen::{CfgEvaluator, CfgResult};
+use std::collections::{BTreeMap as Map, BTreeSet as Set};
+use syn::{parse::ParseStream, Ident, LitBool, LitStr, Token};
+
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub(crate) enum CfgValue {
+    Bool(bool),
+    Str(String),
+}
+
+impl CfgValue {
+    const FALSE: Self = CfgValue::Bool(false);
+    const TRUE: Self = CfgValue::Bool(true);
+}
+
+/// Evaluates the cfgs in the bridges from the `--cfg` flags on the command line
+#[derive(Clone, Default)]
+pub(crate) struct FlagsCfgEvaluator {
+    map: Map<String, Set<CfgValue>>,
+}
+
+impl FlagsCfgEvaluator {
+    /// Parse a `--cfg` flag in the form of `name`, `name = true` or `name = "value"`
+    pub(crate) fn insert(&mut self, flag: &str) -> syn::Result<()> {
+        let (name, value) = syn::parse::Parser::parse_str(parse, flag)?;
+        self.map.entry(name).or_default().insert(value);
+        Ok(())
+    }
+}
+
+impl CfgEvaluator for FlagsCfgEvaluator {
+    fn eval(&self, name: &str, value: Option<&str>) -> CfgResult {
+        let set = self.map.get(name);
+        if let Some(value) = value {
+            if let Some(set) = set {
+                CfgResult::from(set.contains(&CfgValue::Str(value.to_owned())))
+            } else if name == "feature" {
+                CfgResult::False
+            } else {
+                let msg = format!("pass `--cfg {name}=\"...\"` to be able to use this attribute");
+                CfgResult::Undetermined { msg }
+            }
+        } else {
+            let (mut is_false, mut is_true) = (false, false);
+            if let Some(set) = set {
+                is_false = set.contains(&CfgValue::FALSE);
+                is_true = set.contains(&CfgValue::TRUE);
+            }
+            if is_false && is_true {
+                let msg = format!("the cfg flags say both {name}=false and {name}=true");
+                CfgResult::Undetermined { msg }
+            } else if is_false {
+                CfgResult::False
+            } else if is_true {
+                CfgResult::True
+            } else {
+                let msg = format!(
+                    "pass either `--cfg {name}=true` or `--cfg {name}=false` to be able to use this cfg attribute"
+                );
+                CfgResult::Undetermined { msg }
+            }
+        }
+    }
+}
+
+// cxx-qt-gen has a matching trait, so that cfgs are evaluated the same for the CXX-Qt generated C++
+impl cxx_qt_gen::CfgEvaluator for FlagsCfgEvaluator {
+    fn eval(&self, name: &str, query_value: Option<&str>) -> cxx_qt_gen::CfgResult {
+        match CfgEvaluator::eval(self, name, query_value) {
+            CfgResult::True => cxx_qt_gen::CfgResult::True,
+            CfgResult::False => cxx_qt_gen::CfgResult::False,
+            CfgResult::Undetermined { msg } => cxx_qt_gen::CfgResult::Undetermined { msg },
+        }
+    }
+}
+
+fn parse(input: ParseStream) -> syn::Result<(String, CfgValue)> {
+    let ident: Ident = input.parse()?;
+    let name = ident.to_string();
+    if input.is_empty() {
+        return Ok((name, CfgValue::TRUE));
+    }
+    input.parse::<Token![=]>()?;
+    let lookahead = input.lookahead1();
+    if lookahead.peek(LitBool) {
+        let lit: LitBool = input.parse()?;
+        Ok((name, CfgValue::Bool(lit.value)))
+    } else if lookahead.peek(LitStr) {
+        let lit: LitStr = input.parse()?;
+        Ok((name, CfgValue::Str(lit.value())))
+    } else {
+        Err(lookahead.error())
+    }
+}
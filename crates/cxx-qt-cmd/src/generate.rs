@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use cxx_qt_gen::{generate_file, parse_qt_file, BridgeDescription, CppFragment, GeneratedOpt};
+use proc_macro2::Span;
+
+use crate::cfg::FlagsCfgEvaluator;
+
+/// The CXX and CXX-Qt errors which can occur while generating a bridge
+#[derive(Debug)]
+pub(crate) enum GeneratedError {
+    Cxx(cxx_gen::Error),
+    CxxQt(cxx_qt_gen::Error),
+    Io(std::io::Error),
+}
+
+impl Display for GeneratedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeneratedError::Cxx(err) => write!(f, "{err}"),
+            GeneratedError::CxxQt(err) => write!(f, "{err}"),
+            GeneratedError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<cxx_gen::Error> for GeneratedError {
+    fn from(err: cxx_gen::Error) -> Self {
+        Self::Cxx(err)
+    }
+}
+
+impl From<cxx_qt_gen::Error> for GeneratedError {
+    fn from(err: cxx_qt_gen::Error) -> Self {
+        Self::CxxQt(err)
+    }
+}
+
+impl From<std::io::Error> for GeneratedError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl GeneratedError {
+    /// Print the error to stderr, with the line and column of the span in the given file
+    pub(crate) fn report(self, file_path: &Path) {
+        let errors: Vec<(Option<Span>, String)> = match self {
+            GeneratedError::Cxx(err) => err
+                .into_iter()
+                .map(|err| (err.span(), err.to_string()))
+                .collect(),
+            GeneratedError::CxxQt(err) => err
+                .into_iter()
+                .map(|err| (Some(err.span()), err.to_string()))
+                .collect(),
+            GeneratedError::Io(err) => vec![(None, err.to_string())],
+        };
+
+        for (span, message) in errors {
+            if let Some(span) = span {
+                let start = span.start();
+                // Columns from proc-macro2 start at zero, but editors start at one
+                eprintln!(
+                    "{}:{}:{}: error: {message}",
+                    file_path.display(),
+                    start.line,
+                    start.column + 1
+                );
+            } else {
+                eprintln!("{}: error: {message}", file_path.display());
+            }
+        }
+    }
+}
+
+/// The generated code of one Rust file, containing at most one CXX or CXX-Qt bridge
+pub(crate) struct GeneratedBridge {
+    cxx_qt: Option<CppFragment>,
+    cxx: cxx_gen::GeneratedCode,
+    description: Option<BridgeDescription>,
+    rust: proc_macro2::TokenStream,
+    file_ident: String,
+}
+
+/// The paths of the files which have been written for a bridge
+pub(crate) struct GeneratedFilePaths {
+    /// All of the files which have been written
+    pub(crate) outputs: Vec<PathBuf>,
+    /// The CXX-Qt header, which needs to be processed by moc
+    pub(crate) moc_header: Option<PathBuf>,
+}
+
+impl GeneratedBridge {
+    /// Generate the C++ and Rust code of the bridge in the given file
+    ///
+    /// The file_ident is used for the names of the generated files, which are included as
+    /// `include_prefix/file_ident.cxxqt.h`, matching how CxxQtBuilder names them.
+    pub(crate) fn new(
+        rust_file_path: &Path,
+        file_ident: String,
+        include_prefix: &str,
+        cfg_evaluator: &FlagsCfgEvaluator,
+    ) -> Result<Self, GeneratedError> {
+        let file = parse_qt_file(rust_file_path)?;
+
+        let include_ident = if include_prefix.is_empty() {
+            file_ident.clone()
+        } else {
+            format!("{include_prefix}/{file_ident}")
+        };
+
+        let mut cxx_qt_opt = GeneratedOpt::default();
+        cxx_qt_opt.cfg_evaluator = Some(Box::new(cfg_evaluator.clone()));
+        let generated = generate_file(&file, &include_ident, &cxx_qt_opt)?;
+
+        let mut opt = cxx_gen::Opt::default();
+        opt.cfg_evaluator = Box::new(cfg_evaluator.clone());
+        let cxx = cxx_gen::generate_header_and_cc(generated.rust.clone(), &opt)?;
+
+        Ok(Self {
+            cxx_qt: generated.cxx_qt,
+            cxx,
+            description: generated.description,
+            rust: generated.rust,
+            file_ident,
+        })
+    }
+
+    /// Write the generated files into the given directories
+    ///
+    /// Headers are written to `header_dir/include_prefix`, so that `header_dir` can be used as
+    /// an include directory. The Rust code is only written when a `rust_dir` is given.
+    pub(crate) fn write(
+        self,
+        cpp_dir: &Path,
+        header_dir: &Path,
+        rust_dir: Option<&Path>,
+        include_prefix: &str,
+    ) -> Result<GeneratedFilePaths, GeneratedError> {
+        let header_dir = header_dir.join(include_prefix);
+        let mut paths = GeneratedFilePaths {
+            outputs: vec![],
+            moc_header: None,
+        };
+
+        if let Some(cxx_qt) = &self.cxx_qt {
+            let (header, source) = match cxx_qt {
+                CppFragment::Pair { header, source } => (header.as_str(), source.as_str()),
+                CppFragment::Header(header) => (header.as_str(), ""),
+                CppFragment::Source(source) => ("", source.as_str()),
+            };
+
+            let header_path = header_dir.join(format!("{}.cxxqt.h", self.file_ident));
+            write_file(&header_path, header.as_bytes())?;
+            paths.moc_header = Some(header_path.clone());
+            paths.outputs.push(header_path);
+
+            let cpp_path = cpp_dir.join(format!("{}.cxxqt.cpp", self.file_ident));
+            write_file(&cpp_path, source.as_bytes())?;
+            paths.outputs.push(cpp_path);
+        }
+
+        if let Some(description) = &self.description {
+            let json_path = header_dir.join(format!("{}.cxxqt.json", self.file_ident));
+            let json = serde_json::to_string_pretty(description).map_err(std::io::Error::from)?;
+            write_file(&json_path, json.as_bytes())?;
+            paths.outputs.push(json_path);
+        }
+
+        let header_path = header_dir.join(format!("{}.cxx.h", self.file_ident));
+        write_file(&header_path, &self.cxx.header)?;
+        paths.outputs.push(header_path);
+
+        let cpp_path = cpp_dir.join(format!("{}.cxx.cpp", self.file_ident));
+        write_file(&cpp_path, &self.cxx.implementation)?;
+        paths.outputs.push(cpp_path);
+
+        if let Some(rust_dir) = rust_dir {
+            let rust_path = rust_dir.join(format!("{}.cxxqt.rs", self.file_ident));
+            write_file(&rust_path, self.rust.to_string().as_bytes())?;
+            paths.outputs.push(rust_path);
+        }
+
+        Ok(paths)
+    }
+}
+
+/// Write the contents to the path, creating any missing parent directories
+pub(crate) fn write_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+
+    std::fs::write(path, contents)
+}
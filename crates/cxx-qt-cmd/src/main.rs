@@ -0,0 +1,322 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! `cxxqtbridge` generates the C++ and Rust code of CXX-Qt bridges outside of Cargo,
+//! so that build systems such as Meson or Bazel can invoke the generation directly.
+//!
+//! This is the equivalent of `cxxbridge` for CXX-Qt bridges, it generates the same files
+//! that `CxxQtBuilder` generates in a `build.rs`.
+
+mod cfg;
+mod generate;
+
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use cfg::FlagsCfgEvaluator;
+use generate::{write_file, GeneratedBridge, GeneratedError};
+
+const USAGE: &str = r#"Generate the C++ and Rust code of CXX-Qt bridges
+
+Usage: cxxqtbridge [OPTIONS] <INPUT.rs>...
+
+Options:
+      --cpp-dir <DIR>          Directory for the generated C++ sources [default: .]
+      --header-dir <DIR>       Directory for the generated C++ headers and the JSON descriptions of
+                               the bridges, which are written into <DIR>/<PREFIX> so that <DIR> can
+                               be an include path [default: .]
+      --rust-dir <DIR>         Directory for the generated Rust code, which is not written if missing
+      --include-prefix <PREFIX>
+                               Prefix of the include paths of the generated headers
+      --source-root <DIR>      The generated files are named after the path of each input
+                               relative to this directory [default: .]
+      --cfg <NAME[=VALUE]>     Enable a cfg when evaluating #[cfg] attributes in the bridges,
+                               eg --cfg 'feature="a"' or --cfg qt6=true
+      --moc-list <FILE>        Write the paths of the generated headers which need to be
+                               processed by moc (and qmltyperegistrar for QML modules), one per line
+      --depfile <FILE>         Write a Makefile style dependency file of the outputs on the inputs
+  -h, --help                   Print help
+"#;
+
+/// The options which are passed on the command line
+#[derive(Default)]
+struct Opts {
+    inputs: Vec<PathBuf>,
+    cpp_dir: Option<PathBuf>,
+    header_dir: Option<PathBuf>,
+    rust_dir: Option<PathBuf>,
+    include_prefix: String,
+    source_root: Option<PathBuf>,
+    cfg_evaluator: FlagsCfgEvaluator,
+    moc_list: Option<PathBuf>,
+    depfile: Option<PathBuf>,
+}
+
+impl Opts {
+    fn parse(args: impl IntoIterator<Item = OsString>) -> Result<Option<Self>, String> {
+        let mut opts = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let arg_str = arg.to_string_lossy();
+            if arg_str == "-h" || arg_str == "--help" {
+                return Ok(None);
+            }
+
+            if !arg_str.starts_with("--") {
+                opts.inputs.push(PathBuf::from(arg));
+                continue;
+            }
+
+            // Support both --flag value and --flag=value
+            let (flag, value) = if let Some((flag, value)) = arg_str.split_once('=') {
+                (flag.to_owned(), OsString::from(value))
+            } else {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for {arg_str}"))?;
+                (arg_str.into_owned(), value)
+            };
+
+            match flag.as_str() {
+                "--cpp-dir" => opts.cpp_dir = Some(value.into()),
+                "--header-dir" => opts.header_dir = Some(value.into()),
+                "--rust-dir" => opts.rust_dir = Some(value.into()),
+                "--include-prefix" => opts.include_prefix = value.to_string_lossy().into_owned(),
+                "--source-root" => opts.source_root = Some(value.into()),
+                "--cfg" => opts
+                    .cfg_evaluator
+                    .insert(&value.to_string_lossy())
+                    .map_err(|err| format!("invalid --cfg {}: {err}", value.to_string_lossy()))?,
+                "--moc-list" => opts.moc_list = Some(value.into()),
+                "--depfile" => opts.depfile = Some(value.into()),
+                _others => return Err(format!("unknown option {flag}")),
+            }
+        }
+
+        if opts.inputs.is_empty() {
+            return Err("no input files".to_owned());
+        }
+
+        Ok(Some(opts))
+    }
+
+    /// The name of the generated files for the input, which is its path relative to the
+    /// source root without the .rs extension
+    fn file_ident(&self, input: &Path) -> String {
+        let source_root = self.source_root.as_deref().unwrap_or(Path::new("."));
+        let relative = input
+            .strip_prefix(source_root)
+            .or_else(|_| input.strip_prefix("."))
+            .unwrap_or(input);
+        let relative = if relative.is_absolute() {
+            Path::new(relative.file_name().unwrap_or_default())
+        } else {
+            relative
+        };
+
+        relative
+            .with_extension("")
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+}
+
+/// Escape a path for a Makefile style dependency file
+fn escape_depfile_path(path: &Path) -> String {
+    path.to_string_lossy()
+        .replace('\\', "/")
+        .replace(' ', "\\ ")
+        .replace('#', "\\#")
+        .replace('$', "$$")
+}
+
+fn run(opts: Opts) -> Result<(), (PathBuf, GeneratedError)> {
+    let cpp_dir = opts.cpp_dir.as_deref().unwrap_or(Path::new("."));
+    let header_dir = opts.header_dir.as_deref().unwrap_or(Path::new("."));
+
+    let mut outputs = vec![];
+    let mut moc_headers = vec![];
+    for input in &opts.inputs {
+        let generated = GeneratedBridge::new(
+            input,
+            opts.file_ident(input),
+            &opts.include_prefix,
+            &opts.cfg_evaluator,
+        )
+        .and_then(|generated| {
+            generated.write(
+                cpp_dir,
+                header_dir,
+                opts.rust_dir.as_deref(),
+                &opts.include_prefix,
+            )
+        })
+        .map_err(|err| (input.clone(), err))?;
+
+        outputs.extend(generated.outputs);
+        moc_headers.extend(generated.moc_header);
+    }
+
+    if let Some(moc_list) = &opts.moc_list {
+        let contents: String = moc_headers
+            .iter()
+            .map(|header| format!("{}\n", header.display()))
+            .collect();
+        write_file(moc_list, contents.as_bytes()).map_err(|err| (moc_list.clone(), err.into()))?;
+    }
+
+    // Each output gets its own rule, as not all build systems support multiple outputs per rule
+    if let Some(depfile) = &opts.depfile {
+        let inputs = opts
+            .inputs
+            .iter()
+            .map(|input| escape_depfile_path(input))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let contents: String = outputs
+            .iter()
+            .chain(opts.moc_list.iter())
+            .map(|output| format!("{}: {inputs}\n", escape_depfile_path(output)))
+            .collect();
+        write_file(depfile, contents.as_bytes()).map_err(|err| (depfile.clone(), err.into()))?;
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let opts = match Opts::parse(std::env::args_os().skip(1)) {
+        Ok(Some(opts)) => opts,
+        Ok(None) => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(opts) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err((path, err)) => {
+            err.report(&path);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<Opts>, String> {
+        Opts::parse(args.iter().map(OsString::from))
+    }
+
+    #[test]
+    fn test_parse_opts() {
+        let opts = parse(&[
+            "--cpp-dir",
+            "gen/src",
+            "--header-dir=gen/include",
+            "--include-prefix",
+            "my_crate",
+            "--cfg",
+            "feature=\"a\"",
+            "src/lib.rs",
+            "src/other.rs",
+        ])
+        .unwrap()
+        .unwrap();
+        assert_eq!(opts.cpp_dir, Some(PathBuf::from("gen/src")));
+        assert_eq!(opts.header_dir, Some(PathBuf::from("gen/include")));
+        assert_eq!(opts.rust_dir, None);
+        assert_eq!(opts.include_prefix, "my_crate");
+        assert_eq!(
+            opts.inputs,
+            vec![PathBuf::from("src/lib.rs"), PathBuf::from("src/other.rs")]
+        );
+    }
+
+    #[test]
+    fn test_parse_opts_invalid() {
+        assert!(parse(&["--help"]).unwrap().is_none());
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--unknown", "value", "src/lib.rs"]).is_err());
+        assert!(parse(&["src/lib.rs", "--cpp-dir"]).is_err());
+        assert!(parse(&["--cfg", "feature=1", "src/lib.rs"]).is_err());
+    }
+
+    #[test]
+    fn test_file_ident() {
+        let opts = parse(&["--source-root", "crate", "crate/src/lib.rs"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(opts.file_ident(Path::new("crate/src/lib.rs")), "src/lib");
+        assert_eq!(opts.file_ident(Path::new("./src/lib.rs")), "src/lib");
+        assert_eq!(opts.file_ident(Path::new("/absolute/lib.rs")), "lib");
+    }
+
+    #[test]
+    fn test_escape_depfile_path() {
+        assert_eq!(
+            escape_depfile_path(Path::new("my dir/$file#1.h")),
+            "my\\ dir/$$file\\#1.h"
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let dir = std::env::temp_dir().join(format!("cxx-qt-cmd-test-run-{}", std::process::id()));
+        let input = dir.join("src/lib.rs");
+        write_file(
+            &input,
+            br#"
+#[cxx_qt::bridge]
+mod qobject {
+    extern "RustQt" {
+        #[qobject]
+        type MyObject = super::MyObjectRust;
+    }
+}
+
+#[derive(Default)]
+pub struct MyObjectRust;
+"#,
+        )
+        .unwrap();
+
+        let dir_arg = dir.to_string_lossy().into_owned();
+        let input_arg = input.to_string_lossy().into_owned();
+        let opts = parse(&[
+            "--cpp-dir",
+            &format!("{dir_arg}/gen/src"),
+            "--header-dir",
+            &format!("{dir_arg}/gen/include"),
+            "--include-prefix",
+            "my_crate",
+            "--source-root",
+            &dir_arg,
+            &input_arg,
+        ])
+        .unwrap()
+        .unwrap();
+        assert!(run(opts).is_ok());
+
+        let header =
+            std::fs::read_to_string(dir.join("gen/include/my_crate/src/lib.cxxqt.h")).unwrap();
+        assert!(header.contains("class MyObject"));
+        let source = std::fs::read_to_string(dir.join("gen/src/src/lib.cxxqt.cpp")).unwrap();
+        assert!(source.contains("#include \"my_crate/src/lib.cxxqt.h\""));
+        assert!(dir.join("gen/include/my_crate/src/lib.cxx.h").exists());
+        assert!(dir.join("gen/src/src/lib.cxx.cpp").exists());
+        assert!(dir.join("gen/include/my_crate/src/lib.cxxqt.json").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
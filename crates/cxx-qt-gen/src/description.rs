@@ -0,0 +1,761 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A serializable description of the API which a CXX-Qt bridge defines,
+//! for tools such as documentation generators which cannot parse Rust.
+
+use crate::{
+    generator::structuring::{StructuredQObject, Structures},
+    naming::Name,
+    parser::{
+        method::{MethodFields, ParsedQInvokableSpecifiers},
+        qenum::ParsedQEnum,
+        qgadget::ParsedQGadget,
+        AccessSpecifier, Parser,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use serde::{Deserialize, Serialize};
+use syn::{Attribute, Expr, ExprLit, Lit, Meta, Result, ReturnType, Type};
+
+/// The version of the format of the [BridgeDescription], which is increased for breaking changes
+pub const BRIDGE_DESCRIPTION_VERSION: u32 = 1;
+
+/// A description of the QObjects, QGadgets, QEnums and QNamespaces of a CXX-Qt bridge
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BridgeDescription {
+    /// The version of the format of this description, see [BRIDGE_DESCRIPTION_VERSION]
+    pub version: u32,
+    /// The C++ namespace of the bridge
+    pub namespace: Option<String>,
+    /// The QObjects which are defined in Rust
+    pub qobjects: Vec<QObjectDescription>,
+    /// The QGadgets which are defined as shared structs
+    pub qgadgets: Vec<QGadgetDescription>,
+    /// The QEnums of the bridge, including the ones which are declared within a QObject
+    pub qenums: Vec<QEnumDescription>,
+    /// The namespaces which have a `Q_NAMESPACE`
+    pub qnamespaces: Vec<QNamespaceDescription>,
+}
+
+/// A description of a QObject which is defined in Rust
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QObjectDescription {
+    /// The name of the QObject in Rust
+    pub rust_name: String,
+    /// The name of the QObject in C++
+    pub cxx_name: String,
+    /// The C++ namespace of the QObject
+    pub namespace: Option<String>,
+    /// The Rust struct which stores the data of the QObject
+    pub rust_type: String,
+    /// The C++ class which the QObject inherits from
+    pub base_class: String,
+    /// The registration of the QObject with QML, if any
+    pub qml: Option<QmlDescription>,
+    /// The `Q_CLASSINFO` entries of the QObject
+    pub class_info: Vec<ClassInfoDescription>,
+    /// The C++ interfaces which the QObject implements
+    pub interfaces: Vec<String>,
+    /// The doc comments of the QObject
+    pub docs: String,
+    /// The predicates of the `#[cfg(...)]` attributes of the QObject, eg `feature="a"`
+    pub cfgs: Vec<String>,
+    /// The `Q_PROPERTY`s of the QObject
+    pub properties: Vec<PropertyDescription>,
+    /// The signals of the QObject, including inherited ones
+    pub signals: Vec<SignalDescription>,
+    /// The methods of the QObject, which are `Q_INVOKABLE` if they are marked as such
+    pub methods: Vec<MethodDescription>,
+    /// The methods of the base class which are made available in Rust
+    pub inherited_methods: Vec<MethodDescription>,
+}
+
+/// How a QObject is registered with QML
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QmlDescription {
+    /// The name of the type in QML
+    pub name: String,
+    /// Whether the type is `QML_UNCREATABLE`
+    pub uncreatable: bool,
+    /// Whether the type is a `QML_SINGLETON`
+    pub singleton: bool,
+    /// Whether the type is `QML_ANONYMOUS`
+    pub anonymous: bool,
+    /// Whether the type is a `QML_INTERFACE`
+    pub interface: bool,
+    /// The type given to `QML_EXTENDED`
+    pub extended: Option<String>,
+    /// The type given to `QML_FOREIGN`
+    pub foreign: Option<String>,
+    /// The type given to `QML_ATTACHED`
+    pub attached: Option<String>,
+}
+
+/// A `Q_CLASSINFO` entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClassInfoDescription {
+    /// The name of the entry
+    pub name: String,
+    /// The value of the entry
+    pub value: String,
+}
+
+/// A description of a `Q_PROPERTY`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PropertyDescription {
+    /// The name of the property in Rust
+    pub rust_name: String,
+    /// The name of the property in C++ and QML
+    pub cxx_name: String,
+    /// The Rust type of the property
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Whether the property can be written to
+    pub write: bool,
+    /// Whether the property has a notify signal
+    pub notify: bool,
+    /// Whether the property can be reset
+    pub reset: bool,
+    /// Whether the property is `CONSTANT`
+    pub constant: bool,
+    /// Whether the property is `REQUIRED`
+    pub required: bool,
+    /// Whether the property is `FINAL`
+    #[serde(rename = "final")]
+    pub is_final: bool,
+    /// The doc comments of the property
+    pub docs: String,
+    /// The predicates of the `#[cfg(...)]` attributes of the property, eg `feature="a"`
+    pub cfgs: Vec<String>,
+}
+
+/// A description of a parameter of a signal or method
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterDescription {
+    /// The name of the parameter
+    pub name: String,
+    /// The Rust type of the parameter
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+/// A description of a signal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignalDescription {
+    /// The name of the signal in Rust
+    pub rust_name: String,
+    /// The name of the signal in C++ and QML
+    pub cxx_name: String,
+    /// The parameters of the signal, without the self parameter
+    pub parameters: Vec<ParameterDescription>,
+    /// Whether the signal is defined in the base class
+    pub inherit: bool,
+    /// Whether the signal is private, so that it can only be emitted by the QObject itself
+    pub private: bool,
+    /// The doc comments of the signal
+    pub docs: String,
+    /// The predicates of the `#[cfg(...)]` attributes of the signal, eg `feature="a"`
+    pub cfgs: Vec<String>,
+}
+
+/// A description of a method
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MethodDescription {
+    /// The name of the method in Rust
+    pub rust_name: String,
+    /// The name of the method in C++ and QML
+    pub cxx_name: String,
+    /// The parameters of the method, without the self parameter
+    pub parameters: Vec<ParameterDescription>,
+    /// The Rust return type of the method, if any
+    pub return_type: Option<String>,
+    /// Whether the method takes a mutable self, otherwise it is `const` in C++
+    pub mutable: bool,
+    /// Whether the method is safe to call
+    pub safe: bool,
+    /// Whether the method is a `Q_INVOKABLE`
+    pub qinvokable: bool,
+    /// Whether the method is an async invokable, whose result is returned to C++ as a QFuture
+    #[serde(rename = "async")]
+    pub is_async: bool,
+    /// The C++ specifiers of the method, which are any of `final`, `override` and `virtual`
+    pub specifiers: Vec<String>,
+    /// Whether the method is a protected method of the base class, for inherited methods
+    pub protected: bool,
+    /// The doc comments of the method
+    pub docs: String,
+    /// The predicates of the `#[cfg(...)]` attributes of the method, eg `feature="a"`
+    pub cfgs: Vec<String>,
+}
+
+/// A description of a QGadget, which is a shared struct with a `Q_GADGET`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QGadgetDescription {
+    /// The name of the struct in Rust
+    pub rust_name: String,
+    /// The name of the struct in C++
+    pub cxx_name: String,
+    /// The C++ namespace of the struct
+    pub namespace: Option<String>,
+    /// The name of the QML value type, if it is registered with QML
+    pub qml_name: Option<String>,
+    /// The fields of the struct, which are the `Q_PROPERTY`s of the gadget
+    pub fields: Vec<QGadgetFieldDescription>,
+    /// The doc comments of the struct
+    pub docs: String,
+    /// The predicates of the `#[cfg(...)]` attributes of the struct, eg `feature="a"`
+    pub cfgs: Vec<String>,
+}
+
+/// A description of a field of a QGadget
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QGadgetFieldDescription {
+    /// The name of the field in both Rust and C++
+    pub name: String,
+    /// The Rust type of the field
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The doc comments of the field
+    pub docs: String,
+}
+
+/// A description of a QEnum
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QEnumDescription {
+    /// The name of the enum in Rust
+    pub rust_name: String,
+    /// The name of the enum in C++
+    pub cxx_name: String,
+    /// The C++ namespace of the enum
+    pub namespace: Option<String>,
+    /// The QObject which the enum is declared in, otherwise it is in a `Q_NAMESPACE`
+    pub qobject: Option<String>,
+    /// The C++ name of the `Q_FLAG` of the enum, if it is a flag
+    pub flags: Option<String>,
+    /// The variants of the enum
+    pub variants: Vec<QEnumVariantDescription>,
+    /// The doc comments of the enum
+    pub docs: String,
+    /// The predicates of the `#[cfg(...)]` attributes of the enum, eg `feature="a"`
+    pub cfgs: Vec<String>,
+}
+
+/// A description of a variant of a QEnum
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QEnumVariantDescription {
+    /// The name of the variant
+    pub name: String,
+    /// The value of the variant, if it is given explicitly
+    pub value: Option<i128>,
+}
+
+/// A description of a namespace with a `Q_NAMESPACE`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QNamespaceDescription {
+    /// The C++ namespace
+    pub namespace: String,
+    /// Whether the namespace is a `QML_ELEMENT`
+    pub qml_element: bool,
+}
+
+impl BridgeDescription {
+    /// Create a description of the bridge which the [Parser] has parsed
+    pub fn from(parser: &Parser) -> Result<Self> {
+        let structures = Structures::new(&parser.cxx_qt_data)?;
+
+        Ok(Self {
+            version: BRIDGE_DESCRIPTION_VERSION,
+            namespace: parser.cxx_qt_data.namespace.clone(),
+            qobjects: structures
+                .qobjects
+                .iter()
+                .map(QObjectDescription::from)
+                .collect(),
+            qgadgets: parser
+                .cxx_qt_data
+                .qgadgets
+                .iter()
+                .map(QGadgetDescription::from)
+                .collect(),
+            qenums: parser
+                .cxx_qt_data
+                .qenums
+                .iter()
+                .map(QEnumDescription::from)
+                .collect(),
+            qnamespaces: parser
+                .cxx_qt_data
+                .qnamespaces
+                .iter()
+                .map(|qnamespace| QNamespaceDescription {
+                    namespace: qnamespace.namespace.clone(),
+                    qml_element: qnamespace.qml_element,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl From<&StructuredQObject<'_>> for QObjectDescription {
+    fn from(structured: &StructuredQObject) -> Self {
+        let qobject = structured.declaration;
+        Self {
+            rust_name: qobject.name.rust_unqualified().to_string(),
+            cxx_name: qobject.name.cxx_unqualified(),
+            namespace: qobject.name.namespace().map(str::to_owned),
+            rust_type: qobject.rust_type.to_string(),
+            base_class: qobject
+                .base_class
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| "QObject".to_owned()),
+            qml: qobject.qml_metadata.as_ref().map(|qml| QmlDescription {
+                name: qml.name.clone(),
+                uncreatable: qml.uncreatable,
+                singleton: qml.singleton,
+                anonymous: qml.anonymous,
+                interface: qml.interface,
+                extended: qml.extended.as_ref().map(ToString::to_string),
+                foreign: qml.foreign.as_ref().map(ToString::to_string),
+                attached: qml.attached.as_ref().map(ToString::to_string),
+            }),
+            class_info: qobject
+                .class_info
+                .iter()
+                .map(|(name, value)| ClassInfoDescription {
+                    name: name.clone(),
+                    value: value.clone(),
+                })
+                .collect(),
+            interfaces: qobject.interfaces.iter().map(ToString::to_string).collect(),
+            docs: docs_to_string(&qobject.docs),
+            cfgs: cfgs_to_strings(&qobject.cfgs),
+            properties: qobject
+                .properties
+                .iter()
+                .map(|property| PropertyDescription {
+                    rust_name: property.name.rust_unqualified().to_string(),
+                    cxx_name: property.name.cxx_unqualified(),
+                    ty: type_to_string(&property.ty),
+                    write: property.flags.write.is_some(),
+                    notify: property.flags.notify.is_some(),
+                    reset: property.flags.reset.is_some(),
+                    constant: property.flags.constant,
+                    required: property.flags.required,
+                    is_final: property.flags.is_final,
+                    docs: docs_to_string(&property.docs),
+                    cfgs: cfgs_to_strings(&property.cfgs),
+                })
+                .collect(),
+            signals: structured
+                .signals
+                .iter()
+                .map(|signal| SignalDescription {
+                    rust_name: signal.name.rust_unqualified().to_string(),
+                    cxx_name: signal.name.cxx_unqualified(),
+                    parameters: parameters(signal),
+                    inherit: signal.inherit,
+                    private: signal.private || signal.access == AccessSpecifier::Private,
+                    docs: docs_to_string(&signal.docs),
+                    cfgs: cfgs_to_strings(&signal.cfgs),
+                })
+                .collect(),
+            methods: structured
+                .methods
+                .iter()
+                .map(|method| {
+                    let specifiers = [
+                        (ParsedQInvokableSpecifiers::Final, "final"),
+                        (ParsedQInvokableSpecifiers::Override, "override"),
+                        (ParsedQInvokableSpecifiers::Virtual, "virtual"),
+                    ]
+                    .into_iter()
+                    .filter(|(specifier, _)| method.specifiers.contains(specifier))
+                    .map(|(_, name)| name.to_owned())
+                    .collect();
+
+                    MethodDescription {
+                        qinvokable: method.is_qinvokable,
+                        is_async: method.is_async,
+                        specifiers,
+                        ..MethodDescription::new(&method.method_fields, &method.docs, &method.cfgs)
+                    }
+                })
+                .collect(),
+            inherited_methods: structured
+                .inherited_methods
+                .iter()
+                .map(|method| MethodDescription {
+                    protected: method.access == AccessSpecifier::Protected,
+                    ..MethodDescription::new(&method.method_fields, &method.docs, &method.cfgs)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl MethodDescription {
+    fn new(fields: &MethodFields, docs: &[Attribute], cfgs: &[Attribute]) -> Self {
+        Self {
+            rust_name: fields.name.rust_unqualified().to_string(),
+            cxx_name: fields.name.cxx_unqualified(),
+            parameters: parameters(fields),
+            return_type: match &fields.method.sig.output {
+                ReturnType::Default => None,
+                ReturnType::Type(_, ty) => Some(type_to_string(ty)),
+            },
+            mutable: fields.mutable,
+            safe: fields.safe,
+            qinvokable: false,
+            is_async: false,
+            specifiers: vec![],
+            protected: false,
+            docs: docs_to_string(docs),
+            cfgs: cfgs_to_strings(cfgs),
+        }
+    }
+}
+
+impl From<&ParsedQEnum> for QEnumDescription {
+    fn from(qenum: &ParsedQEnum) -> Self {
+        Self {
+            rust_name: qenum.name.rust_unqualified().to_string(),
+            cxx_name: qenum.name.cxx_unqualified(),
+            namespace: qenum.name.namespace().map(str::to_owned),
+            qobject: qenum.qobject.as_ref().map(ToString::to_string),
+            flags: qenum.flags.as_ref().map(Name::cxx_unqualified),
+            variants: qenum
+                .variants
+                .iter()
+                .map(|variant| QEnumVariantDescription {
+                    name: variant.ident.to_string(),
+                    value: variant.discriminant,
+                })
+                .collect(),
+            docs: docs_to_string(&qenum.docs),
+            cfgs: cfgs_to_strings(&qenum.cfgs),
+        }
+    }
+}
+
+impl From<&ParsedQGadget> for QGadgetDescription {
+    fn from(qgadget: &ParsedQGadget) -> Self {
+        Self {
+            rust_name: qgadget.name.rust_unqualified().to_string(),
+            cxx_name: qgadget.name.cxx_unqualified(),
+            namespace: qgadget.name.namespace().map(str::to_owned),
+            qml_name: qgadget.qml_name.clone(),
+            fields: qgadget
+                .fields
+                .iter()
+                .map(|field| QGadgetFieldDescription {
+                    name: field.ident.to_string(),
+                    ty: type_to_string(&field.ty),
+                    docs: docs_to_string(&field.docs),
+                })
+                .collect(),
+            docs: docs_to_string(&qgadget.docs),
+            cfgs: cfgs_to_strings(&qgadget.cfgs),
+        }
+    }
+}
+
+fn parameters(fields: &MethodFields) -> Vec<ParameterDescription> {
+    fields
+        .parameters
+        .iter()
+        .map(|parameter| ParameterDescription {
+            name: parameter.ident.to_string(),
+            ty: type_to_string(&parameter.ty),
+        })
+        .collect()
+}
+
+/// Join the lines of the doc attributes, removing the space which follows `///`
+fn docs_to_string(docs: &[Attribute]) -> String {
+    docs.iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => match &name_value.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) => Some(lit.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').map(str::to_owned).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The predicates of the cfg attributes, which are the tokens within `#[cfg(...)]`
+fn cfgs_to_strings(cfgs: &[Attribute]) -> Vec<String> {
+    cfgs.iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::List(list) => Some(tokens_to_string(&list.tokens)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Format the type as it would be written, as the tokens of the type have spaces between them
+fn type_to_string(ty: &Type) -> String {
+    tokens_to_string(&ty.to_token_stream())
+}
+
+/// Format the tokens without the spaces which are placed between all tokens
+fn tokens_to_string(tokens: &TokenStream) -> String {
+    let tokens = tokens.to_string();
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    let mut output = String::with_capacity(tokens.len());
+    let mut chars = tokens.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ' ' {
+            // Only keep spaces which separate two words, eg `dyn Trait` or `'a T`
+            let previous_is_word = output.chars().last().is_some_and(is_word);
+            let next_is_word = chars.peek().is_some_and(|next| is_word(*next));
+            if !(previous_is_word && next_is_word) {
+                continue;
+            }
+        }
+        output.push(c);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    #[test]
+    fn test_type_to_string() {
+        assert_eq!(type_to_string(&parse_quote! { i32 }), "i32");
+        assert_eq!(
+            type_to_string(&parse_quote! { Pin<&mut MyObject> }),
+            "Pin<&mut MyObject>"
+        );
+        assert_eq!(
+            type_to_string(&parse_quote! { &'a QList<QString> }),
+            "&'a QList<QString>"
+        );
+        assert_eq!(
+            type_to_string(&parse_quote! { UniquePtr<QObject> }),
+            "UniquePtr<QObject>"
+        );
+    }
+
+    #[test]
+    fn test_docs_to_string() {
+        let docs: Vec<Attribute> = vec![
+            parse_quote! { #[doc = " The first line"] },
+            parse_quote! { #[doc = "  indented"] },
+        ];
+        assert_eq!(docs_to_string(&docs), "The first line\n indented");
+    }
+
+    #[test]
+    fn test_bridge_description() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+            mod ffi {
+                #[qenum(MyObject)]
+                enum State {
+                    Idle,
+                    Running = 2,
+                }
+
+                unsafe extern "RustQt" {
+                    /// An object
+                    #[qobject]
+                    #[qml_element = "Object"]
+                    #[qproperty(i32, number, cxx_name = "numberValue")]
+                    #[qproperty(QString, name, READ, CONSTANT)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>, value: &QString);
+
+                    /// Say hello
+                    #[qinvokable]
+                    #[cxx_virtual]
+                    fn hello(self: &MyObject, name: &QString) -> QString;
+
+                    #[inherit(protected)]
+                    fn children(self: &MyObject) -> bool;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let description = BridgeDescription::from(&parser).unwrap();
+
+        assert_eq!(description.version, BRIDGE_DESCRIPTION_VERSION);
+        assert_eq!(description.namespace.as_deref(), Some("cxx_qt::my_object"));
+        assert!(description.qnamespaces.is_empty());
+
+        assert_eq!(description.qobjects.len(), 1);
+        let qobject = &description.qobjects[0];
+        assert_eq!(qobject.rust_name, "MyObject");
+        assert_eq!(qobject.rust_type, "MyObjectRust");
+        assert_eq!(qobject.base_class, "QObject");
+        assert_eq!(qobject.namespace.as_deref(), Some("cxx_qt::my_object"));
+        assert_eq!(qobject.docs, "An object");
+        assert_eq!(qobject.qml.as_ref().unwrap().name, "Object");
+
+        assert_eq!(qobject.properties.len(), 2);
+        assert_eq!(qobject.properties[0].rust_name, "number");
+        assert_eq!(qobject.properties[0].cxx_name, "numberValue");
+        assert_eq!(qobject.properties[0].ty, "i32");
+        assert!(qobject.properties[0].write);
+        assert!(!qobject.properties[1].write);
+        assert!(qobject.properties[1].constant);
+
+        assert_eq!(qobject.signals.len(), 1);
+        assert_eq!(qobject.signals[0].cxx_name, "ready");
+        assert!(!qobject.signals[0].private);
+        assert_eq!(
+            qobject.signals[0].parameters,
+            vec![ParameterDescription {
+                name: "value".to_owned(),
+                ty: "&QString".to_owned()
+            }]
+        );
+
+        assert_eq!(qobject.methods.len(), 1);
+        let method = &qobject.methods[0];
+        assert_eq!(method.rust_name, "hello");
+        assert_eq!(method.return_type.as_deref(), Some("QString"));
+        assert!(method.qinvokable);
+        assert!(!method.mutable);
+        assert_eq!(method.specifiers, vec!["virtual".to_owned()]);
+        assert_eq!(method.docs, "Say hello");
+
+        assert_eq!(qobject.inherited_methods.len(), 1);
+        assert_eq!(qobject.inherited_methods[0].rust_name, "children");
+        assert!(qobject.inherited_methods[0].protected);
+        assert!(!qobject.inherited_methods[0].qinvokable);
+
+        assert_eq!(description.qenums.len(), 1);
+        let qenum = &description.qenums[0];
+        assert_eq!(qenum.qobject.as_deref(), Some("MyObject"));
+        assert_eq!(
+            qenum.variants,
+            vec![
+                QEnumVariantDescription {
+                    name: "Idle".to_owned(),
+                    value: None
+                },
+                QEnumVariantDescription {
+                    name: "Running".to_owned(),
+                    value: Some(2)
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cfgs_to_strings() {
+        let cfgs: Vec<Attribute> = vec![
+            parse_quote! { #[cfg(feature = "a")] },
+            parse_quote! { #[cfg(not(any(unix, windows)))] },
+        ];
+        assert_eq!(
+            cfgs_to_strings(&cfgs),
+            vec![
+                "feature=\"a\"".to_owned(),
+                "not(any(unix,windows))".to_owned()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bridge_description_qgadgets_and_cfgs() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                /// A point
+                #[qgadget]
+                #[qml_element]
+                #[cfg(feature = "gadget")]
+                struct Point {
+                    /// The x coordinate
+                    x: i32,
+                    y: QString,
+                }
+
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[cfg(feature = "object")]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    #[cfg(feature = "signal")]
+                    fn ready(self: Pin<&mut MyObject>);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let description = BridgeDescription::from(&parser).unwrap();
+
+        assert_eq!(
+            description.qgadgets,
+            vec![QGadgetDescription {
+                rust_name: "Point".to_owned(),
+                cxx_name: "Point".to_owned(),
+                namespace: None,
+                qml_name: Some("point".to_owned()),
+                fields: vec![
+                    QGadgetFieldDescription {
+                        name: "x".to_owned(),
+                        ty: "i32".to_owned(),
+                        docs: "The x coordinate".to_owned(),
+                    },
+                    QGadgetFieldDescription {
+                        name: "y".to_owned(),
+                        ty: "QString".to_owned(),
+                        docs: String::new(),
+                    },
+                ],
+                docs: "A point".to_owned(),
+                cfgs: vec!["feature=\"gadget\"".to_owned()],
+            }]
+        );
+
+        let qobject = &description.qobjects[0];
+        assert_eq!(qobject.cfgs, vec!["feature=\"object\"".to_owned()]);
+        assert_eq!(
+            qobject.signals[0].cfgs,
+            vec!["feature=\"signal\"".to_owned()]
+        );
+    }
+
+    #[test]
+    fn test_bridge_description_json() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let description = BridgeDescription::from(&parser).unwrap();
+
+        let json = serde_json::to_string(&description).unwrap();
+        assert_eq!(
+            json,
+            r#"{"version":1,"namespace":null,"qobjects":[{"rust_name":"MyObject","cxx_name":"MyObject","namespace":null,"rust_type":"MyObjectRust","base_class":"QObject","qml":null,"class_info":[],"interfaces":[],"docs":"","cfgs":[],"properties":[],"signals":[],"methods":[],"inherited_methods":[]}],"qgadgets":[],"qenums":[],"qnamespaces":[]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<BridgeDescription>(&json).unwrap(),
+            description
+        );
+    }
+}
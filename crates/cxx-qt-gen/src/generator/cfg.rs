@@ -0,0 +1,226 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::{expr::expr_to_string, path::path_compare_str};
+use syn::{punctuated::Punctuated, Attribute, Error, Meta, Result, Token};
+
+/// The result of evaluating a single cfg option, such as `feature = "qml"`
+#[derive(Debug, PartialEq, Eq)]
+pub enum CfgResult {
+    /// The cfg option is enabled
+    True,
+    /// The cfg option is disabled
+    False,
+    /// The cfg option could not be evaluated, the message describes why
+    Undetermined {
+        /// The reason that the cfg option could not be evaluated
+        msg: String,
+    },
+}
+
+impl From<bool> for CfgResult {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+/// Evaluates the `#[cfg(...)]` attributes in a bridge, so that the C++ code is only generated for
+/// the items which are enabled in the Rust code.
+///
+/// This matches the `CfgEvaluator` of `cxx-gen`, so that both generators can share an implementation.
+pub trait CfgEvaluator {
+    /// Evaluate a cfg option with the given name and an optional value,
+    /// for example `feature = "qml"` has the name `feature` and the value `qml`.
+    fn eval(&self, name: &str, value: Option<&str>) -> CfgResult;
+}
+
+/// Options for the C++ code generation
+#[derive(Default)]
+#[non_exhaustive]
+pub struct GeneratedOpt {
+    /// The evaluator of any `#[cfg(...)]` attributes in the bridge
+    ///
+    /// When this is `None` the attributes are not evaluated and C++ is generated for every item.
+    pub cfg_evaluator: Option<Box<dyn CfgEvaluator>>,
+}
+
+/// Returns whether all of the `#[cfg(...)]` attributes in the given list are enabled
+///
+/// Without a [CfgEvaluator] every item is treated as enabled.
+pub(crate) fn try_eval_attributes(
+    cfg_evaluator: Option<&dyn CfgEvaluator>,
+    attrs: &[Attribute],
+) -> Result<bool> {
+    let Some(cfg_evaluator) = cfg_evaluator else {
+        return Ok(true);
+    };
+
+    for attr in attrs
+        .iter()
+        .filter(|attr| path_compare_str(attr.meta.path(), &["cfg"]))
+    {
+        let meta = attr.parse_args::<Meta>()?;
+        if !try_eval_meta(cfg_evaluator, &meta)? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Returns true if the bridge is known to be built against the given major version of Qt
+///
+/// This uses the `cxxqt_qt_version_major` cfg which is set by cxx-qt-build,
+/// without it the version of Qt is unknown and false is returned.
+pub(crate) fn is_qt_version_major(cfg_evaluator: Option<&dyn CfgEvaluator>, major: &str) -> bool {
+    cfg_evaluator.is_some_and(|cfg_evaluator| {
+        cfg_evaluator.eval("cxxqt_qt_version_major", Some(major)) == CfgResult::True
+    })
+}
+
+/// Returns the items whose `#[cfg(...)]` attributes are all enabled
+pub(crate) fn try_filter_cfgs<'a, T: 'a>(
+    cfg_evaluator: Option<&dyn CfgEvaluator>,
+    items: impl IntoIterator<Item = &'a T>,
+    cfgs: impl Fn(&T) -> &[Attribute],
+) -> Result<Vec<&'a T>> {
+    let mut enabled = vec![];
+    for item in items {
+        if try_eval_attributes(cfg_evaluator, cfgs(item))? {
+            enabled.push(item);
+        }
+    }
+    Ok(enabled)
+}
+
+/// Evaluate a single cfg predicate, which could be a combination of other predicates
+fn try_eval_meta(cfg_evaluator: &dyn CfgEvaluator, meta: &Meta) -> Result<bool> {
+    match meta {
+        Meta::List(list) => {
+            let predicates =
+                list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            let mut results = predicates
+                .iter()
+                .map(|predicate| try_eval_meta(cfg_evaluator, predicate));
+
+            if list.path.is_ident("all") {
+                results.try_fold(true, |all, result| Ok(all && result?))
+            } else if list.path.is_ident("any") {
+                results.try_fold(false, |any, result| Ok(any || result?))
+            } else if list.path.is_ident("not") {
+                if predicates.len() != 1 {
+                    return Err(Error::new_spanned(
+                        list,
+                        "cfg(not(...)) expects exactly one predicate!",
+                    ));
+                }
+                Ok(!try_eval_meta(cfg_evaluator, &predicates[0])?)
+            } else {
+                Err(Error::new_spanned(
+                    &list.path,
+                    "Unsupported cfg predicate, expected all, any or not!",
+                ))
+            }
+        }
+        Meta::Path(path) => {
+            let name = path.require_ident()?.to_string();
+            cfg_result_to_bool(cfg_evaluator.eval(&name, None), meta)
+        }
+        Meta::NameValue(name_value) => {
+            let name = name_value.path.require_ident()?.to_string();
+            let value = expr_to_string(&name_value.value)?;
+            cfg_result_to_bool(cfg_evaluator.eval(&name, Some(&value)), meta)
+        }
+    }
+}
+
+fn cfg_result_to_bool(result: CfgResult, meta: &Meta) -> Result<bool> {
+    match result {
+        CfgResult::True => Ok(true),
+        CfgResult::False => Ok(false),
+        CfgResult::Undetermined { msg } => Err(Error::new_spanned(meta, msg)),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    /// A [CfgEvaluator] for tests where only `feature = "enabled"` is enabled
+    pub struct MockCfgEvaluator;
+
+    impl CfgEvaluator for MockCfgEvaluator {
+        fn eval(&self, name: &str, value: Option<&str>) -> CfgResult {
+            match (name, value) {
+                ("feature", Some(value)) => CfgResult::from(value == "enabled"),
+                ("unknown", _) => CfgResult::Undetermined {
+                    msg: "unknown cfg".to_owned(),
+                },
+                _ => CfgResult::False,
+            }
+        }
+    }
+
+    fn eval(attrs: &[Attribute]) -> Result<bool> {
+        try_eval_attributes(Some(&MockCfgEvaluator), attrs)
+    }
+
+    #[test]
+    fn test_try_eval_attributes() {
+        assert!(eval(&[]).unwrap());
+        assert!(eval(&[parse_quote! { #[doc = "Not a cfg"] }]).unwrap());
+        assert!(eval(&[parse_quote! { #[cfg(feature = "enabled")] }]).unwrap());
+        assert!(!eval(&[parse_quote! { #[cfg(feature = "disabled")] }]).unwrap());
+        assert!(!eval(&[
+            parse_quote! { #[cfg(feature = "enabled")] },
+            parse_quote! { #[cfg(feature = "disabled")] },
+        ])
+        .unwrap());
+        assert!(!eval(&[parse_quote! { #[cfg(debug_assertions)] }]).unwrap());
+    }
+
+    #[test]
+    fn test_try_eval_attributes_predicates() {
+        assert!(
+            eval(&[parse_quote! { #[cfg(any(feature = "disabled", feature = "enabled"))] }])
+                .unwrap()
+        );
+        assert!(
+            !eval(&[parse_quote! { #[cfg(all(feature = "disabled", feature = "enabled"))] }])
+                .unwrap()
+        );
+        assert!(eval(&[parse_quote! { #[cfg(not(feature = "disabled"))] }]).unwrap());
+        assert!(eval(&[parse_quote! { #[cfg(all())] }]).unwrap());
+        assert!(!eval(&[parse_quote! { #[cfg(any())] }]).unwrap());
+    }
+
+    #[test]
+    fn test_try_eval_attributes_invalid() {
+        assert!(eval(&[parse_quote! { #[cfg(unknown)] }]).is_err());
+        assert!(eval(&[parse_quote! { #[cfg(not(feature = "a", feature = "b"))] }]).is_err());
+        assert!(eval(&[parse_quote! { #[cfg(other(feature = "a"))] }]).is_err());
+        assert!(eval(&[parse_quote! { #[cfg(feature = 1)] }]).is_err());
+    }
+
+    #[test]
+    fn test_try_eval_attributes_without_evaluator() {
+        let opt = GeneratedOpt::default();
+        assert!(try_eval_attributes(
+            opt.cfg_evaluator.as_deref(),
+            &[parse_quote! { #[cfg(feature = "disabled")] }]
+        )
+        .unwrap());
+        assert!(try_eval_attributes(
+            opt.cfg_evaluator.as_deref(),
+            &[parse_quote! { #[cfg(unknown)] }]
+        )
+        .unwrap());
+    }
+}
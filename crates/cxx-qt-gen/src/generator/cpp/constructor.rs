@@ -173,6 +173,7 @@ mod tests {
             namespace_internals: "rust".to_string(),
             blocks: GeneratedCppQObjectBlocks::default(),
             has_qobject_macro: true,
+            docs: "".to_owned(),
         }
     }
 
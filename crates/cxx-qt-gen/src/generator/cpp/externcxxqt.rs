@@ -4,8 +4,13 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    generator::cpp::signal::generate_cpp_signal, naming::TypeNames,
-    parser::externcxxqt::ParsedExternCxxQt, CppFragment,
+    generator::{
+        cfg::{try_filter_cfgs, GeneratedOpt},
+        cpp::signal::generate_cpp_signal,
+    },
+    naming::TypeNames,
+    parser::externcxxqt::ParsedExternCxxQt,
+    CppFragment,
 };
 use std::collections::BTreeSet;
 use syn::Result;
@@ -23,11 +28,15 @@ pub struct GeneratedCppExternCxxQtBlocks {
 pub fn generate(
     blocks: &[ParsedExternCxxQt],
     type_names: &TypeNames,
+    opt: &GeneratedOpt,
 ) -> Result<Vec<GeneratedCppExternCxxQtBlocks>> {
     let mut out = vec![];
 
     for block in blocks {
-        for signal in &block.signals {
+        // Note that the signals also have the cfgs of the block
+        for signal in try_filter_cfgs(opt.cfg_evaluator.as_deref(), &block.signals, |signal| {
+            &signal.cfgs
+        })? {
             let mut block = GeneratedCppExternCxxQtBlocks::default();
             let qobject_name = type_names.lookup(&signal.qobject_ident)?;
             let data = generate_cpp_signal(signal, qobject_name, type_names)?;
@@ -70,9 +79,9 @@ mod tests {
         .unwrap()];
 
         // Unknown types
-        assert!(generate(&blocks, &TypeNames::default()).is_err());
+        assert!(generate(&blocks, &TypeNames::default(), &GeneratedOpt::default()).is_err());
 
-        let generated = generate(&blocks, &TypeNames::mock()).unwrap();
+        let generated = generate(&blocks, &TypeNames::mock(), &GeneratedOpt::default()).unwrap();
         assert_eq!(generated.len(), 2);
     }
 
@@ -97,7 +106,7 @@ mod tests {
         let mut type_names = TypeNames::default();
         type_names.mock_insert("ObjRust", None, Some("ObjCpp"), Some("mynamespace"));
 
-        let generated = generate(&blocks, &type_names).unwrap();
+        let generated = generate(&blocks, &type_names, &GeneratedOpt::default()).unwrap();
         assert_eq!(generated.len(), 1);
     }
 }
@@ -6,40 +6,89 @@ use indoc::formatdoc;
 
 use crate::{
     generator::cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
-    naming::cpp::syn_type_to_cpp_return_type,
-    naming::TypeNames,
-    parser::inherit::ParsedInheritedMethod,
+    naming::cpp::{syn_type_to_cpp_return_type, syn_type_to_cpp_type},
+    naming::{Name, TypeNames},
+    parser::{inherit::ParsedInheritedMethod, AccessSpecifier},
+    writer::cpp::namespaced,
 };
 
 use syn::Result;
 
 pub fn generate(
     inherited_methods: &[&ParsedInheritedMethod],
+    qobject_name: &Name,
     base_class: &Option<String>,
     type_names: &TypeNames,
 ) -> Result<GeneratedCppQObjectBlocks> {
     let mut result = GeneratedCppQObjectBlocks::default();
 
     for &method in inherited_methods {
-        let return_type = syn_type_to_cpp_return_type(&method.method.sig.output, type_names)?;
+        let return_type = syn_type_to_cpp_return_type(&method.method.sig.output, type_names)?
+            .unwrap_or_else(|| "void".to_string());
         // Note that no qobject macro with no base class is an error
         //
         // So a default of QObject is fine here
         let base_class = base_class.as_deref().unwrap_or("QObject");
-
-        result.methods.push(CppFragment::Header(formatdoc! {
-        r#"
-              template <class... Args>
-              {return_type} {wrapper_ident}(Args ...args){mutability}
-              {{
-                  return {base_class}::{func_ident}(args...);
-              }}"#,
-        mutability = if method.mutable { "" } else { " const" },
-        func_ident = method.name.cxx_unqualified(),
-        wrapper_ident = method.wrapper_ident(),
-        return_type = return_type.unwrap_or_else(|| "void".to_string()),
-        base_class = base_class
-        }));
+        let func_ident = method.name.cxx_unqualified();
+
+        if method.access == AccessSpecifier::Public {
+            result.methods.push(CppFragment::Header(formatdoc! {
+            r#"
+                  template <class... Args>
+                  {return_type} {wrapper_ident}(Args ...args){mutability}
+                  {{
+                      return {base_class}::{func_ident}(args...);
+                  }}"#,
+            mutability = if method.mutable { "" } else { " const" },
+            wrapper_ident = method.wrapper_ident(),
+            }));
+            continue;
+        }
+
+        // A public wrapper would make the protected method available to any C++ code,
+        // so instead a free function which is a friend of the QObject calls the method
+        let wrapper_ident = method.friend_wrapper_name().cxx_unqualified().to_owned();
+        let self_parameter = format!(
+            "{constness}{qobject}& self",
+            constness = if method.mutable { "" } else { "const " },
+            qobject = qobject_name.cxx_qualified()
+        );
+        let mut parameters = vec![self_parameter];
+        let mut parameter_values = vec![];
+        for parameter in &method.parameters {
+            let ident = parameter.ident.to_string();
+            parameters.push(format!(
+                "{ty} {ident}",
+                ty = syn_type_to_cpp_type(&parameter.ty, type_names)?
+            ));
+            parameter_values.push(ident);
+        }
+        let parameters = parameters.join(", ");
+        let parameter_values = parameter_values.join(", ");
+        let namespace = qobject_name.namespace().unwrap_or_default();
+
+        result.private_methods.push(CppFragment::Header(format!(
+            "friend {return_type} {wrapper_ident}({parameters});"
+        )));
+        result.fragments.push(CppFragment::Pair {
+            header: namespaced(
+                namespace,
+                &formatdoc! {r#"
+                    {return_type}
+                    {wrapper_ident}({parameters});"#
+                },
+            ),
+            source: namespaced(
+                namespace,
+                &formatdoc! {r#"
+                    {return_type}
+                    {wrapper_ident}({parameters})
+                    {{
+                        return self.{base_class}::{func_ident}({parameter_values});
+                    }}"#
+                },
+            ),
+        });
     }
 
     Ok(result)
@@ -51,7 +100,7 @@ mod tests {
     use syn::{parse_quote, ForeignItemFn};
 
     use super::*;
-    use crate::generator::cpp::property::tests::require_header;
+    use crate::generator::cpp::property::tests::{require_header, require_pair};
     use crate::parser::CaseConversion;
     use crate::{parser::inherit::ParsedInheritedMethod, syntax::safety::Safety};
 
@@ -62,7 +111,12 @@ mod tests {
         let method = ParsedInheritedMethod::parse(method, Safety::Safe, CaseConversion::none())?;
         let inherited_methods = vec![&method];
         let base_class = base_class.map(|s| s.to_owned());
-        generate(&inherited_methods, &base_class, &TypeNames::default())
+        generate(
+            &inherited_methods,
+            &Name::mock("MyObject"),
+            &base_class,
+            &TypeNames::default(),
+        )
     }
 
     fn assert_generated_eq(expected: &str, generated: &GeneratedCppQObjectBlocks) {
@@ -136,4 +190,44 @@ mod tests {
             &generated,
         );
     }
+
+    #[test]
+    fn test_protected() {
+        let generated = generate_from_foreign(
+            parse_quote! {
+                #[inherit(protected)]
+                #[cxx_name = "hasIndex"]
+                fn has_index(self: &MyObject, row: i32, column: i32) -> bool;
+            },
+            Some("QAbstractListModel"),
+        )
+        .unwrap();
+
+        assert!(generated.methods.is_empty());
+        assert_eq!(generated.private_methods.len(), 1);
+        assert_str_eq!(
+            require_header(&generated.private_methods[0]).unwrap(),
+            "friend bool MyObject_hasIndexCxxQtInherit(const MyObject& self, ::std::int32_t row, ::std::int32_t column);"
+        );
+
+        assert_eq!(generated.fragments.len(), 1);
+        let (header, source) = require_pair(&generated.fragments[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc::indoc! {"
+                bool
+                MyObject_hasIndexCxxQtInherit(const MyObject& self, ::std::int32_t row, ::std::int32_t column);"
+            }
+        );
+        assert_str_eq!(
+            source,
+            indoc::indoc! {"
+                bool
+                MyObject_hasIndexCxxQtInherit(const MyObject& self, ::std::int32_t row, ::std::int32_t column)
+                {
+                    return self.QAbstractListModel::hasIndex(row, column);
+                }"
+            }
+        );
+    }
 }
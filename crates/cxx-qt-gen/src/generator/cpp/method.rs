@@ -5,15 +5,22 @@
 
 use crate::generator::cpp::get_cpp_params;
 use crate::{
-    generator::cpp::{
-        fragment::{CppFragment, CppNamedType},
-        qobject::GeneratedCppQObjectBlocks,
+    generator::{
+        cpp::{
+            fragment::{CppFragment, CppNamedType},
+            qobject::GeneratedCppQObjectBlocks,
+            utils::doc_comment,
+        },
+        naming::{method::QAsyncInvokableNames, qobject::QObjectNames},
+    },
+    naming::cpp::{
+        syn_return_type_to_cpp_except, syn_type_to_cpp_return_type, syn_type_to_cpp_type,
     },
-    naming::cpp::{syn_return_type_to_cpp_except, syn_type_to_cpp_return_type},
     naming::TypeNames,
     parser::method::{ParsedMethod, ParsedQInvokableSpecifiers},
 };
-use syn::Result;
+use indoc::formatdoc;
+use syn::{Result, ReturnType};
 
 pub fn generate_cpp_methods(
     invokables: &Vec<&ParsedMethod>,
@@ -64,7 +71,8 @@ pub fn generate_cpp_methods(
         // CXX generates the source and we just need the matching header.
         let has_noexcept = syn_return_type_to_cpp_except(&invokable.method.sig.output);
         generated.methods.push(CppFragment::Header(format!(
-            "{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept}{is_final}{is_override};",
+            "{docs}{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept}{is_final}{is_override};",
+            docs = doc_comment(&invokable.docs),
             ident = invokable.name.cxx_unqualified(),
         )));
     }
@@ -72,11 +80,154 @@ pub fn generate_cpp_methods(
     Ok(generated)
 }
 
+/// Generate async invokables, which return a QFuture that is resolved once their Rust future
+/// has finished
+///
+/// If the QObject is a QML element, an overload which takes a JavaScript callback instead of
+/// returning the QFuture is generated too, as QML cannot wait for a QFuture.
+pub fn generate_cpp_async_invokables(
+    invokables: &Vec<&ParsedMethod>,
+    qobject_idents: &QObjectNames,
+    is_qml_element: bool,
+    type_names: &TypeNames,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut generated = GeneratedCppQObjectBlocks::default();
+    let class_name = qobject_idents.name.cxx_unqualified();
+
+    for &invokable in invokables {
+        let async_names = QAsyncInvokableNames::new(invokable, &qobject_idents.name);
+        let promise_alias = &async_names.promise_alias;
+        let spawn_ident = async_names.spawn_name.cxx_unqualified();
+        let ident = invokable.name.cxx_unqualified();
+        let is_const = if !invokable.mutable { " const" } else { "" };
+        let result_ty = match &invokable.method.sig.output {
+            ReturnType::Default => "void".to_owned(),
+            ReturnType::Type(_, ty) => syn_type_to_cpp_type(ty, type_names)?,
+        };
+
+        let parameters: Vec<CppNamedType> = get_cpp_params(&invokable.method, type_names)?;
+        let parameter_types = parameters
+            .iter()
+            .map(|parameter| format!("{ty} {ident}", ident = parameter.ident, ty = parameter.ty))
+            .collect::<Vec<String>>();
+        let parameter_idents = parameters
+            .iter()
+            .map(|parameter| parameter.ident.clone())
+            .collect::<Vec<String>>();
+
+        generated
+            .includes
+            .insert("#include <cxx-qt/promise.h>".to_owned());
+        generated.forward_declares.push(format!(
+            "using {promise_alias} = ::rust::cxxqt1::CxxQtPromise<{result_ty}>;"
+        ));
+
+        // The future is spawned by Rust, which CXX generates the source of
+        let spawn_parameter_types = parameter_types
+            .iter()
+            .cloned()
+            .chain([format!("::std::unique_ptr<{promise_alias}> promise")])
+            .collect::<Vec<String>>()
+            .join(", ");
+        // Rust borrows the QObject mutably to spawn the future, so a const invokable casts away
+        // the constness to call it
+        generated.private_methods.push(CppFragment::Header(format!(
+            "void {spawn_ident}({spawn_parameter_types}) noexcept;"
+        )));
+        let spawn_receiver = if invokable.mutable {
+            String::new()
+        } else {
+            format!("const_cast<{class_name}*>(this)->")
+        };
+
+        let spawn_arguments = parameter_idents
+            .iter()
+            .cloned()
+            .chain(["::std::move(promise)".to_owned()])
+            .collect::<Vec<String>>()
+            .join(", ");
+        generated.methods.push(CppFragment::Pair {
+            header: format!(
+                "{docs}Q_INVOKABLE QFuture<{result_ty}> {ident}({parameter_types}){is_const};",
+                docs = doc_comment(&invokable.docs),
+                parameter_types = parameter_types.join(", "),
+            ),
+            source: formatdoc! {
+                r#"
+                QFuture<{result_ty}>
+                {class_name}::{ident}({parameter_types}){is_const}
+                {{
+                  auto promise = ::std::make_unique<{promise_alias}>();
+                  auto future = promise->future();
+                  {spawn_receiver}{spawn_ident}({spawn_arguments});
+                  return future;
+                }}
+                "#,
+                parameter_types = parameter_types.join(", "),
+            },
+        });
+
+        if is_qml_element {
+            generated
+                .includes
+                .insert("#include <QtQml/QJSEngine>".to_owned());
+            generated
+                .includes
+                .insert("#include <QtQml/QJSValue>".to_owned());
+
+            let callback_parameter_types = parameter_types
+                .iter()
+                .cloned()
+                .chain(["QJSValue const& callback".to_owned()])
+                .collect::<Vec<String>>()
+                .join(", ");
+            let (captures, future_parameter, call) = if result_ty == "void" {
+                ("callback", "", "callback.call();".to_owned())
+            } else {
+                (
+                    "this, callback",
+                    " future",
+                    formatdoc! {
+                        r#"
+                        auto* engine = qjsEngine(this);
+                        callback.call({{ engine ? engine->toScriptValue(future.result()) : QJSValue() }});"#
+                    },
+                )
+            };
+            generated.methods.push(CppFragment::Pair {
+                header: format!("Q_INVOKABLE void {ident}({callback_parameter_types}){is_const};"),
+                source: formatdoc! {
+                    r#"
+                    void
+                    {class_name}::{ident}({callback_parameter_types}){is_const}
+                    {{
+                      ::rust::cxxqt1::cxxQtOnFinished(
+                        const_cast<{class_name}*>(this),
+                        {ident}({arguments}),
+                        [{captures}](QFuture<{result_ty}> const&{future_parameter}) mutable {{
+                          {call}
+                        }});
+                    }}
+                    "#,
+                    arguments = parameter_idents.join(", "),
+                    call = call.replace('\n', "\n      "),
+                },
+            });
+        }
+    }
+
+    Ok(generated)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::generator::cpp::property::tests::require_header;
+    use crate::generator::cpp::property::tests::{require_header, require_pair};
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::parser::CaseConversion;
+    use crate::syntax::safety::Safety;
+    use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use std::collections::HashSet;
     use syn::{parse_quote, ForeignItemFn};
@@ -182,4 +333,113 @@ mod tests {
         // private methods
         assert_eq!(generated.private_methods.len(), 0);
     }
+
+    #[test]
+    fn test_generate_cpp_invokables_async() {
+        let method_declaration: ForeignItemFn = parse_quote! {
+            /// Fetch the value
+            #[qinvokable]
+            async fn fetch(self: &MyObject, param: i32) -> i32;
+        };
+        let method =
+            ParsedMethod::parse(method_declaration, Safety::Safe, CaseConversion::none()).unwrap();
+        assert!(method.is_async);
+
+        let generated = generate_cpp_async_invokables(
+            &vec![&method],
+            &create_qobjectname(),
+            true,
+            &TypeNames::mock(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.forward_declares.len(), 1);
+        assert_str_eq!(
+            generated.forward_declares[0],
+            "using MyObjectCxxQtPromisefetch = ::rust::cxxqt1::CxxQtPromise<::std::int32_t>;"
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "void fetchCxxQtSpawn(::std::int32_t param, ::std::unique_ptr<MyObjectCxxQtPromisefetch> promise) noexcept;"
+        );
+
+        assert_eq!(generated.methods.len(), 2);
+        let (header, source) = require_pair(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Fetch the value\nQ_INVOKABLE QFuture<::std::int32_t> fetch(::std::int32_t param) const;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QFuture<::std::int32_t>
+            MyObject::fetch(::std::int32_t param) const
+            {
+              auto promise = ::std::make_unique<MyObjectCxxQtPromisefetch>();
+              auto future = promise->future();
+              const_cast<MyObject*>(this)->fetchCxxQtSpawn(param, ::std::move(promise));
+              return future;
+            }
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.methods[1]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE void fetch(::std::int32_t param, QJSValue const& callback) const;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::fetch(::std::int32_t param, QJSValue const& callback) const
+            {
+              ::rust::cxxqt1::cxxQtOnFinished(
+                const_cast<MyObject*>(this),
+                fetch(param),
+                [this, callback](QFuture<::std::int32_t> const& future) mutable {
+                  auto* engine = qjsEngine(this);
+                  callback.call({ engine ? engine->toScriptValue(future.result()) : QJSValue() });
+                });
+            }
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_async_void() {
+        let method_declaration: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            async fn reset(self: Pin<&mut MyObject>);
+        };
+        let method =
+            ParsedMethod::parse(method_declaration, Safety::Safe, CaseConversion::none()).unwrap();
+
+        let generated = generate_cpp_async_invokables(
+            &vec![&method],
+            &create_qobjectname(),
+            false,
+            &TypeNames::mock(),
+        )
+        .unwrap();
+
+        assert_str_eq!(
+            generated.forward_declares[0],
+            "using MyObjectCxxQtPromisereset = ::rust::cxxqt1::CxxQtPromise<void>;"
+        );
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "void resetCxxQtSpawn(::std::unique_ptr<MyObjectCxxQtPromisereset> promise) noexcept;"
+        );
+
+        // Without QML there is no overload with a callback
+        assert_eq!(generated.methods.len(), 1);
+        let (header, _) = require_pair(&generated.methods[0]).unwrap();
+        assert_str_eq!(header, "Q_INVOKABLE QFuture<void> reset();");
+        assert!(!generated.includes.contains("#include <QtQml/QJSValue>"));
+    }
 }
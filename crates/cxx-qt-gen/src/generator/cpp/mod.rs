@@ -11,9 +11,12 @@ pub mod inherit;
 pub mod method;
 pub mod property;
 pub mod qenum;
+pub mod qgadget;
+pub mod qmlmodel;
 pub mod qnamespace;
 pub mod qobject;
 pub mod signal;
+pub mod singleton;
 pub mod threading;
 
 mod utils;
@@ -23,7 +26,13 @@ use std::collections::BTreeSet;
 use crate::generator::cpp::fragment::CppNamedType;
 use crate::naming::cpp::syn_type_to_cpp_type;
 use crate::naming::TypeNames;
-use crate::{generator::structuring, parser::Parser};
+use crate::{
+    generator::{
+        cfg::{try_filter_cfgs, GeneratedOpt},
+        structuring,
+    },
+    parser::Parser,
+};
 use externcxxqt::GeneratedCppExternCxxQtBlocks;
 use qobject::GeneratedCppQObject;
 use syn::{FnArg, ForeignItemFn, Pat, PatIdent, PatType, Result};
@@ -34,6 +43,8 @@ pub struct GeneratedCppBlocks {
     pub forward_declares: Vec<String>,
     /// Additional includes for the CXX bridge
     pub includes: BTreeSet<String>,
+    /// Definitions of the Q_GADGETs, which are written after the CXX header as their fields can use CXX types
+    pub qgadgets: Vec<String>,
     /// Generated QObjects
     pub qobjects: Vec<GeneratedCppQObject>,
     /// Generated extern C++Qt blocks
@@ -42,8 +53,12 @@ pub struct GeneratedCppBlocks {
 
 impl GeneratedCppBlocks {
     /// Create a [GeneratedCppBlocks] from the given [Parser] object
-    pub fn from(parser: &Parser) -> Result<GeneratedCppBlocks> {
+    ///
+    /// Any `#[cfg(...)]` attributes in the bridge are evaluated with the given [GeneratedOpt],
+    /// so that C++ is only generated for the items which are enabled.
+    pub fn from(parser: &Parser, opt: &GeneratedOpt) -> Result<GeneratedCppBlocks> {
         let structures = structuring::Structures::new(&parser.cxx_qt_data)?;
+        let cfg_evaluator = opt.cfg_evaluator.as_deref();
 
         let mut includes = BTreeSet::new();
 
@@ -54,23 +69,42 @@ impl GeneratedCppBlocks {
             .map(|parsed_qnamespace| qnamespace::generate(parsed_qnamespace, &mut includes))
             .collect();
         forward_declares.extend(
-            parser
-                .cxx_qt_data
-                .qenums
+            try_filter_cfgs(cfg_evaluator, &parser.cxx_qt_data.qenums, |qenum| {
+                &qenum.cfgs
+            })?
+            .into_iter()
+            .map(|parsed_qenum| qenum::generate_declaration(parsed_qenum, &mut includes)),
+        );
+
+        let qgadgets = try_filter_cfgs(cfg_evaluator, &structures.qgadgets, |qgadget| {
+            &qgadget.declaration.cfgs
+        })?;
+        forward_declares.extend(
+            qgadgets
                 .iter()
-                .map(|parsed_qenum| qenum::generate_declaration(parsed_qenum, &mut includes)),
+                .map(|qgadget| qgadget::generate_forward_declaration(qgadget.declaration)),
         );
+        let qgadgets = qgadgets
+            .into_iter()
+            .map(|qgadget| {
+                qgadget::generate_definition(qgadget, &parser.type_names, opt, &mut includes)
+            })
+            .collect::<Result<Vec<String>>>()?;
+
         Ok(GeneratedCppBlocks {
             forward_declares,
             includes,
-            qobjects: structures
-                .qobjects
-                .iter()
-                .map(|qobject| GeneratedCppQObject::from(qobject, &parser.type_names))
-                .collect::<Result<Vec<GeneratedCppQObject>>>()?,
+            qgadgets,
+            qobjects: try_filter_cfgs(cfg_evaluator, &structures.qobjects, |qobject| {
+                &qobject.declaration.cfgs
+            })?
+            .into_iter()
+            .map(|qobject| GeneratedCppQObject::from(qobject, &parser.type_names, opt))
+            .collect::<Result<Vec<GeneratedCppQObject>>>()?,
             extern_cxx_qt: externcxxqt::generate(
                 &parser.cxx_qt_data.extern_cxxqt_blocks,
                 &parser.type_names,
+                opt,
             )?,
         })
     }
@@ -125,7 +159,7 @@ mod tests {
         };
         let parser = Parser::from(module).unwrap();
 
-        let cpp = GeneratedCppBlocks::from(&parser).unwrap();
+        let cpp = GeneratedCppBlocks::from(&parser, &GeneratedOpt::default()).unwrap();
         assert_eq!(cpp.qobjects.len(), 1);
         assert_eq!(cpp.qobjects[0].name.namespace(), None);
     }
@@ -143,7 +177,7 @@ mod tests {
         };
         let parser = Parser::from(module).unwrap();
 
-        let cpp = GeneratedCppBlocks::from(&parser).unwrap();
+        let cpp = GeneratedCppBlocks::from(&parser, &GeneratedOpt::default()).unwrap();
         assert_eq!(cpp.qobjects[0].name.namespace(), Some("cxx_qt"));
     }
 }
@@ -0,0 +1,89 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+    naming::{property::QPropertyNames, qobject::QObjectNames},
+};
+use indoc::{formatdoc, indoc};
+
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_idents: &QObjectNames,
+    cxx_ty: &str,
+) -> Option<GeneratedCppQObjectBlocks> {
+    let bindable = idents.bindable.as_ref()?;
+    let mut generated = GeneratedCppQObjectBlocks::default();
+
+    let class_name = qobject_idents.name.cxx_unqualified();
+    let getter = idents.getter.cxx_unqualified();
+    let accessor = bindable.accessor.cxx_unqualified();
+    let untyped = bindable.untyped.cxx_unqualified();
+    let bind = bindable.bind.cxx_unqualified();
+    let update = bindable.update.cxx_unqualified();
+    let set_from_bindable = bindable.set_from_bindable.cxx_unqualified();
+    let member = format!("m_{}Bindable", idents.name.cxx_unqualified());
+    let changed = format!("{accessor}Changed");
+
+    // QProperty and QBindable only exist in Qt 6, so give a clear error when building against Qt 5
+    generated.includes.insert(
+        indoc! {r#"
+        #include <QtCore/QtGlobal>
+        #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+        #include <QtCore/QProperty>
+        #else
+        #error "BINDABLE properties require Qt 6"
+        #endif"#}
+        .to_owned(),
+    );
+
+    // The QBindable accessor used by the Q_PROPERTY and the helpers which are called from Rust
+    generated.methods.push(CppFragment::Header(formatdoc! {
+        r#"
+        #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+        QBindable<{cxx_ty}> {accessor}()
+        {{
+          // Ensure that the bindable starts with the value of the Rust field
+          if (!{member}.hasBinding()) {{
+            {member}.setValueBypassingBindings({getter}());
+          }}
+          return QBindable<{cxx_ty}>(&{member});
+        }}
+        QUntypedBindable {untyped}()
+        {{
+          return {accessor}();
+        }}
+        bool {bind}(QUntypedBindable source)
+        {{
+          QBindable<{cxx_ty}> typed(source);
+          if (!typed.isValid()) {{
+            return false;
+          }}
+          {accessor}().setBinding([typed]() {{ return typed.value(); }});
+          return true;
+        }}
+        void {update}()
+        {{
+          {member}.setValue({getter}());
+        }}
+        #endif"#
+    }));
+
+    // The storage of the bindable, which writes any change back into the Rust field
+    generated
+        .private_methods
+        .push(CppFragment::Header(formatdoc! {
+            r#"
+        #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+        void {changed}()
+        {{
+          {set_from_bindable}({member}.value());
+        }}
+        void {set_from_bindable}({cxx_ty} value) noexcept;
+        Q_OBJECT_BINDABLE_PROPERTY({class_name}, {cxx_ty}, {member}, &{class_name}::{changed})
+        #endif"#
+        }));
+
+    Some(generated)
+}
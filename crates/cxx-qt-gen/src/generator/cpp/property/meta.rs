@@ -3,11 +3,12 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::naming::property::QPropertyNames;
-use crate::parser::property::QPropertyFlags;
+use crate::generator::{cpp::utils::doc_comment, naming::property::QPropertyNames};
+use crate::parser::property::ParsedQProperty;
 
-/// Generate the metaobject line for a given property
-pub fn generate(idents: &QPropertyNames, flags: &QPropertyFlags, cxx_ty: &str) -> String {
+/// Generate the metaobject line for a given property, preceded by any docs of the property
+pub fn generate(idents: &QPropertyNames, property: &ParsedQProperty, cxx_ty: &str) -> String {
+    let flags = &property.flags;
     let mut parts = vec![format!(
         "READ {ident_getter}",
         ident_getter = idents.getter.cxx_unqualified()
@@ -25,6 +26,10 @@ pub fn generate(idents: &QPropertyNames, flags: &QPropertyFlags, cxx_ty: &str) -
         parts.push(format!("RESET {}", reset.cxx_unqualified()));
     }
 
+    if let Some(bindable) = &idents.bindable {
+        parts.push(format!("BINDABLE {}", bindable.accessor.cxx_unqualified()));
+    }
+
     if flags.constant {
         parts.push(String::from("CONSTANT"))
     }
@@ -38,7 +43,8 @@ pub fn generate(idents: &QPropertyNames, flags: &QPropertyFlags, cxx_ty: &str) -
     }
 
     format!(
-        "Q_PROPERTY({ty} {ident} {meta_parts})",
+        "{docs}Q_PROPERTY({ty} {ident} {meta_parts})",
+        docs = doc_comment(&property.docs),
         ty = cxx_ty,
         ident = idents.name.cxx_unqualified(),
         meta_parts = parts.join(" ")
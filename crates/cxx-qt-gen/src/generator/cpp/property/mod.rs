@@ -13,13 +13,15 @@ use crate::{
 };
 use syn::Result;
 
+mod bindable;
 mod getter;
 mod meta;
+mod reset;
 mod setter;
 mod signal;
 
 pub fn generate_cpp_properties(
-    properties: &Vec<ParsedQProperty>,
+    properties: &Vec<&ParsedQProperty>,
     qobject_idents: &QObjectNames,
     type_names: &TypeNames,
     structured_qobject: &StructuredQObject,
@@ -27,14 +29,14 @@ pub fn generate_cpp_properties(
     let mut generated = GeneratedCppQObjectBlocks::default();
     let mut signals = vec![];
 
-    for property in properties {
+    for &property in properties {
         // Cache the idents as they are used in multiple places
         let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
         let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)?;
 
         generated
             .metaobjects
-            .push(meta::generate(&idents, &property.flags, &cxx_ty));
+            .push(meta::generate(&idents, property, &cxx_ty));
 
         if let Some(getter) = getter::generate(&idents, &cxx_ty) {
             generated.methods.push(getter);
@@ -44,6 +46,14 @@ pub fn generate_cpp_properties(
             generated.methods.push(setter)
         }
 
+        if let Some(reset) = reset::generate(&idents) {
+            generated.methods.push(reset)
+        }
+
+        if let Some(mut bindable) = bindable::generate(&idents, qobject_idents, &cxx_ty) {
+            generated.append(&mut bindable);
+        }
+
         if let Some(notify) = signal::generate(&idents, &qobject_idents.name) {
             signals.push(notify)
         }
@@ -97,7 +107,7 @@ pub mod tests {
     fn setup_generated(input: &mut ItemStruct) -> Result<GeneratedCppQObjectBlocks> {
         let property = ParsedQProperty::parse(&input.attrs.remove(0), CaseConversion::none())?;
 
-        let properties = vec![property];
+        let properties = [property];
 
         let qobject_idents = create_qobjectname();
 
@@ -107,7 +117,7 @@ pub mod tests {
 
         let type_names = TypeNames::mock();
         generate_cpp_properties(
-            &properties,
+            &properties.iter().collect(),
             &qobject_idents,
             &type_names,
             &structured_qobject,
@@ -159,7 +169,7 @@ pub mod tests {
 
         let property = mock_property(input);
 
-        let properties = vec![property];
+        let properties = [property];
 
         let qobject_idents = create_qobjectname();
 
@@ -171,7 +181,7 @@ pub mod tests {
 
         let type_names = TypeNames::mock();
         let generated = generate_cpp_properties(
-            &properties,
+            &properties.iter().collect(),
             &qobject_idents,
             &type_names,
             structured_qobject,
@@ -197,7 +207,7 @@ pub mod tests {
 
         let property = mock_property(input);
 
-        let properties = vec![property];
+        let properties = [property];
 
         let qobject_idents = create_qobjectname();
 
@@ -209,7 +219,7 @@ pub mod tests {
 
         let type_names = TypeNames::mock();
         let generated = generate_cpp_properties(
-            &properties,
+            &properties.iter().collect(),
             &qobject_idents,
             &type_names,
             structured_qobject,
@@ -238,7 +248,7 @@ pub mod tests {
 
         let property = mock_property(input);
 
-        let properties = vec![property];
+        let properties = [property];
 
         let qobject_idents = create_qobjectname();
 
@@ -251,7 +261,7 @@ pub mod tests {
 
         let type_names = TypeNames::mock();
         let generated = generate_cpp_properties(
-            &properties,
+            &properties.iter().collect(),
             &qobject_idents,
             &type_names,
             structured_qobject,
@@ -264,6 +274,89 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_auto_reset() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, num, READ, WRITE, NOTIFY, RESET)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t num READ getNum WRITE setNum NOTIFY numChanged RESET resetNum)"
+        );
+
+        assert_eq!(generated.methods.len(), 4);
+        let header = require_header(&generated.methods[2]).unwrap();
+        assert_str_eq!(header, "Q_SLOT void resetNum() noexcept;");
+    }
+
+    #[test]
+    fn test_bindable() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, num, READ, WRITE, NOTIFY, BINDABLE)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t num READ getNum WRITE setNum NOTIFY numChanged BINDABLE bindableNum)"
+        );
+        assert!(generated
+            .includes
+            .iter()
+            .any(|include| include.contains("#include <QtCore/QProperty>")));
+
+        assert_eq!(generated.methods.len(), 4);
+        assert_str_eq!(
+            require_header(&generated.methods[2]).unwrap(),
+            indoc! {r#"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            QBindable<::std::int32_t> bindableNum()
+            {
+              // Ensure that the bindable starts with the value of the Rust field
+              if (!m_numBindable.hasBinding()) {
+                m_numBindable.setValueBypassingBindings(getNum());
+              }
+              return QBindable<::std::int32_t>(&m_numBindable);
+            }
+            QUntypedBindable cxxQtBindableNum()
+            {
+              return bindableNum();
+            }
+            bool cxxQtBindNum(QUntypedBindable source)
+            {
+              QBindable<::std::int32_t> typed(source);
+              if (!typed.isValid()) {
+                return false;
+              }
+              bindableNum().setBinding([typed]() { return typed.value(); });
+              return true;
+            }
+            void cxxQtUpdateNumBindable()
+            {
+              m_numBindable.setValue(getNum());
+            }
+            #endif"#}
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        assert_str_eq!(
+            require_header(&generated.private_methods[0]).unwrap(),
+            indoc! {r#"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            void bindableNumChanged()
+            {
+              cxxQtSetNumFromBindable(m_numBindable.value());
+            }
+            void cxxQtSetNumFromBindable(::std::int32_t value) noexcept;
+            Q_OBJECT_BINDABLE_PROPERTY(MyObject, ::std::int32_t, m_numBindable, &MyObject::bindableNumChanged)
+            #endif"#}
+        );
+    }
+
     #[test]
     fn test_constant_and_required() {
         let mut input: ItemStruct = parse_quote! {
@@ -290,7 +383,7 @@ pub mod tests {
             struct MyStruct;
         };
 
-        let properties = vec![mock_property(input1), mock_property(input2)];
+        let properties = [mock_property(input1), mock_property(input2)];
 
         let qobject_idents = create_qobjectname();
 
@@ -301,7 +394,7 @@ pub mod tests {
         let mut type_names = TypeNames::mock();
         type_names.mock_insert("QColor", None, None, None);
         let generated = generate_cpp_properties(
-            &properties,
+            &properties.iter().collect(),
             &qobject_idents,
             &type_names,
             &structured_qobject,
@@ -468,10 +561,12 @@ pub mod tests {
 
     #[test]
     fn test_generate_cpp_properties_mapped_cxx_name() {
-        let properties = vec![ParsedQProperty {
+        let properties = [ParsedQProperty {
             name: Name::mock_name_with_cxx("mapped_property", "mappedProperty"),
             ty: parse_quote! { A },
             flags: QPropertyFlags::default(),
+            docs: vec![],
+            cfgs: vec![],
         }];
         let qobject_idents = create_qobjectname();
 
@@ -483,7 +578,7 @@ pub mod tests {
         type_names.mock_insert("A", None, Some("A1"), None);
 
         let generated = generate_cpp_properties(
-            &properties,
+            &properties.iter().collect(),
             &qobject_idents,
             &type_names,
             &structured_qobject,
@@ -21,7 +21,6 @@ pub fn generate(idents: &QPropertyNames, qobject_name: &Name) -> Option<ParsedSi
         let notify_cpp = notify.cxx_unqualified();
         let notify_rust = notify.rust_unqualified();
         let method: ForeignItemFn = syn::parse_quote! {
-            #[doc = "Notify for the Q_PROPERTY"]
             #[cxx_name = #notify_cpp]
             fn #notify_rust(self: Pin<&mut #cpp_class_rust>);
         };
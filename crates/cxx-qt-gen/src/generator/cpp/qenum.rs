@@ -10,7 +10,10 @@ use syn::Result;
 
 use crate::{parser::qenum::ParsedQEnum, writer::cpp::namespaced};
 
-use super::{qobject::GeneratedCppQObjectBlocks, utils::Indent};
+use super::{
+    qobject::GeneratedCppQObjectBlocks,
+    utils::{doc_comment, Indent},
+};
 
 fn generate_definition(qenum: &ParsedQEnum) -> String {
     let enum_name = &qenum.name.cxx_unqualified();
@@ -18,15 +21,38 @@ fn generate_definition(qenum: &ParsedQEnum) -> String {
     let enum_values = qenum
         .variants
         .iter()
-        .map(ToString::to_string)
+        .map(|variant| {
+            if let Some(discriminant) = variant.discriminant {
+                format!("{ident} = {discriminant}", ident = variant.ident)
+            } else {
+                variant.ident.to_string()
+            }
+        })
         .collect::<Vec<_>>()
         .join(",\n");
 
+    // The repr is one of the Rust integer types, so map it to the fixed width C++ type
+    let repr = qenum.repr.to_string();
+    let cxx_repr = if let Some(bits) = repr.strip_prefix('u') {
+        format!("::std::uint{bits}_t")
+    } else {
+        format!("::std::int{bits}_t", bits = repr.trim_start_matches('i'))
+    };
+
     formatdoc! { r#"
-        enum class {enum_name} : ::std::int32_t {{
+        {docs}enum class {enum_name} : {cxx_repr} {{
         {enum_values}
         }};
-        "#, enum_values = enum_values.indented(2) }
+        "#, docs = doc_comment(&qenum.docs), enum_values = enum_values.indented(2) }
+}
+
+fn generate_flags_declaration(qenum: &ParsedQEnum) -> Option<String> {
+    let flags_name = qenum.flags.as_ref()?.cxx_unqualified();
+    let enum_name = qenum.name.cxx_unqualified();
+    Some(formatdoc! {r#"
+        Q_DECLARE_FLAGS({flags_name}, {enum_name})
+        Q_DECLARE_OPERATORS_FOR_FLAGS({flags_name})
+        "# })
 }
 
 pub fn generate_declaration(qenum: &ParsedQEnum, includes: &mut BTreeSet<String>) -> String {
@@ -36,7 +62,12 @@ pub fn generate_declaration(qenum: &ParsedQEnum, includes: &mut BTreeSet<String>
         includes.insert("#include <QtCore/QObject>".to_string());
     }
 
-    let enum_definition = generate_definition(qenum).indented(2);
+    let mut enum_definition = generate_definition(qenum);
+    if let Some(flags_declaration) = generate_flags_declaration(qenum) {
+        includes.insert("#include <QtCore/QFlags>".to_string());
+        enum_definition.push_str(&flags_declaration);
+    }
+    let enum_definition = enum_definition.indented(2);
     let enum_name = &qenum.name.cxx_unqualified();
     namespaced(
         qenum.name.namespace().unwrap_or_default(),
@@ -44,10 +75,18 @@ pub fn generate_declaration(qenum: &ParsedQEnum, includes: &mut BTreeSet<String>
         // This is redundant with `qnamespace!`, which is now only required if you want to specify
         // it as QML_ELEMENT.
         &if is_standalone {
+            let registration = if let Some(flags) = &qenum.flags {
+                format!(
+                    "Q_FLAG_NS({flags_name})",
+                    flags_name = flags.cxx_unqualified()
+                )
+            } else {
+                format!("Q_ENUM_NS({enum_name})")
+            };
             formatdoc! {r#"
                 Q_NAMESPACE
                 {enum_definition}
-                Q_ENUM_NS({enum_name}) "# }
+                {registration} "# }
         } else {
             enum_definition
         },
@@ -71,15 +110,36 @@ pub fn generate_on_qobject<'a>(
 
         generated.includes.insert("#include <cstdint>".to_string());
         let enum_definition = generate_definition(qenum);
-        generated.metaobjects.push(formatdoc! {r#"
-            #ifdef Q_MOC_RUN
-            {enum_definition}
-              Q_ENUM({enum_name})
-            #else
-              using {enum_name} = {qualified_name};
-              Q_ENUM({enum_name})
-            #endif
-        "#, enum_definition = enum_definition.indented(2)});
+
+        if let Some(flags) = &qenum.flags {
+            let mut qualified_flags_name = flags.cxx_qualified();
+            if !qualified_flags_name.starts_with("::") {
+                qualified_flags_name.insert_str(0, "::");
+            }
+            let flags_name = flags.cxx_unqualified();
+
+            generated.metaobjects.push(formatdoc! {r#"
+                #ifdef Q_MOC_RUN
+                {enum_definition}
+                  Q_DECLARE_FLAGS({flags_name}, {enum_name})
+                  Q_FLAG({flags_name})
+                #else
+                  using {enum_name} = {qualified_name};
+                  using {flags_name} = {qualified_flags_name};
+                  Q_FLAG({flags_name})
+                #endif
+            "#, enum_definition = enum_definition.indented(2)});
+        } else {
+            generated.metaobjects.push(formatdoc! {r#"
+                #ifdef Q_MOC_RUN
+                {enum_definition}
+                  Q_ENUM({enum_name})
+                #else
+                  using {enum_name} = {qualified_name};
+                  Q_ENUM({enum_name})
+                #endif
+            "#, enum_definition = enum_definition.indented(2)});
+        }
     }
 
     Ok(generated)
@@ -131,4 +191,93 @@ mod tests {
         );
         assert_eq!(generated.forward_declares.len(), 0);
     }
+
+    #[test]
+    fn generates_discriminants_and_repr() {
+        let qenums = [ParsedQEnum::parse(
+            parse_quote! {
+                #[repr(u8)]
+                enum MyEnum {
+                    A = 4, B, C = 0x10
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+
+        let generated = generate_on_qobject(qenums.iter()).unwrap();
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            indoc! {r#"
+                #ifdef Q_MOC_RUN
+                  enum class MyEnum : ::std::uint8_t {
+                    A = 4,
+                    B,
+                    C = 16
+                  };
+                  Q_ENUM(MyEnum)
+                #else
+                  using MyEnum = ::MyEnum;
+                  Q_ENUM(MyEnum)
+                #endif
+            "#},
+            generated.metaobjects[0],
+        );
+    }
+
+    #[test]
+    fn generates_flags() {
+        let qenums = [ParsedQEnum::parse(
+            parse_quote! {
+                #[qflags(MyFlags)]
+                #[namespace = "my_namespace"]
+                enum MyFlag {
+                    A = 1, B = 2
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+
+        let generated = generate_on_qobject(qenums.iter()).unwrap();
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            indoc! {r#"
+                #ifdef Q_MOC_RUN
+                  enum class MyFlag : ::std::int32_t {
+                    A = 1,
+                    B = 2
+                  };
+                  Q_DECLARE_FLAGS(MyFlags, MyFlag)
+                  Q_FLAG(MyFlags)
+                #else
+                  using MyFlag = ::my_namespace::MyFlag;
+                  using MyFlags = ::my_namespace::MyFlags;
+                  Q_FLAG(MyFlags)
+                #endif
+            "#},
+            generated.metaobjects[0],
+        );
+
+        let mut includes = BTreeSet::new();
+        let declaration = generate_declaration(&qenums[0], &mut includes);
+        assert!(includes.contains("#include <QtCore/QFlags>"));
+        assert_str_eq!(
+            indoc! {r#"
+                namespace my_namespace {
+                  enum class MyFlag : ::std::int32_t {
+                    A = 1,
+                    B = 2
+                  };
+                  Q_DECLARE_FLAGS(MyFlags, MyFlag)
+                  Q_DECLARE_OPERATORS_FOR_FLAGS(MyFlags)
+                } // namespace my_namespace
+            "#},
+            declaration,
+        );
+    }
 }
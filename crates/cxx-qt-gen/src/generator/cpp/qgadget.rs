@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeSet;
+
+use indoc::formatdoc;
+use syn::Result;
+
+use crate::{
+    generator::{
+        cfg::{try_filter_cfgs, GeneratedOpt},
+        cpp::{
+            method::generate_cpp_methods,
+            utils::{doc_comment, Indent},
+        },
+        structuring::StructuredQGadget,
+    },
+    naming::{cpp::syn_type_to_cpp_type, TypeNames},
+    parser::qgadget::ParsedQGadget,
+    writer::cpp::{namespaced, pair_as_header},
+};
+
+/// Generate the forward declaration of the gadget, as the CXX header refers to it before it is defined
+pub fn generate_forward_declaration(qgadget: &ParsedQGadget) -> String {
+    namespaced(
+        qgadget.name.namespace().unwrap_or_default(),
+        &format!("struct {};", qgadget.name.cxx_unqualified()),
+    )
+}
+
+/// Generate the definition of the gadget
+///
+/// This takes the place of the definition CXX would generate for the shared struct,
+/// so it also declares the methods and operators which CXX defines.
+pub fn generate_definition(
+    qgadget: &StructuredQGadget,
+    type_names: &TypeNames,
+    opt: &GeneratedOpt,
+    includes: &mut BTreeSet<String>,
+) -> Result<String> {
+    let declaration = qgadget.declaration;
+    let name = declaration.name.cxx_unqualified();
+    includes.insert("#include <QtCore/QObject>".to_owned());
+
+    let mut metaobjects = vec!["Q_GADGET".to_owned()];
+    if let Some(qml_name) = &declaration.qml_name {
+        includes.insert("#include <QtQml/QQmlEngine>".to_owned());
+        metaobjects.push(format!("QML_VALUE_TYPE({qml_name})"));
+    }
+
+    let mut fields = vec![];
+    for field in &declaration.fields {
+        let ident = &field.ident;
+        let ty = syn_type_to_cpp_type(&field.ty, type_names)?;
+        metaobjects.push(format!(
+            "{docs}Q_PROPERTY({ty} {ident} MEMBER {ident})",
+            docs = doc_comment(&field.docs)
+        ));
+        fields.push(format!("{ty} {ident};"));
+    }
+
+    let methods = try_filter_cfgs(opt.cfg_evaluator.as_deref(), &qgadget.methods, |method| {
+        &method.cfgs
+    })?
+    .into_iter()
+    .copied()
+    .collect();
+    let mut methods: Vec<String> = generate_cpp_methods(&methods, type_names)?
+        .methods
+        .iter()
+        .filter_map(pair_as_header)
+        .collect();
+    if declaration.partial_eq {
+        methods.push(format!("bool operator==({name} const&) const noexcept;"));
+        methods.push(format!("bool operator!=({name} const&) const noexcept;"));
+    }
+    if declaration.partial_ord {
+        for operator in ["<", "<=", ">", ">="] {
+            methods.push(format!(
+                "bool operator{operator}({name} const&) const noexcept;"
+            ));
+        }
+    }
+
+    let definition = formatdoc! {r#"
+        {docs}struct {name}
+        {{
+        {metaobjects}
+
+        public:
+        {fields}
+        {methods}
+          using IsRelocatable = ::std::true_type;
+        }};
+        "#,
+        docs = doc_comment(&declaration.docs),
+        metaobjects = metaobjects.join("\n").indented(2),
+        fields = fields.join("\n").indented(2),
+        methods = methods.join("\n").indented(2),
+    };
+
+    Ok(formatdoc! {r#"
+        {definition}
+        Q_DECLARE_METATYPE({qualified})
+        "#,
+        definition = namespaced(declaration.name.namespace().unwrap_or_default(), &definition),
+        qualified = declaration.name.cxx_qualified(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::method::ParsedMethod;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use quote::format_ident;
+    use syn::{parse_quote, ForeignItemFn};
+
+    fn parse_qgadget() -> ParsedQGadget {
+        ParsedQGadget::parse(
+            parse_quote! {
+                /// A point
+                #[qgadget]
+                #[qml_element]
+                #[derive(PartialEq)]
+                struct Point {
+                    /// The x coordinate
+                    x: i32,
+                    y: i32,
+                }
+            },
+            Some("my_namespace"),
+            &format_ident!("qobject"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_generate_forward_declaration() {
+        assert_str_eq!(
+            generate_forward_declaration(&parse_qgadget()),
+            indoc! {r#"
+                namespace my_namespace {
+                struct Point;
+                } // namespace my_namespace
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generate_definition() {
+        let qgadget = parse_qgadget();
+        let method: ForeignItemFn = parse_quote! {
+            fn length(self: &Point) -> i32;
+        };
+        let method = ParsedMethod::mock_qinvokable(&method);
+        let mut structured = StructuredQGadget::from_qgadget(&qgadget);
+        structured.methods.push(&method);
+
+        let mut includes = BTreeSet::new();
+        let definition = generate_definition(
+            &structured,
+            &TypeNames::mock(),
+            &GeneratedOpt::default(),
+            &mut includes,
+        )
+        .unwrap();
+
+        assert_str_eq!(
+            definition,
+            indoc! {r#"
+                namespace my_namespace {
+                /// A point
+                struct Point
+                {
+                  Q_GADGET
+                  QML_VALUE_TYPE(point)
+                  /// The x coordinate
+                  Q_PROPERTY(::std::int32_t x MEMBER x)
+                  Q_PROPERTY(::std::int32_t y MEMBER y)
+
+                public:
+                  ::std::int32_t x;
+                  ::std::int32_t y;
+                  Q_INVOKABLE ::std::int32_t length() const noexcept;
+                  bool operator==(Point const&) const noexcept;
+                  bool operator!=(Point const&) const noexcept;
+                  using IsRelocatable = ::std::true_type;
+                };
+
+                } // namespace my_namespace
+
+                Q_DECLARE_METATYPE(my_namespace::Point)
+            "#}
+        );
+        assert_eq!(includes.len(), 2);
+        assert!(includes.contains("#include <QtQml/QQmlEngine>"));
+    }
+}
@@ -0,0 +1,373 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        naming::qobject::QObjectNames,
+    },
+    naming::{cpp::syn_type_to_cpp_type, TypeNames},
+    parser::qmlmodel::ParsedQmlModelRow,
+};
+use indoc::formatdoc;
+use syn::Result;
+
+/// The base class used for a #[qml_model] QObject without a #[base] attribute
+pub const DEFAULT_MODEL_BASE_CLASS: &str = "QAbstractListModel";
+
+pub fn generate(
+    row: &ParsedQmlModelRow,
+    base_class: &str,
+    qobject_idents: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut result = GeneratedCppQObjectBlocks::default();
+
+    let class_name = qobject_idents.name.cxx_unqualified();
+    let row_ty = type_names.cxx_qualified(&row.ident)?;
+
+    // A custom #[base] class is declared by the bridge, which is responsible for its include
+    if base_class == DEFAULT_MODEL_BASE_CLASS {
+        result
+            .includes
+            .insert(format!("#include <QtCore/{DEFAULT_MODEL_BASE_CLASS}>"));
+    }
+    for include in ["QByteArray", "QHash", "QVariant"] {
+        result
+            .includes
+            .insert(format!("#include <QtCore/{include}>"));
+    }
+
+    let mut data_cases = vec![];
+    let mut set_data_cases = vec![];
+    let mut role_names = vec![];
+    for (index, role) in row.roles.iter().enumerate() {
+        let role_value = if index == 0 {
+            "Qt::UserRole".to_owned()
+        } else {
+            format!("Qt::UserRole + {index}")
+        };
+        let field = &role.ident;
+        let cxx_ty = syn_type_to_cpp_type(&role.ty, type_names)?;
+
+        data_cases.push(format!(
+            "  case {role_value}:\n    return QVariant::fromValue(row.{field});"
+        ));
+        set_data_cases.push(format!(
+            "  case {role_value}:\n    if (!value.canConvert<{cxx_ty}>()) {{\n      return false;\n    }}\n    row.{field} = value.value<{cxx_ty}>();\n    break;"
+        ));
+        role_names.push(format!(
+            "    {{ {role_value}, QByteArrayLiteral(\"{name}\") }}",
+            name = role.name
+        ));
+    }
+    let data_cases = data_cases.join("\n");
+    let set_data_cases = set_data_cases.join("\n");
+    let role_names = role_names.join(",\n");
+
+    // Overrides of the QAbstractItemModel virtuals, which read the rows from Rust
+    result.methods.push(CppFragment::Pair {
+        header: "int rowCount(QModelIndex const& parent = QModelIndex()) const override;"
+            .to_owned(),
+        source: formatdoc! {
+            r#"
+            int
+            {class_name}::rowCount(QModelIndex const& parent) const
+            {{
+              if (parent.isValid()) {{
+                return 0;
+              }}
+              return cxxQtModelRowCount();
+            }}
+            "#
+        },
+    });
+    result.methods.push(CppFragment::Pair {
+        header:
+            "QVariant data(QModelIndex const& index, int role = Qt::DisplayRole) const override;"
+                .to_owned(),
+        source: formatdoc! {
+            r#"
+            QVariant
+            {class_name}::data(QModelIndex const& index, int role) const
+            {{
+              if (!checkIndex(index, QAbstractItemModel::CheckIndexOption::IndexIsValid)) {{
+                return QVariant();
+              }}
+
+              {row_ty} const& row = cxxQtModelRow(index.row());
+              switch (role) {{
+            {data_cases}
+              }}
+              return QVariant();
+            }}
+            "#
+        },
+    });
+    result.methods.push(CppFragment::Pair {
+        header: "bool setData(QModelIndex const& index, QVariant const& value, int role = Qt::EditRole) override;"
+            .to_owned(),
+        source: formatdoc! {
+            r#"
+            bool
+            {class_name}::setData(QModelIndex const& index, QVariant const& value, int role)
+            {{
+              if (!checkIndex(index, QAbstractItemModel::CheckIndexOption::IndexIsValid)) {{
+                return false;
+              }}
+
+              {row_ty} row = cxxQtModelRow(index.row());
+              switch (role) {{
+            {set_data_cases}
+              default:
+                return false;
+              }}
+              cxxQtModelSetRow(index.row(), ::std::move(row));
+              return true;
+            }}
+            "#
+        },
+    });
+    result.methods.push(CppFragment::Pair {
+        header: "Qt::ItemFlags flags(QModelIndex const& index) const override;".to_owned(),
+        source: formatdoc! {
+            r#"
+            Qt::ItemFlags
+            {class_name}::flags(QModelIndex const& index) const
+            {{
+              return {base_class}::flags(index) | Qt::ItemIsEditable;
+            }}
+            "#
+        },
+    });
+    result.methods.push(CppFragment::Pair {
+        header: "QHash<int, QByteArray> roleNames() const override;".to_owned(),
+        source: formatdoc! {
+            r#"
+            QHash<int, QByteArray>
+            {class_name}::roleNames() const
+            {{
+              return {{
+            {role_names}
+              }};
+            }}
+            "#
+        },
+    });
+
+    // Helpers which expose the protected QAbstractItemModel methods to Rust
+    result.methods.push(CppFragment::Header(formatdoc! {
+        r#"
+        void cxxQtModelBeginInsertRows(::std::int32_t first, ::std::int32_t last)
+        {{
+          beginInsertRows(QModelIndex(), first, last);
+        }}
+        void cxxQtModelEndInsertRows()
+        {{
+          endInsertRows();
+        }}
+        void cxxQtModelBeginRemoveRows(::std::int32_t first, ::std::int32_t last)
+        {{
+          beginRemoveRows(QModelIndex(), first, last);
+        }}
+        void cxxQtModelEndRemoveRows()
+        {{
+          endRemoveRows();
+        }}
+        bool cxxQtModelBeginMoveRows(::std::int32_t first, ::std::int32_t last, ::std::int32_t destination)
+        {{
+          return beginMoveRows(QModelIndex(), first, last, QModelIndex(), destination);
+        }}
+        void cxxQtModelEndMoveRows()
+        {{
+          endMoveRows();
+        }}
+        void cxxQtModelBeginResetModel()
+        {{
+          beginResetModel();
+        }}
+        void cxxQtModelEndResetModel()
+        {{
+          endResetModel();
+        }}
+        void cxxQtModelRowChanged(::std::int32_t row)
+        {{
+          Q_EMIT dataChanged(index(row), index(row));
+        }}"#
+    }));
+
+    // Declarations of the Rust functions which access the rows
+    result.private_methods.push(CppFragment::Header(formatdoc! {
+        r#"
+        ::std::int32_t cxxQtModelRowCount() const noexcept;
+        {row_ty} const& cxxQtModelRow(::std::int32_t row) const noexcept;
+        void cxxQtModelSetRow(::std::int32_t row, {row_ty} value) noexcept;"#
+    }));
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::cpp::property::tests::{require_header, require_pair};
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use quote::format_ident;
+    use syn::{parse_quote, ItemStruct};
+
+    #[test]
+    fn test_generate_cpp_qml_model() {
+        let mut item: ItemStruct = parse_quote! {
+            struct Row {
+                #[qrole]
+                name: QString,
+                #[qrole = "itemValue"]
+                value: i32,
+            }
+        };
+        let row = ParsedQmlModelRow::parse(&mut item).unwrap();
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("Row", Some(format_ident!("ffi")), None, None);
+        type_names.mock_insert("QString", None, None, None);
+
+        let generated = generate(
+            &row,
+            DEFAULT_MODEL_BASE_CLASS,
+            &create_qobjectname(),
+            &type_names,
+        )
+        .unwrap();
+
+        assert_eq!(generated.includes.len(), 4);
+        assert!(generated
+            .includes
+            .contains("#include <QtCore/QAbstractListModel>"));
+
+        assert_eq!(generated.methods.len(), 6);
+        let (header, source) = require_pair(&generated.methods[1]).unwrap();
+        assert_str_eq!(
+            header,
+            "QVariant data(QModelIndex const& index, int role = Qt::DisplayRole) const override;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QVariant
+            MyObject::data(QModelIndex const& index, int role) const
+            {
+              if (!checkIndex(index, QAbstractItemModel::CheckIndexOption::IndexIsValid)) {
+                return QVariant();
+              }
+
+              Row const& row = cxxQtModelRow(index.row());
+              switch (role) {
+              case Qt::UserRole:
+                return QVariant::fromValue(row.name);
+              case Qt::UserRole + 1:
+                return QVariant::fromValue(row.value);
+              }
+              return QVariant();
+            }
+            "#}
+        );
+
+        let (_, source) = require_pair(&generated.methods[2]).unwrap();
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            bool
+            MyObject::setData(QModelIndex const& index, QVariant const& value, int role)
+            {
+              if (!checkIndex(index, QAbstractItemModel::CheckIndexOption::IndexIsValid)) {
+                return false;
+              }
+
+              Row row = cxxQtModelRow(index.row());
+              switch (role) {
+              case Qt::UserRole:
+                if (!value.canConvert<QString>()) {
+                  return false;
+                }
+                row.name = value.value<QString>();
+                break;
+              case Qt::UserRole + 1:
+                if (!value.canConvert<::std::int32_t>()) {
+                  return false;
+                }
+                row.value = value.value<::std::int32_t>();
+                break;
+              default:
+                return false;
+              }
+              cxxQtModelSetRow(index.row(), ::std::move(row));
+              return true;
+            }
+            "#}
+        );
+
+        let (_, source) = require_pair(&generated.methods[4]).unwrap();
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QHash<int, QByteArray>
+            MyObject::roleNames() const
+            {
+              return {
+                { Qt::UserRole, QByteArrayLiteral("name") },
+                { Qt::UserRole + 1, QByteArrayLiteral("itemValue") }
+              };
+            }
+            "#}
+        );
+
+        assert!(require_header(&generated.methods[5])
+            .unwrap()
+            .contains("void cxxQtModelRowChanged(::std::int32_t row)"));
+
+        assert_eq!(generated.private_methods.len(), 1);
+        assert_str_eq!(
+            require_header(&generated.private_methods[0]).unwrap(),
+            indoc! {r#"
+            ::std::int32_t cxxQtModelRowCount() const noexcept;
+            Row const& cxxQtModelRow(::std::int32_t row) const noexcept;
+            void cxxQtModelSetRow(::std::int32_t row, Row value) noexcept;"#}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_qml_model_custom_base() {
+        let mut item: ItemStruct = parse_quote! {
+            struct Row {
+                #[qrole]
+                value: i32,
+            }
+        };
+        let row = ParsedQmlModelRow::parse(&mut item).unwrap();
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("Row", Some(format_ident!("ffi")), None, None);
+
+        let generated = generate(&row, "MyListModel", &create_qobjectname(), &type_names).unwrap();
+
+        // The bridge declaring the base class is responsible for its include
+        assert_eq!(generated.includes.len(), 3);
+        assert!(!generated
+            .includes
+            .contains("#include <QtCore/QAbstractListModel>"));
+
+        let (_, source) = require_pair(&generated.methods[3]).unwrap();
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            Qt::ItemFlags
+            MyObject::flags(QModelIndex const& index) const
+            {
+              return MyListModel::flags(index) | Qt::ItemIsEditable;
+            }
+            "#}
+        );
+    }
+}
@@ -5,9 +5,17 @@
 
 use crate::{
     generator::{
+        cfg::{is_qt_version_major, try_filter_cfgs, GeneratedOpt},
         cpp::{
-            constructor, cxxqttype, fragment::CppFragment, inherit, method::generate_cpp_methods,
-            property::generate_cpp_properties, qenum, signal::generate_cpp_signals, threading,
+            constructor, cxxqttype,
+            fragment::CppFragment,
+            inherit,
+            method::{generate_cpp_async_invokables, generate_cpp_methods},
+            property::generate_cpp_properties,
+            qenum, qmlmodel,
+            signal::generate_cpp_signals,
+            singleton, threading,
+            utils::doc_comment,
         },
         naming::{namespace::NamespaceName, qobject::QObjectNames},
         structuring::StructuredQObject,
@@ -15,8 +23,9 @@ use crate::{
     naming::Name,
 };
 use crate::{naming::TypeNames, parser::qobject::ParsedQObject};
+use indoc::formatdoc;
 use std::collections::BTreeSet;
-use syn::Result;
+use syn::{Error, Result};
 
 #[derive(Default)]
 pub struct GeneratedCppQObjectBlocks {
@@ -56,32 +65,112 @@ impl GeneratedCppQObjectBlocks {
         self.private_methods.append(&mut other.private_methods);
     }
 
-    pub fn from(qobject: &ParsedQObject) -> GeneratedCppQObjectBlocks {
-        let mut qml_specifiers = Vec::new();
+    pub fn from(
+        qobject: &ParsedQObject,
+        type_names: &TypeNames,
+    ) -> Result<GeneratedCppQObjectBlocks> {
+        let mut result = GeneratedCppQObjectBlocks::default();
         if let Some(qml_metadata) = &qobject.qml_metadata {
             // Somehow moc doesn't include the info in metatypes.json that qmltyperegistrar needs
             // when using the QML_ELEMENT/QML_NAMED_ELEMENT macros, but moc works when using what
             // those macros expand to.
-            qml_specifiers.push(format!(
-                "Q_CLASSINFO(\"QML.Element\", \"{}\")",
-                qml_metadata.name
-            ));
+            if qml_metadata.interface {
+                // QML_INTERFACE also declares the friends needed for registering the interface
+                result.metaobjects.push("QML_INTERFACE".to_owned());
+
+                // The registration of an interface needs its IID, use the class name as moc
+                // already matches this in qt_metacast so that qobject_cast continues to work
+                let qualified = qobject.name.cxx_qualified();
+                result.fragments.push(CppFragment::Header(format!(
+                    "Q_DECLARE_INTERFACE({qualified}, \"{qualified}\")"
+                )));
+            } else if qml_metadata.anonymous {
+                result
+                    .metaobjects
+                    .push("Q_CLASSINFO(\"QML.Element\", \"anonymous\")".to_owned());
+            } else {
+                result.metaobjects.push(format!(
+                    "Q_CLASSINFO(\"QML.Element\", \"{}\")",
+                    qml_metadata.name
+                ));
+            }
 
             if qml_metadata.uncreatable {
-                qml_specifiers.push("Q_CLASSINFO(\"QML.Creatable\", \"false\")".to_owned());
+                result
+                    .metaobjects
+                    .push("Q_CLASSINFO(\"QML.Creatable\", \"false\")".to_owned());
             }
 
             if qml_metadata.singleton {
-                qml_specifiers.push("QML_SINGLETON".to_owned());
+                result.metaobjects.push("QML_SINGLETON".to_owned());
+            }
+
+            // These macros declare type aliases which the QML type registration uses
+            if let Some(ident) = &qml_metadata.extended {
+                result.metaobjects.push(format!(
+                    "QML_EXTENDED({})",
+                    type_names.cxx_qualified(ident)?
+                ));
+            }
+
+            if let Some(ident) = &qml_metadata.foreign {
+                result
+                    .metaobjects
+                    .push(format!("QML_FOREIGN({})", type_names.cxx_qualified(ident)?));
+            }
+
+            if let Some(ident) = &qml_metadata.attached {
+                let attached = type_names.cxx_qualified(ident)?;
+                result.metaobjects.push(format!("QML_ATTACHED({attached})"));
+
+                // QML creates the attached object for a given object through this static method
+                result.methods.push(CppFragment::Pair {
+                    header: format!("static {attached}* qmlAttachedProperties(QObject* object);"),
+                    source: formatdoc! {
+                        r#"
+                        {attached}*
+                        {class_name}::qmlAttachedProperties(QObject* object)
+                        {{
+                          return new {attached}(object);
+                        }}
+                        "#,
+                        class_name = qobject.name.cxx_unqualified(),
+                    },
+                });
             }
         }
-        GeneratedCppQObjectBlocks {
-            metaobjects: qml_specifiers,
-            ..Default::default()
+        for (key, value) in &qobject.class_info {
+            result.metaobjects.push(format!(
+                "Q_CLASSINFO(\"{}\", \"{}\")",
+                escape_cpp_string(key),
+                escape_cpp_string(value)
+            ));
         }
+        Ok(result)
     }
 }
 
+/// Escape the given string so that it can be used within a C++ string literal
+fn escape_cpp_string(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+    for character in string.chars() {
+        match character {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            // Octal escapes take at most three digits, unlike hex escapes which would consume
+            // any following hex digits
+            character if character.is_ascii_control() => {
+                escaped.push_str(&format!("\\{:03o}", character as u32))
+            }
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
+
 pub struct GeneratedCppQObject {
     /// Name of the QObject, with associated namespace, cxx_name, etc.
     pub name: Name,
@@ -93,14 +182,18 @@ pub struct GeneratedCppQObject {
     pub blocks: GeneratedCppQObjectBlocks,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
+    /// The Doxygen comment of the QObject which is placed before the class definition
+    pub docs: String,
 }
 
 impl GeneratedCppQObject {
     pub fn from(
         structured_qobject: &StructuredQObject,
         type_names: &TypeNames,
+        opt: &GeneratedOpt,
     ) -> Result<GeneratedCppQObject> {
         let qobject = structured_qobject.declaration;
+        let cfg_evaluator = opt.cfg_evaluator.as_deref();
 
         // Create the base object
         let qobject_idents = QObjectNames::from_qobject(qobject, type_names)?;
@@ -109,12 +202,15 @@ impl GeneratedCppQObject {
             name: qobject.name.clone(),
             rust_struct: type_names.lookup(&qobject.rust_type)?.clone(),
             namespace_internals: namespace_idents.internal,
-            blocks: GeneratedCppQObjectBlocks::from(qobject),
+            blocks: GeneratedCppQObjectBlocks::from(qobject, type_names)?,
             has_qobject_macro: qobject.has_qobject_macro,
+            docs: doc_comment(&qobject.docs),
         };
 
         let base_class = if let Some(ident) = &qobject.base_class {
             type_names.lookup(ident)?.cxx_qualified()
+        } else if structured_qobject.model_row.is_some() {
+            qmlmodel::DEFAULT_MODEL_BASE_CLASS.to_string()
         } else if qobject.has_qobject_macro {
             "QObject".to_string()
         } else {
@@ -125,37 +221,109 @@ impl GeneratedCppQObject {
 
         generated.blocks.base_classes.push(base_class.clone());
 
+        // Interfaces are additional base classes, which qobject_cast finds through Q_INTERFACES
+        if !qobject.interfaces.is_empty() {
+            let interfaces = qobject
+                .interfaces
+                .iter()
+                .map(|ident| type_names.cxx_qualified(ident))
+                .collect::<Result<Vec<_>>>()?;
+            generated
+                .blocks
+                .metaobjects
+                .push(format!("Q_INTERFACES({})", interfaces.join(" ")));
+            generated.blocks.base_classes.extend(interfaces);
+        }
+
         // Add the CxxQtType rust and rust_mut methods
         generated
             .blocks
             .append(&mut cxxqttype::generate(&qobject_idents)?);
 
         // Generate methods for the properties, invokables, signals
+        //
+        // Only the members which are enabled by their cfgs are generated, as the Rust code for
+        // any other members is not compiled.
+        let properties = try_filter_cfgs(cfg_evaluator, &qobject.properties, |property| {
+            &property.cfgs
+        })?;
+        // QBindable and QProperty do not exist in Qt 5, so fail here rather than in the C++ code
+        if is_qt_version_major(cfg_evaluator, "5") {
+            if let Some(property) = properties.iter().find(|property| property.flags.bindable) {
+                return Err(Error::new(
+                    property.name.rust_unqualified().span(),
+                    "BINDABLE properties require Qt 6, but the bridge is built against Qt 5",
+                ));
+            }
+        }
         generated.blocks.append(&mut generate_cpp_properties(
-            &qobject.properties,
+            &properties,
             &qobject_idents,
             type_names,
             structured_qobject,
         )?);
-        generated.blocks.append(&mut generate_cpp_methods(
-            &structured_qobject.methods,
+        let (async_methods, methods): (Vec<_>, Vec<_>) = try_filter_cfgs(
+            cfg_evaluator,
+            structured_qobject.methods.iter().copied(),
+            |method| &method.cfgs,
+        )?
+        .into_iter()
+        .partition(|method| method.is_async);
+        generated
+            .blocks
+            .append(&mut generate_cpp_methods(&methods, type_names)?);
+        generated.blocks.append(&mut generate_cpp_async_invokables(
+            &async_methods,
+            &qobject_idents,
+            qobject.qml_metadata.is_some(),
             type_names,
         )?);
         generated.blocks.append(&mut generate_cpp_signals(
-            &structured_qobject.signals,
+            &try_filter_cfgs(
+                cfg_evaluator,
+                structured_qobject.signals.iter().copied(),
+                |signal| &signal.cfgs,
+            )?,
             &qobject_idents,
             type_names,
         )?);
 
         generated.blocks.append(&mut inherit::generate(
-            &structured_qobject.inherited_methods,
+            &try_filter_cfgs(
+                cfg_evaluator,
+                structured_qobject.inherited_methods.iter().copied(),
+                |method| &method.cfgs,
+            )?,
+            &qobject_idents.name,
             &qobject.base_class.as_ref().map(|ident| ident.to_string()),
             type_names,
         )?);
         generated.blocks.append(&mut qenum::generate_on_qobject(
-            structured_qobject.qenums.iter().cloned(),
+            try_filter_cfgs(
+                cfg_evaluator,
+                structured_qobject.qenums.iter().copied(),
+                |qenum| &qenum.cfgs,
+            )?
+            .into_iter(),
         )?);
 
+        if let Some(row) = structured_qobject.model_row {
+            generated.blocks.append(&mut qmlmodel::generate(
+                row,
+                &base_class,
+                &qobject_idents,
+                type_names,
+            )?);
+        }
+
+        // If this singleton is created by a factory then generate the static create method
+        if structured_qobject.singleton_factory.is_some() {
+            generated.blocks.append(&mut singleton::generate(
+                &qobject_idents,
+                &generated.namespace_internals,
+            )?);
+        }
+
         let mut class_initializers = vec![];
 
         // If this type has threading enabled then add generation
@@ -182,8 +350,11 @@ impl GeneratedCppQObject {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generator::cpp::property::tests::{require_header, require_pair};
     use crate::generator::mock_qml_singleton;
     use crate::{generator::structuring::Structures, parser::Parser};
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
     use quote::format_ident;
     use syn::{parse_quote, ItemMod};
 
@@ -201,9 +372,12 @@ mod tests {
         let parser = Parser::from(module).unwrap();
         let structures = Structures::new(&parser.cxx_qt_data).unwrap();
 
-        let cpp =
-            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
-                .unwrap();
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &TypeNames::mock(),
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
         assert_eq!(cpp.name.cxx_unqualified(), "MyObject");
         assert_eq!(cpp.rust_struct.cxx_unqualified(), "MyObjectRust");
         assert_eq!(cpp.namespace_internals, "cxx_qt_MyObject");
@@ -240,8 +414,12 @@ mod tests {
             None,
         );
 
-        let cpp =
-            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &type_names).unwrap();
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
         assert_eq!(cpp.namespace_internals, "cxx_qt::cxx_qt_MyObject");
         assert_eq!(cpp.blocks.base_classes.len(), 2);
         assert_eq!(cpp.blocks.base_classes[0], "QStringListModel");
@@ -270,8 +448,12 @@ mod tests {
         let mut type_names = TypeNames::default();
         type_names.mock_insert("MyNamedObject", None, None, None);
         type_names.mock_insert("MyNamedObjectRust", None, None, None);
-        let cpp =
-            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &type_names).unwrap();
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
         assert_eq!(cpp.name.cxx_unqualified(), "MyNamedObject");
         assert_eq!(cpp.blocks.metaobjects.len(), 1);
         assert_eq!(
@@ -286,9 +468,12 @@ mod tests {
         let parser = Parser::from(module).unwrap();
         let structures = Structures::new(&parser.cxx_qt_data).unwrap();
 
-        let cpp =
-            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
-                .unwrap();
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &TypeNames::mock(),
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
         assert_eq!(cpp.name.cxx_unqualified(), "MyObject");
         assert_eq!(cpp.blocks.metaobjects.len(), 2);
         assert_eq!(
@@ -314,9 +499,12 @@ mod tests {
         let parser = Parser::from(module).unwrap();
         let structures = Structures::new(&parser.cxx_qt_data).unwrap();
 
-        let cpp =
-            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
-                .unwrap();
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &TypeNames::mock(),
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
         assert_eq!(cpp.name.cxx_unqualified(), "MyObject");
         assert_eq!(cpp.blocks.metaobjects.len(), 2);
         assert_eq!(
@@ -328,4 +516,249 @@ mod tests {
             "Q_CLASSINFO(\"QML.Creatable\", \"false\")"
         );
     }
+
+    #[test]
+    fn test_generated_cpp_qobject_anonymous() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_anonymous]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &TypeNames::mock(),
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 1);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"anonymous\")"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_interface() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_interface]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &TypeNames::mock(),
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 1);
+        assert_eq!(cpp.blocks.metaobjects[0], "QML_INTERFACE");
+        assert_eq!(cpp.blocks.fragments.len(), 1);
+        assert_eq!(
+            require_header(&cpp.blocks.fragments[0]).unwrap(),
+            "Q_DECLARE_INTERFACE(cxx_qt::MyObject, \"cxx_qt::MyObject\")"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_qml_types() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_extended = MyExtension]
+                    #[qml_foreign = QLine]
+                    #[qml_attached = MyAttached]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("MyExtension", None, None, Some("cxx_qt"));
+        type_names.mock_insert("QLine", None, None, None);
+        type_names.mock_insert("MyAttached", None, None, Some("cxx_qt"));
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 4);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "QML_EXTENDED(cxx_qt::MyExtension)"
+        );
+        assert_eq!(cpp.blocks.metaobjects[2], "QML_FOREIGN(QLine)");
+        assert_eq!(
+            cpp.blocks.metaobjects[3],
+            "QML_ATTACHED(cxx_qt::MyAttached)"
+        );
+
+        let (header, source) = require_pair(&cpp.blocks.methods[0]).unwrap();
+        assert_eq!(
+            header,
+            "static cxx_qt::MyAttached* qmlAttachedProperties(QObject* object);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            cxx_qt::MyAttached*
+            MyObject::qmlAttachedProperties(QObject* object)
+            {
+              return new cxx_qt::MyAttached(object);
+            }
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_interfaces() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qinterfaces(QQmlParserStatus, MyInterface)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QQmlParserStatus", None, None, None);
+        type_names.mock_insert("MyInterface", None, None, Some("my_namespace"));
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.base_classes.len(), 4);
+        assert_eq!(cpp.blocks.base_classes[0], "QObject");
+        assert_eq!(cpp.blocks.base_classes[1], "QQmlParserStatus");
+        assert_eq!(cpp.blocks.base_classes[2], "my_namespace::MyInterface");
+        assert_eq!(
+            cpp.blocks.base_classes[3],
+            "::rust::cxxqt1::CxxQtType<MyObjectRust>"
+        );
+        assert_eq!(cpp.blocks.metaobjects.len(), 1);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_INTERFACES(QQmlParserStatus my_namespace::MyInterface)"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_class_info() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qclassinfo(DefaultProperty = "children")]
+                    #[qclassinfo("D-Bus Interface" = "org.example.\"quoted\"")]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &TypeNames::mock(),
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 3);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "Q_CLASSINFO(\"DefaultProperty\", \"children\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[2],
+            r#"Q_CLASSINFO("D-Bus Interface", "org.example.\"quoted\"")"#
+        );
+    }
+
+    /// A [CfgEvaluator](crate::CfgEvaluator) for a build against the given major version of Qt
+    struct QtVersionCfgEvaluator(&'static str);
+
+    impl crate::CfgEvaluator for QtVersionCfgEvaluator {
+        fn eval(&self, name: &str, value: Option<&str>) -> crate::CfgResult {
+            crate::CfgResult::from(name == "cxxqt_qt_version_major" && value == Some(self.0))
+        }
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_bindable_qt_version() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number, READ, WRITE, NOTIFY, BINDABLE)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let qobject = structures.qobjects.first().unwrap();
+
+        let opt = GeneratedOpt {
+            cfg_evaluator: Some(Box::new(QtVersionCfgEvaluator("6"))),
+        };
+        assert!(GeneratedCppQObject::from(qobject, &TypeNames::mock(), &opt).is_ok());
+
+        let opt = GeneratedOpt {
+            cfg_evaluator: Some(Box::new(QtVersionCfgEvaluator("5"))),
+        };
+        assert!(GeneratedCppQObject::from(qobject, &TypeNames::mock(), &opt).is_err());
+    }
+
+    #[test]
+    fn test_escape_cpp_string() {
+        assert_eq!(escape_cpp_string("plain"), "plain");
+        assert_eq!(
+            escape_cpp_string("back\\slash \"quoted\""),
+            r#"back\\slash \"quoted\""#
+        );
+        assert_eq!(
+            escape_cpp_string("line\nreturn\rtab\t"),
+            r#"line\nreturn\rtab\t"#
+        );
+        assert_eq!(escape_cpp_string("bell\u{7}1"), r#"bell\0071"#);
+    }
 }
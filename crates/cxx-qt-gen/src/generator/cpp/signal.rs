@@ -5,14 +5,14 @@
 
 use crate::{
     generator::{
-        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks, utils::doc_comment},
         naming::{
             qobject::QObjectNames,
             signals::{QSignalHelperNames, QSignalNames},
         },
     },
     naming::{cpp::syn_type_to_cpp_type, Name, TypeNames},
-    parser::{parameter::ParsedFunctionParameter, signals::ParsedSignal},
+    parser::{parameter::ParsedFunctionParameter, signals::ParsedSignal, AccessSpecifier},
 };
 use indoc::formatdoc;
 use std::collections::BTreeSet;
@@ -30,6 +30,8 @@ pub struct CppSignalFragment {
     pub fragments: Vec<CppFragment>,
     /// Any methods for the class
     pub methods: Vec<CppFragment>,
+    /// Any private methods for the class
+    pub private_methods: Vec<CppFragment>,
 }
 
 /// Combined output of possible parameter lines to be used
@@ -42,6 +44,8 @@ struct Parameters {
     types_with_self: String,
     /// Raw ::std::move values of the parameters including self
     values_with_self: String,
+    /// Names of the parameters
+    names: Vec<String>,
 }
 
 /// From given parameters, mappings, and self value constructor the combined parameter lines
@@ -53,6 +57,7 @@ fn parameter_types_and_values(
     let mut parameter_named_types_with_self = vec![];
     let mut parameter_types_with_self = vec![];
     let mut parameter_values_with_self = vec![];
+    let mut parameter_names = vec![];
 
     for parameter in parameters {
         let cxx_ty = syn_type_to_cpp_type(&parameter.ty, type_names)?;
@@ -60,6 +65,7 @@ fn parameter_types_and_values(
         parameter_named_types_with_self.push(format!("{cxx_ty} {ident_str}",));
         parameter_types_with_self.push(cxx_ty.clone());
         parameter_values_with_self.push(format!("::std::move({ident_str})"));
+        parameter_names.push(ident_str);
     }
 
     let parameter_named_types = parameter_named_types_with_self.join(", ");
@@ -75,6 +81,7 @@ fn parameter_types_and_values(
         named_types_with_self: parameter_named_types_with_self.join(", "),
         types_with_self: parameter_types_with_self.join(", "),
         values_with_self: parameter_values_with_self.join(", "),
+        names: parameter_names,
     })
 }
 
@@ -106,6 +113,7 @@ pub fn generate_cpp_signal(
     let parameters_named_types_with_self = parameters.named_types_with_self;
     let parameter_types_with_self = parameters.types_with_self;
     let parameter_values_with_self = parameters.values_with_self;
+    let parameter_names = parameters.names;
 
     let param_struct = idents_helper.struct_param;
     let signal_handler_alias = idents_helper.handler_alias;
@@ -126,9 +134,52 @@ pub fn generate_cpp_signal(
 
     // Generate the Q_SIGNAL if this is not an existing signal
     if !signal.inherit {
+        // Private signals have an extra QPrivateSignal parameter, which only the QObject itself
+        // can construct, so they are emitted by a friend function
+        let private = signal.access == AccessSpecifier::Private;
+        let signal_parameters = match (private, parameters_named_types.is_empty()) {
+            (true, true) => "QPrivateSignal".to_owned(),
+            (true, false) => format!("{parameters_named_types}, QPrivateSignal"),
+            (false, _) => parameters_named_types.clone(),
+        };
         generated.methods.push(CppFragment::Header(format!(
-            "Q_SIGNAL void {signal_ident}({parameters_named_types});"
+            "{docs}Q_SIGNAL void {signal_ident}({signal_parameters});",
+            docs = doc_comment(&signal.docs)
         )));
+
+        if private {
+            let emit_ident_cpp = idents_helper.emit_name.cxx_unqualified();
+            let emit_values = parameter_names
+                .into_iter()
+                .chain([format!("{qobject_ident_namespaced}::QPrivateSignal()")])
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            generated.private_methods.push(CppFragment::Header(format!(
+                "friend void ::{namespace}::{emit_ident_cpp}({parameters_named_types_with_self});"
+            )));
+            generated.fragments.push(CppFragment::Pair {
+                header: formatdoc! {
+                    r#"
+                    namespace {namespace} {{
+                    void
+                    {emit_ident_cpp}({parameters_named_types_with_self});
+                    }} // namespace {namespace}
+                    "#
+                },
+                source: formatdoc! {
+                    r#"
+                    namespace {namespace} {{
+                    void
+                    {emit_ident_cpp}({parameters_named_types_with_self})
+                    {{
+                        Q_EMIT self.{signal_ident}({emit_values});
+                    }}
+                    }} // namespace {namespace}
+                    "#
+                },
+            });
+        }
     }
 
     generated.fragments.push(CppFragment::Pair {
@@ -202,6 +253,7 @@ pub fn generate_cpp_signals(
         block.forward_declares_namespaced = data.forward_declares;
         block.fragments = data.fragments;
         block.methods = data.methods;
+        block.private_methods = data.private_methods;
         generated.append(&mut block);
     }
 
@@ -527,6 +579,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_cpp_signal_private() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qsignal(private)]
+            fn value_changed(self: Pin<&mut MyObject>, value: i32);
+        };
+        let signal = ParsedSignal::mock(&method);
+
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("MyObject", None, None, None);
+        let qobject_name = type_names.lookup(&signal.qobject_ident).unwrap();
+        let generated = generate_cpp_signal(&signal, qobject_name, &type_names).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_SIGNAL void value_changed(::std::int32_t value, QPrivateSignal);"
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "friend void ::rust::cxxqtgen1::MyObject_value_changedEmit(MyObject& self, ::std::int32_t value);"
+        );
+
+        assert_eq!(generated.fragments.len(), 2);
+        let (header, source) = require_pair(&generated.fragments[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            namespace rust::cxxqtgen1 {
+            void
+            MyObject_value_changedEmit(MyObject& self, ::std::int32_t value);
+            } // namespace rust::cxxqtgen1
+            "#}
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            namespace rust::cxxqtgen1 {
+            void
+            MyObject_value_changedEmit(MyObject& self, ::std::int32_t value)
+            {
+                Q_EMIT self.value_changed(value, MyObject::QPrivateSignal());
+            }
+            } // namespace rust::cxxqtgen1
+            "#}
+        );
+    }
+
     #[test]
     fn test_generate_cpp_signal_free_mapped() {
         let method = parse_quote! {
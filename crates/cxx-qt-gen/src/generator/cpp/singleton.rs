@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+    naming::qobject::QObjectNames,
+};
+use indoc::formatdoc;
+use syn::Result;
+
+pub fn generate(
+    qobject_idents: &QObjectNames,
+    namespace_internals: &str,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut result = GeneratedCppQObjectBlocks::default();
+
+    let class_name = qobject_idents.name.cxx_unqualified();
+
+    // The QML engine uses this static method instead of the default constructor to create the
+    // singleton, the engine is given to the cxx_qt::SingletonFactory implementation in Rust
+    result.methods.push(CppFragment::Pair {
+        header: format!("static {class_name}* create(QQmlEngine* qmlEngine, QJSEngine* jsEngine);"),
+        source: formatdoc! {
+            r#"
+            {class_name}*
+            {class_name}::create(QQmlEngine* qmlEngine, QJSEngine* jsEngine)
+            {{
+              Q_UNUSED(jsEngine);
+              return ::{namespace_internals}::createSingleton(*qmlEngine);
+            }}
+            "#
+        },
+    });
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::cpp::property::tests::require_pair;
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn test_generate_cpp_singleton() {
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate(&qobject_idents, "cxx_qt::cxx_qt_MyObject").unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = require_pair(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "static MyObject* create(QQmlEngine* qmlEngine, QJSEngine* jsEngine);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            MyObject*
+            MyObject::create(QQmlEngine* qmlEngine, QJSEngine* jsEngine)
+            {
+              Q_UNUSED(jsEngine);
+              return ::cxx_qt::cxx_qt_MyObject::createSingleton(*qmlEngine);
+            }
+            "#}
+        );
+    }
+}
@@ -3,6 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::syntax::expr::expr_to_string;
+use syn::Attribute;
+
 /// A trait to allow indenting multi-line string
 /// This is specifically useful when using formatdoc! with a multi-line string argument.
 /// As the formatdoc! formatting doesn't support indenting multi-line arguments, we can indent
@@ -20,12 +23,28 @@ impl Indent for str {
     }
 }
 
+/// Convert the given `#[doc = "..."]` attributes into a Doxygen comment
+///
+/// Each line of the comment is terminated with a newline, so that the result can be placed
+/// directly before a declaration. If there are no docs then this is an empty string.
+pub(crate) fn doc_comment(docs: &[Attribute]) -> String {
+    docs.iter()
+        .filter_map(|attr| expr_to_string(&attr.meta.require_name_value().ok()?.value).ok())
+        .flat_map(|doc| {
+            doc.split('\n')
+                .map(|line| format!("///{}\n", line.trim_end()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use indoc::{formatdoc, indoc};
     use pretty_assertions::assert_str_eq;
+    use syn::parse_quote;
 
     #[test]
     fn indent_string() {
@@ -48,4 +67,24 @@ mod tests {
         "#}
         );
     }
+
+    #[test]
+    fn doc_comment_lines() {
+        let docs: Vec<Attribute> = vec![
+            parse_quote! { #[doc = " A summary"] },
+            parse_quote! { #[doc = ""] },
+            parse_quote! { #[doc = " More details\n over two lines  "] },
+        ];
+
+        assert_str_eq!(
+            doc_comment(&docs),
+            indoc! { r#"
+            /// A summary
+            ///
+            /// More details
+            /// over two lines
+        "#}
+        );
+        assert_str_eq!(doc_comment(&[]), "");
+    }
 }
@@ -5,6 +5,7 @@
 
 #[cfg(test)]
 use syn::{parse_quote, ItemMod};
+pub mod cfg;
 pub mod cpp;
 pub mod naming;
 pub mod rust;
@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use crate::{naming::Name, parser::method::ParsedMethod};
+use quote::format_ident;
+use syn::Ident;
+
+/// Names for the helpers of an async Q_INVOKABLE
+pub struct QAsyncInvokableNames {
+    /// The C++ alias of the promise which resolves the QFuture returned by the invokable
+    pub promise_alias: Ident,
+    /// The private method which spawns the future of the invokable
+    pub spawn_name: Name,
+}
+
+impl QAsyncInvokableNames {
+    pub fn new(method: &ParsedMethod, qobject_name: &Name) -> Self {
+        let method_ident = method.name.cxx_unqualified();
+        let qobject_ident = qobject_name.rust_unqualified();
+
+        Self {
+            promise_alias: format_ident!("{qobject_ident}CxxQtPromise{method_ident}"),
+            spawn_name: method
+                .name
+                .clone()
+                .with_rust_name(format_ident!(
+                    "cxx_qt_ffi_spawn_{}",
+                    method.name.rust_unqualified()
+                ))
+                .with_cxx_name(format!("{method_ident}CxxQtSpawn")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::CaseConversion;
+    use crate::syntax::safety::Safety;
+    use syn::{parse_quote, ForeignItemFn};
+
+    #[test]
+    fn test_async_invokable_names() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            #[cxx_name = "fetchData"]
+            async fn fetch_data(self: &MyObject) -> i32;
+        };
+        let method = ParsedMethod::parse(method, Safety::Safe, CaseConversion::none()).unwrap();
+
+        let names = QAsyncInvokableNames::new(&method, &Name::mock("MyObject"));
+        assert_eq!(
+            names.promise_alias,
+            format_ident!("MyObjectCxxQtPromisefetchData")
+        );
+        assert_eq!(
+            names.spawn_name.rust_unqualified(),
+            &format_ident!("cxx_qt_ffi_spawn_fetch_data")
+        );
+        assert_eq!(names.spawn_name.cxx_unqualified(), "fetchDataCxxQtSpawn");
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     parser::property::{FlagState, ParsedQProperty},
 };
 use quote::format_ident;
-use syn::Result;
+use syn::{Ident, Result};
 
 use crate::generator::structuring::StructuredQObject;
 use core::ops::Deref;
@@ -54,7 +54,54 @@ pub struct QPropertyNames {
     pub getter: NameState,
     pub setter: Option<NameState>,
     pub notify: Option<NameState>,
-    pub reset: Option<Name>,
+    pub reset: Option<NameState>,
+    pub bindable: Option<QPropertyBindableNames>,
+}
+
+/// Names for the parts of a BINDABLE Q_PROPERTY
+pub struct QPropertyBindableNames {
+    /// The `QBindable<T>` accessor used by the Q_PROPERTY
+    pub accessor: Name,
+    /// The accessor returning a `QUntypedBindable` for use from Rust
+    pub untyped: Name,
+    /// Bind the property to follow another bindable
+    pub bind: Name,
+    /// Write the value of the Rust field into the bindable
+    pub update: Name,
+    /// Write the value of the bindable into the Rust field
+    pub set_from_bindable: Name,
+}
+
+impl QPropertyBindableNames {
+    fn from_property(name: &Name) -> Self {
+        let cxx_name = capitalise_first(name.cxx_unqualified());
+        let rust_name = name.rust_unqualified();
+        let with_names =
+            |rust: Ident, cxx: String| name.clone().with_rust_name(rust).with_cxx_name(cxx);
+
+        Self {
+            accessor: with_names(
+                format_ident!("bindable_{rust_name}"),
+                format!("bindable{cxx_name}"),
+            ),
+            untyped: with_names(
+                format_ident!("bindable_{rust_name}"),
+                format!("cxxQtBindable{cxx_name}"),
+            ),
+            bind: with_names(
+                format_ident!("bind_{rust_name}"),
+                format!("cxxQtBind{cxx_name}"),
+            ),
+            update: with_names(
+                format_ident!("cxx_qt_update_{rust_name}_bindable"),
+                format!("cxxQtUpdate{cxx_name}Bindable"),
+            ),
+            set_from_bindable: with_names(
+                format_ident!("cxx_qt_set_{rust_name}_from_bindable"),
+                format!("cxxQtSet{cxx_name}FromBindable"),
+            ),
+        }
+    }
 }
 
 impl QPropertyNames {
@@ -103,14 +150,26 @@ impl QPropertyNames {
         let reset = flags
             .reset
             .as_ref()
-            .map(|ident| structured_qobject.method_lookup(ident))
+            .map(|reset| {
+                NameState::from_flag_with_auto_fn(
+                    reset,
+                    || reset_name_from_property(property_name),
+                    structured_qobject,
+                    false,
+                )
+            })
             .transpose()?;
 
+        let bindable = flags
+            .bindable
+            .then(|| QPropertyBindableNames::from_property(property_name));
+
         Ok(Self {
             getter,
             setter,
             notify,
             reset,
+            bindable,
             name: property_name.clone(),
         })
     }
@@ -145,6 +204,13 @@ fn notify_name_from_property(name: &Name) -> Name {
         .with_cxx_name(format!("{}Changed", name.cxx_unqualified()))
 }
 
+/// For a given property name generate the reset name
+fn reset_name_from_property(name: &Name) -> Name {
+    name.clone()
+        .with_rust_name(format_ident!("reset_{}", name.rust_unqualified()))
+        .with_cxx_name(format!("reset{}", capitalise_first(name.cxx_unqualified())))
+}
+
 #[cfg(test)]
 pub mod tests {
     use syn::parse_quote;
@@ -158,6 +224,8 @@ pub mod tests {
             name: Name::mock_name_with_cxx("my_property", "myProperty"),
             ty: parse_quote! { i32 },
             flags: QPropertyFlags::default(),
+            docs: vec![],
+            cfgs: vec![],
         };
 
         let obj = ParsedQObject::mock();
@@ -192,6 +260,66 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_parsed_property_auto_reset() {
+        let property = ParsedQProperty {
+            name: Name::mock_name_with_cxx("my_property", "myProperty"),
+            ty: parse_quote! { i32 },
+            flags: QPropertyFlags {
+                reset: Some(FlagState::Auto),
+                ..QPropertyFlags::default()
+            },
+            docs: vec![],
+            cfgs: vec![],
+        };
+
+        let obj = ParsedQObject::mock();
+        let structured_qobject = StructuredQObject::mock(&obj);
+        let names = QPropertyNames::try_from_property(&property, &structured_qobject).unwrap();
+
+        let reset = names.reset.unwrap();
+        assert!(matches!(reset, NameState::Auto(_)));
+        assert_eq!(reset.cxx_unqualified(), "resetMyProperty");
+        assert_eq!(reset.rust_unqualified(), "reset_my_property");
+    }
+
+    #[test]
+    fn test_parsed_property_bindable() {
+        let property = ParsedQProperty {
+            name: Name::mock_name_with_cxx("my_property", "myProperty"),
+            ty: parse_quote! { i32 },
+            flags: QPropertyFlags {
+                bindable: true,
+                ..QPropertyFlags::default()
+            },
+            docs: vec![],
+            cfgs: vec![],
+        };
+
+        let obj = ParsedQObject::mock();
+        let structured_qobject = StructuredQObject::mock(&obj);
+        let names = QPropertyNames::try_from_property(&property, &structured_qobject).unwrap();
+
+        let bindable = names.bindable.unwrap();
+        assert_eq!(bindable.accessor.cxx_unqualified(), "bindableMyProperty");
+        assert_eq!(
+            bindable.untyped.cxx_unqualified(),
+            "cxxQtBindableMyProperty"
+        );
+        assert_eq!(bindable.untyped.rust_unqualified(), "bindable_my_property");
+        assert_eq!(bindable.bind.cxx_unqualified(), "cxxQtBindMyProperty");
+        assert_eq!(bindable.bind.rust_unqualified(), "bind_my_property");
+        assert_eq!(
+            bindable.update.rust_unqualified(),
+            "cxx_qt_update_my_property_bindable"
+        );
+        assert_eq!(
+            bindable.set_from_bindable.cxx_unqualified(),
+            "cxxQtSetMyPropertyFromBindable"
+        );
+        assert!(create_i32_qpropertyname().bindable.is_none());
+    }
+
     #[test]
     fn test_capitalise_first() {
         assert_eq!(capitalise_first("abc".to_owned()), "Abc".to_owned());
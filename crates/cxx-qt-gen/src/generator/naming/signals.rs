@@ -11,6 +11,8 @@ pub struct QSignalNames {
     pub name: Name,
     pub connect_name: Name,
     pub on_name: Ident,
+    pub stream_name: Ident,
+    pub next_name: Ident,
 }
 
 impl From<&ParsedSignal> for QSignalNames {
@@ -19,6 +21,8 @@ impl From<&ParsedSignal> for QSignalNames {
             name: signal.name.clone(),
             connect_name: connect_name_from_signal(&signal.name),
             on_name: on_from_signal(signal.name.rust_unqualified()),
+            stream_name: format_ident!("{}_stream", signal.name.rust_unqualified()),
+            next_name: format_ident!("next_{}", signal.name.rust_unqualified()),
         }
     }
 }
@@ -37,6 +41,8 @@ fn on_from_signal(ident: &Ident) -> Ident {
 
 pub struct QSignalHelperNames {
     pub connect_name: Name,
+    /// The free function which emits a private signal, as only friends can construct QPrivateSignal
+    pub emit_name: Name,
     pub function_call: Ident,
     pub function_drop: Ident,
     pub handler_alias: Ident,
@@ -83,10 +89,22 @@ impl QSignalHelperNames {
             idents.connect_name.cxx_unqualified()
         ));
 
+        let emit_name = Name::new(format_ident!(
+            "{}_emit_{}",
+            qobject_name.rust_unqualified(),
+            idents.name.rust_unqualified()
+        ))
+        .with_cxx_name(format!(
+            "{}_{}Emit",
+            qobject_name.cxx_unqualified(),
+            idents.name.cxx_unqualified()
+        ));
+
         // TODO: in the future we might improve the naming of the methods
         // to avoid collisions (maybe use a separator similar to how CXX uses $?)
         Ok(Self {
             connect_name,
+            emit_name,
             function_drop: format_ident!("drop_{qobject_ident}_signal_handler_{signal_ident}"),
             function_call: format_ident!("call_{qobject_ident}_signal_handler_{signal_ident}"),
             handler_alias_namespaced: format!("::{namespace}::{handler_alias}"),
@@ -124,6 +142,8 @@ mod tests {
             &format_ident!("connect_data_changed")
         );
         assert_eq!(names.on_name, format_ident!("on_data_changed"));
+        assert_eq!(names.stream_name, format_ident!("data_changed_stream"));
+        assert_eq!(names.next_name, format_ident!("next_data_changed"));
     }
 
     #[test]
@@ -147,4 +167,31 @@ mod tests {
         );
         assert_eq!(names.on_name, format_ident!("on_existing_signal"));
     }
+
+    #[test]
+    fn test_signal_helper_names() {
+        let method = parse_quote! {
+            #[cxx_name = "dataChanged"]
+            fn data_changed(self: Pin<&mut MyObject>);
+        };
+        let qsignal = ParsedSignal::mock(&method);
+
+        let names = QSignalNames::from(&qsignal);
+        let helper_names =
+            QSignalHelperNames::new(&names, &Name::mock_namespaced("MyObject", "my_namespace"))
+                .unwrap();
+        assert_eq!(
+            helper_names.connect_name.cxx_unqualified(),
+            "MyObject_dataChangedConnect"
+        );
+        assert_eq!(
+            helper_names.emit_name.rust_unqualified(),
+            &format_ident!("MyObject_emit_data_changed")
+        );
+        assert_eq!(
+            helper_names.emit_name.cxx_unqualified(),
+            "MyObject_dataChangedEmit"
+        );
+        assert_eq!(helper_names.namespace, "my_namespace::rust::cxxqtgen1");
+    }
 }
@@ -28,6 +28,7 @@ impl GeneratedRustFragment {
         };
 
         // Add the pass through blocks
+        let cfgs = &extern_cxxqt_block.cfgs;
         let unsafety = &extern_cxxqt_block.unsafety;
         let items = &extern_cxxqt_block.passthrough_items;
         let types = &extern_cxxqt_block
@@ -68,6 +69,7 @@ impl GeneratedRustFragment {
 
         let fragment = RustFragmentPair {
             cxx_bridge: vec![quote! {
+                #(#cfgs)*
                 #extern_block_namespace
                 #unsafety extern "C++" {
                     #(#items)*
@@ -5,64 +5,121 @@
 
 use crate::{
     generator::{naming::qobject::QObjectNames, rust::fragment::GeneratedRustFragment},
-    parser::inherit::ParsedInheritedMethod,
+    naming::{rust::syn_type_cxx_bridge_to_qualified, TypeNames},
+    parser::{inherit::ParsedInheritedMethod, AccessSpecifier},
 };
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, Item, Result};
+use syn::{parse_quote_spanned, spanned::Spanned, Item, Result, ReturnType};
 
 pub fn generate(
     qobject_names: &QObjectNames,
     methods: &[&ParsedInheritedMethod],
+    type_names: &TypeNames,
 ) -> Result<GeneratedRustFragment> {
     let mut blocks = GeneratedRustFragment::default();
     let qobject_name = qobject_names.name.rust_unqualified();
 
-    let mut bridges = methods
-        .iter()
-        .map(|method| {
-            let parameters = method
-                .parameters
-                .iter()
-                .map(|parameter| {
-                    let ident = &parameter.ident;
-                    let ty = &parameter.ty;
-                    quote! { #ident: #ty }
-                })
-                .collect::<Vec<TokenStream>>();
-
-            let ident = &method.method_fields.name.rust_unqualified();
-            let cxx_name_string = &method.wrapper_ident().to_string();
-            let self_param = if method.mutable {
-                quote! { self: Pin<&mut #qobject_name> }
-            } else {
-                quote! { self: &#qobject_name }
-            };
-            let return_type = &method.method.sig.output;
-
-            let mut unsafe_block = None;
-            let mut unsafe_call = Some(quote! { unsafe });
-            if method.safe {
-                std::mem::swap(&mut unsafe_call, &mut unsafe_block);
-            }
-            let doc_comments = &method.docs;
-            let cfgs = &method.cfgs;
-            let namespace = qobject_names.namespace_tokens();
+    for method in methods {
+        let parameters = method
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let ident = &parameter.ident;
+                let ty = &parameter.ty;
+                quote! { #ident: #ty }
+            })
+            .collect::<Vec<TokenStream>>();
+
+        let ident = &method.method_fields.name.rust_unqualified();
+        let cxx_name_string = &method.wrapper_ident().to_string();
+        let self_type = if method.mutable {
+            quote! { Pin<&mut #qobject_name> }
+        } else {
+            quote! { &#qobject_name }
+        };
+        let return_type = &method.method.sig.output;
+
+        let mut unsafe_block = None;
+        let mut unsafe_call = Some(quote! { unsafe });
+        if method.safe {
+            std::mem::swap(&mut unsafe_call, &mut unsafe_block);
+        }
+        let doc_comments = &method.docs;
+        let cfgs = &method.cfgs;
+        let namespace = qobject_names.namespace_tokens();
 
-            syn::parse2(quote_spanned! {
+        if method.access == AccessSpecifier::Public {
+            blocks.cxx_mod_contents.push(syn::parse2(quote_spanned! {
                 method.method.span() =>
                 #unsafe_block extern "C++" {
                     #[cxx_name = #cxx_name_string]
                     #namespace
                     #(#cfgs)*
                     #(#doc_comments)*
-                    #unsafe_call fn #ident(#self_param, #(#parameters),*) #return_type;
+                    #unsafe_call fn #ident(self: #self_type, #(#parameters),*) #return_type;
                 }
+            })?);
+            continue;
+        }
+
+        // A protected method is called through a friend function of the QObject in C++,
+        // so the Rust method has the visibility of the declaration instead of being public
+        let friend_wrapper = method.friend_wrapper_name();
+        let friend_ident_rust = friend_wrapper.rust_unqualified();
+        let friend_ident_cpp = friend_wrapper.cxx_unqualified();
+        let module_ident = qobject_names.name.require_module()?;
+        let qualified_impl = qobject_names.name.rust_qualified();
+        let self_type_qualified =
+            syn_type_cxx_bridge_to_qualified(&syn::parse2(self_type.clone())?, type_names)?;
+        let parameters_qualified = method
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let ident = &parameter.ident;
+                let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+                Ok(quote! { #ident: #ty })
             })
-        })
-        .collect::<Result<Vec<Item>>>()?;
+            .collect::<Result<Vec<TokenStream>>>()?;
+        let parameter_names = method.parameters.iter().map(|parameter| &parameter.ident);
+        let return_type_qualified = match return_type {
+            ReturnType::Type(arrow, ty) => {
+                let ty = syn_type_cxx_bridge_to_qualified(ty, type_names)?;
+                quote! { #arrow #ty }
+            }
+            ReturnType::Default => quote! {},
+        };
+        let call = quote! { #module_ident::#friend_ident_rust(self, #(#parameter_names),*) };
+        let body = if method.safe {
+            call
+        } else {
+            quote! { unsafe { #call } }
+        };
+        let vis = &method.method.vis;
+
+        blocks.cxx_mod_contents.push(syn::parse2(quote_spanned! {
+            method.method.span() =>
+            #unsafe_block extern "C++" {
+                #[doc(hidden)]
+                #[cxx_name = #friend_ident_cpp]
+                #namespace
+                #(#cfgs)*
+                #unsafe_call fn #friend_ident_rust(self_value: #self_type, #(#parameters),*) #return_type;
+            }
+        })?);
+        let wrapper: Item = parse_quote_spanned! {
+            method.method.span() =>
+            #(#cfgs)*
+            impl #qualified_impl {
+                #(#doc_comments)*
+                #vis #unsafe_call fn #ident(self: #self_type_qualified, #(#parameters_qualified),*) #return_type_qualified {
+                    #body
+                }
+            }
+        };
+        blocks.cxx_qt_mod_contents.push(wrapper);
+    }
 
-    blocks.cxx_mod_contents.append(&mut bridges);
     Ok(blocks)
 }
 
@@ -82,7 +139,11 @@ mod tests {
     ) -> Result<GeneratedRustFragment> {
         let method = ParsedInheritedMethod::parse(method, safety, CaseConversion::none())?;
         let inherited_methods = vec![&method];
-        generate(&create_qobjectname(), &inherited_methods)
+        generate(
+            &create_qobjectname(),
+            &inherited_methods,
+            &TypeNames::mock(),
+        )
     }
 
     #[test]
@@ -157,4 +218,74 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_protected() {
+        let generated = generate_from_foreign(
+            parse_quote! {
+                #[inherit(protected)]
+                #[cxx_name = "beginResetModel"]
+                fn begin_reset_model(self: Pin<&mut MyObject>, a: i32) -> bool;
+            },
+            Safety::Safe,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "MyObject_beginResetModelCxxQtInherit"]
+                    fn MyObject_inherit_begin_reset_model(self_value: Pin<&mut MyObject>, a: i32) -> bool;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    fn begin_reset_model(self: core::pin::Pin<&mut qobject::MyObject>, a: i32) -> bool {
+                        qobject::MyObject_inherit_begin_reset_model(self, a)
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_protected_unsafe() {
+        let generated = generate_from_foreign(
+            parse_quote! {
+                #[inherit(protected)]
+                pub(crate) unsafe fn test(self: &MyObject);
+            },
+            Safety::Unsafe,
+        )
+        .unwrap();
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "MyObject_testCxxQtInherit"]
+                    unsafe fn MyObject_inherit_test(self_value: &MyObject,);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    pub(crate) unsafe fn test(self: &qobject::MyObject,) {
+                        unsafe { qobject::MyObject_inherit_test(self,) }
+                    }
+                }
+            },
+        );
+    }
 }
@@ -3,7 +3,8 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::rust::get_params_tokens;
+use crate::generator::{naming::method::QAsyncInvokableNames, rust::get_params_tokens};
+use crate::naming::{rust::syn_type_cxx_bridge_to_qualified, TypeNames};
 use crate::{
     generator::{
         naming::qobject::QObjectNames,
@@ -12,11 +13,12 @@ use crate::{
     parser::method::ParsedMethod,
 };
 use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, Result};
+use syn::{spanned::Spanned, Result, ReturnType};
 
 pub fn generate_rust_methods(
     invokables: &Vec<&ParsedMethod>,
     qobject_names: &QObjectNames,
+    type_names: &TypeNames,
 ) -> Result<GeneratedRustFragment> {
     let mut generated = GeneratedRustFragment::default();
     let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
@@ -43,26 +45,30 @@ pub fn generate_rust_methods(
         let cfgs = &invokable.cfgs;
         let cxx_namespace = qobject_names.namespace_tokens();
 
-        let fragment = RustFragmentPair {
-            cxx_bridge: vec![quote_spanned! {
-                invokable.method.span() =>
-                // Note: extern "Rust" block does not need to be unsafe
-                extern "Rust" {
-                    // Note that we are exposing a Rust method on the C++ type to C++
-                    //
-                    // CXX ends up generating the source, then we generate the matching header.
-                    #[cxx_name = #invokable_ident_cpp]
-                    // Needed for QObjects to have a namespace on their type or extern block
-                    //
-                    // A Namespace from cxx_qt::bridge would be automatically applied to all children
-                    // but to apply it to only certain types, it is needed here too
-                    #cxx_namespace
-                    #(#cfgs)*
-                    #[doc(hidden)]
-                    #unsafe_call fn #invokable_ident_rust(#parameter_signatures) #return_type;
-                }
-            }],
-            implementation: vec![],
+        let fragment = if invokable.is_async {
+            generate_async_invokable(invokable, qobject_names, type_names)?
+        } else {
+            RustFragmentPair {
+                cxx_bridge: vec![quote_spanned! {
+                    invokable.method.span() =>
+                    // Note: extern "Rust" block does not need to be unsafe
+                    extern "Rust" {
+                        // Note that we are exposing a Rust method on the C++ type to C++
+                        //
+                        // CXX ends up generating the source, then we generate the matching header.
+                        #[cxx_name = #invokable_ident_cpp]
+                        // Needed for QObjects to have a namespace on their type or extern block
+                        //
+                        // A Namespace from cxx_qt::bridge would be automatically applied to all children
+                        // but to apply it to only certain types, it is needed here too
+                        #cxx_namespace
+                        #(#cfgs)*
+                        #[doc(hidden)]
+                        #unsafe_call fn #invokable_ident_rust(#parameter_signatures) #return_type;
+                    }
+                }],
+                implementation: vec![],
+            }
         };
 
         generated
@@ -76,11 +82,141 @@ pub fn generate_rust_methods(
     Ok(generated)
 }
 
+/// Generate the helpers for an async invokable, which C++ calls to spawn its future
+///
+/// The future of the Rust method is spawned onto the Qt event loop of the QObject, once it has
+/// finished its result resolves the QFuture returned to C++ and is emitted by the finished signal.
+fn generate_async_invokable(
+    invokable: &ParsedMethod,
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<RustFragmentPair> {
+    let invokable_ident_rust = invokable.name.rust_unqualified();
+    let async_names = QAsyncInvokableNames::new(invokable, &qobject_names.name);
+    let spawn_ident_cpp = async_names.spawn_name.cxx_unqualified();
+    let spawn_ident_rust = async_names.spawn_name.rust_unqualified();
+    let promise_ident = &async_names.promise_alias;
+    let finished_signal = invokable.finished_signal()?;
+    let finished_ident_rust = finished_signal.name.rust_unqualified();
+    let module_ident = qobject_names.name.require_module()?;
+    let qualified_impl = qobject_names.name.rust_qualified();
+
+    // The spawn helper always borrows the QObject mutably, as the future is given mutable
+    // access to it, so for a const invokable C++ casts away the constness to call it
+    let parameter_signatures = get_params_tokens(
+        true,
+        &invokable.parameters,
+        qobject_names.name.rust_unqualified(),
+    );
+    let parameters_qualified = invokable
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let ident = &parameter.ident;
+            let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+            Ok(quote! { #ident: #ty })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let parameter_idents = invokable
+        .parameters
+        .iter()
+        .map(|parameter| &parameter.ident);
+
+    let (unsafe_call, future) = if invokable.safe {
+        (
+            None,
+            quote! { Self::#invokable_ident_rust(qobject #(, #parameter_idents)*) },
+        )
+    } else {
+        (
+            Some(quote! { unsafe }),
+            quote! { unsafe { Self::#invokable_ident_rust(qobject #(, #parameter_idents)*) } },
+        )
+    };
+    let (resolve_signature, resolve_result, finished_call) = match &invokable.method.sig.output {
+        ReturnType::Default => (
+            quote! { fn resolve(self: Pin<&mut #promise_ident>); },
+            quote! {
+                future.await;
+                promise.pin_mut().resolve();
+            },
+            quote! { qobject.#finished_ident_rust() },
+        ),
+        ReturnType::Type(_, ty) => (
+            quote! { fn resolve(self: Pin<&mut #promise_ident>, result: &#ty); },
+            quote! {
+                let result = future.await;
+                promise.pin_mut().resolve(&result);
+            },
+            quote! { qobject.#finished_ident_rust(result) },
+        ),
+    };
+
+    let cfgs = &invokable.cfgs;
+    let cxx_namespace = qobject_names.namespace_tokens();
+
+    Ok(RustFragmentPair {
+        cxx_bridge: vec![
+            quote_spanned! {
+                invokable.method.span() =>
+                unsafe extern "C++" {
+                    // The promise which resolves the QFuture returned by the invokable
+                    //
+                    // CXX doesn't support generic types, so this is a type alias of
+                    // CxxQtPromise<T> in C++ which is then used like a normal type here
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    #(#cfgs)*
+                    type #promise_ident;
+                    include!("cxx-qt/promise.h");
+
+                    #[doc(hidden)]
+                    #(#cfgs)*
+                    #resolve_signature
+                }
+            },
+            quote_spanned! {
+                invokable.method.span() =>
+                extern "Rust" {
+                    #[cxx_name = #spawn_ident_cpp]
+                    #cxx_namespace
+                    #(#cfgs)*
+                    #[doc(hidden)]
+                    #unsafe_call fn #spawn_ident_rust(#parameter_signatures, promise: UniquePtr<#promise_ident>);
+                }
+            },
+        ],
+        implementation: vec![quote_spanned! {
+            invokable.method.span() =>
+            #(#cfgs)*
+            impl #qualified_impl {
+                #[doc(hidden)]
+                pub #unsafe_call fn #spawn_ident_rust(
+                    self: core::pin::Pin<&mut Self>,
+                    #(#parameters_qualified,)*
+                    mut promise: cxx::UniquePtr<#module_ident::#promise_ident>,
+                ) {
+                    cxx_qt::LocalQObject::spawn(self, move |qobject| {
+                        let handle = qobject.clone();
+                        let future = #future;
+                        async move {
+                            #resolve_result
+                            handle.with(|qobject| #finished_call);
+                        }
+                    });
+                }
+            }
+        }],
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::parser::CaseConversion;
+    use crate::syntax::safety::Safety;
     use crate::tests::assert_tokens_eq;
     use syn::{parse_quote, ForeignItemFn};
 
@@ -110,8 +246,12 @@ mod tests {
         ];
         let qobject_names = create_qobjectname();
 
-        let generated =
-            generate_rust_methods(&invokables.iter().collect(), &qobject_names).unwrap();
+        let generated = generate_rust_methods(
+            &invokables.iter().collect(),
+            &qobject_names,
+            &TypeNames::mock(),
+        )
+        .unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 4);
         assert_eq!(generated.cxx_qt_mod_contents.len(), 0);
@@ -164,4 +304,118 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_invokables_async() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            #[cxx_name = "fetchData"]
+            async fn fetch_data(self: &MyObject, param: i32) -> i32;
+        };
+        let invokable = ParsedMethod::parse(method, Safety::Safe, CaseConversion::none()).unwrap();
+        let qobject_names = create_qobjectname();
+
+        let generated =
+            generate_rust_methods(&vec![&invokable], &qobject_names, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    type MyObjectCxxQtPromisefetchData;
+                    include!("cxx-qt/promise.h");
+
+                    #[doc(hidden)]
+                    fn resolve(self: Pin<&mut MyObjectCxxQtPromisefetchData>, result: &i32);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "fetchDataCxxQtSpawn"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_spawn_fetch_data(self: Pin<&mut MyObject>, param: i32, promise: UniquePtr<MyObjectCxxQtPromisefetchData>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn cxx_qt_ffi_spawn_fetch_data(
+                        self: core::pin::Pin<&mut Self>,
+                        param: i32,
+                        mut promise: cxx::UniquePtr<qobject::MyObjectCxxQtPromisefetchData>,
+                    ) {
+                        cxx_qt::LocalQObject::spawn(self, move |qobject| {
+                            let handle = qobject.clone();
+                            let future = Self::fetch_data(qobject, param);
+                            async move {
+                                let result = future.await;
+                                promise.pin_mut().resolve(&result);
+                                handle.with(|qobject| qobject.fetch_data_finished(result));
+                            }
+                        });
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_async_unsafe_void() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            async unsafe fn reset(self: Pin<&mut MyObject>);
+        };
+        let invokable =
+            ParsedMethod::parse(method, Safety::Unsafe, CaseConversion::none()).unwrap();
+        let qobject_names = create_qobjectname();
+
+        let generated =
+            generate_rust_methods(&vec![&invokable], &qobject_names, &TypeNames::mock()).unwrap();
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    type MyObjectCxxQtPromisereset;
+                    include!("cxx-qt/promise.h");
+
+                    #[doc(hidden)]
+                    fn resolve(self: Pin<&mut MyObjectCxxQtPromisereset>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub unsafe fn cxx_qt_ffi_spawn_reset(
+                        self: core::pin::Pin<&mut Self>,
+                        mut promise: cxx::UniquePtr<qobject::MyObjectCxxQtPromisereset>,
+                    ) {
+                        cxx_qt::LocalQObject::spawn(self, move |qobject| {
+                            let handle = qobject.clone();
+                            let future = unsafe { Self::reset(qobject) };
+                            async move {
+                                future.await;
+                                promise.pin_mut().resolve();
+                                handle.with(|qobject| qobject.reset_finished());
+                            }
+                        });
+                    }
+                }
+            },
+        );
+    }
 }
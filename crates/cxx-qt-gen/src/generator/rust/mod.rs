@@ -11,8 +11,11 @@ pub mod inherit;
 pub mod method;
 pub mod property;
 pub mod qenum;
+pub mod qgadget;
+pub mod qmlmodel;
 pub mod qobject;
 pub mod signals;
+pub mod singleton;
 pub mod threading;
 
 use crate::generator::{rust::fragment::GeneratedRustFragment, structuring};
@@ -46,6 +49,13 @@ impl GeneratedRustBlocks {
                 .map(|qobject| GeneratedRustFragment::from_qobject(qobject, &parser.type_names))
                 .collect::<Result<Vec<GeneratedRustFragment>>>()?,
         );
+        fragments.extend(
+            structures
+                .qgadgets
+                .iter()
+                .map(GeneratedRustFragment::from_qgadget)
+                .collect::<Result<Vec<GeneratedRustFragment>>>()?,
+        );
         fragments.extend(
             parser
                 .cxx_qt_data
@@ -56,6 +66,13 @@ impl GeneratedRustBlocks {
                 })
                 .collect::<Result<Vec<GeneratedRustFragment>>>()?,
         );
+        let qflags = qenum::generate_cxx_qt_mod_contents(&parser.cxx_qt_data.qenums);
+        if !qflags.is_empty() {
+            fragments.push(GeneratedRustFragment {
+                cxx_qt_mod_contents: qflags,
+                ..Default::default()
+            });
+        }
 
         let namespace = parser.cxx_qt_data.namespace.clone().unwrap_or_default();
         let passthrough_mod = &parser.passthrough_module;
@@ -86,9 +103,25 @@ impl GeneratedRustBlocks {
             #module
         };
 
+        let mut cxx_mod_contents = qenum::generate_cxx_mod_contents(&parser.cxx_qt_data.qenums);
+        // The QObject alias is shared by the casts of all of the QObjects in the bridge
+        if !parser.cxx_qt_data.qobjects.is_empty() {
+            cxx_mod_contents.push(qobject::generate_cxx_mod_contents());
+        }
+        // The QUntypedBindable alias is shared by all of the BINDABLE properties in the bridge
+        if parser
+            .cxx_qt_data
+            .qobjects
+            .iter()
+            .flat_map(|qobject| &qobject.properties)
+            .any(|property| property.flags.bindable)
+        {
+            cxx_mod_contents.push(property::bindable::generate_cxx_mod_contents());
+        }
+
         Ok(GeneratedRustBlocks {
             cxx_mod,
-            cxx_mod_contents: qenum::generate_cxx_mod_contents(&parser.cxx_qt_data.qenums),
+            cxx_mod_contents,
             namespace,
             fragments,
         })
@@ -141,7 +174,7 @@ mod tests {
 
         let rust = GeneratedRustBlocks::from(&parser).unwrap();
         assert!(rust.cxx_mod.content.is_none());
-        assert_eq!(rust.cxx_mod_contents.len(), 0);
+        assert_eq!(rust.cxx_mod_contents.len(), 1);
         assert_eq!(rust.namespace, "");
         assert_eq!(rust.fragments.len(), 1);
     }
@@ -161,7 +194,7 @@ mod tests {
 
         let rust = GeneratedRustBlocks::from(&parser).unwrap();
         assert!(rust.cxx_mod.content.is_none());
-        assert_eq!(rust.cxx_mod_contents.len(), 0);
+        assert_eq!(rust.cxx_mod_contents.len(), 1);
         assert_eq!(rust.namespace, "cxx_qt");
         assert_eq!(rust.fragments.len(), 1);
     }
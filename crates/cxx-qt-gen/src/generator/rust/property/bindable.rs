@@ -0,0 +1,129 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::{property::QPropertyNames, qobject::QObjectNames},
+        rust::fragment::RustFragmentPair,
+    },
+    naming::rust::{syn_type_cxx_bridge_to_qualified, syn_type_is_cxx_bridge_unsafe},
+    naming::TypeNames,
+};
+use quote::quote;
+use syn::{parse_quote, Item, Result, Type};
+
+/// Generate the alias of `cxx_qt::QUntypedBindable` which is used by all BINDABLE properties in the bridge
+pub fn generate_cxx_mod_contents() -> Item {
+    parse_quote! {
+        unsafe extern "C++" {
+            include!(<QtCore/QProperty>);
+
+            #[doc(hidden)]
+            #[namespace = ""]
+            #[rust_name = "CxxQtQUntypedBindable"]
+            #[allow(dead_code)]
+            type QUntypedBindable = cxx_qt::QUntypedBindable;
+        }
+    }
+}
+
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_names: &QObjectNames,
+    cxx_ty: &Type,
+    type_names: &TypeNames,
+) -> Result<Option<RustFragmentPair>> {
+    let Some(bindable) = &idents.bindable else {
+        return Ok(None);
+    };
+
+    let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
+    let ident = &idents.name.rust_unqualified();
+    let ident_str = ident.to_string();
+
+    let untyped_cpp = bindable.untyped.cxx_unqualified();
+    let untyped_rust = bindable.untyped.rust_unqualified();
+    let bind_cpp = bindable.bind.cxx_unqualified();
+    let bind_rust = bindable.bind.rust_unqualified();
+    let update_cpp = bindable.update.cxx_unqualified();
+    let update_rust = bindable.update.rust_unqualified();
+    let set_from_bindable_cpp = bindable.set_from_bindable.cxx_unqualified();
+    let set_from_bindable_rust = bindable.set_from_bindable.rust_unqualified();
+
+    // Generate a notify name if it was provided, otherwise return empty
+    let notify_binding = match &idents.notify {
+        Some(notify) => {
+            let notify_ident = notify.rust_unqualified();
+            quote! {self.as_mut().#notify_ident();}
+        }
+        None => quote! {},
+    };
+
+    let qualified_ty = syn_type_cxx_bridge_to_qualified(cxx_ty, type_names)?;
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+
+    // Determine if unsafe is required due to an unsafe type
+    let has_unsafe = if syn_type_is_cxx_bridge_unsafe(cxx_ty) {
+        quote! { unsafe }
+    } else {
+        quote! {}
+    };
+
+    let cxx_namespace = qobject_names.namespace_tokens();
+
+    Ok(Some(RustFragmentPair {
+        cxx_bridge: vec![
+            quote! {
+                unsafe extern "C++" {
+                    #[doc = "Returns the bindable of the Q_PROPERTY "]
+                    #[doc = #ident_str]
+                    #[doc = ", which can be passed to the bind function of another property"]
+                    #[cxx_name = #untyped_cpp]
+                    #cxx_namespace
+                    fn #untyped_rust(self: Pin<&mut #cpp_class_name_rust>) -> CxxQtQUntypedBindable;
+
+                    #[doc = "Bind the Q_PROPERTY "]
+                    #[doc = #ident_str]
+                    #[doc = " to follow the value of the given bindable"]
+                    #[doc = ""]
+                    #[doc = "Returns false if the bindable is invalid or has a different type to the property."]
+                    #[doc = "The binding is removed when the property is set."]
+                    #[doc = ""]
+                    #[doc = "# Safety"]
+                    #[doc = ""]
+                    #[doc = "The object which the bindable belongs to must outlive the binding."]
+                    #[cxx_name = #bind_cpp]
+                    #cxx_namespace
+                    unsafe fn #bind_rust(self: Pin<&mut #cpp_class_name_rust>, source: CxxQtQUntypedBindable) -> bool;
+
+                    #[cxx_name = #update_cpp]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn #update_rust(self: Pin<&mut #cpp_class_name_rust>);
+                }
+            },
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = #set_from_bindable_cpp]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    #has_unsafe fn #set_from_bindable_rust(self: Pin<&mut #cpp_class_name_rust>, value: #cxx_ty);
+                }
+            },
+        ],
+        implementation: vec![quote! {
+            impl #qualified_impl {
+                #[doc(hidden)]
+                pub fn #set_from_bindable_rust(mut self: core::pin::Pin<&mut Self>, value: #qualified_ty) {
+                    use cxx_qt::CxxQtType;
+                    if self.#ident == value {
+                        return;
+                    }
+                    self.as_mut().rust_mut().#ident = value;
+                    #notify_binding
+                }
+            }
+        }],
+    }))
+}
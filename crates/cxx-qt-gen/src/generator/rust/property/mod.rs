@@ -3,7 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod bindable;
 pub mod getter;
+pub mod reset;
 pub mod setter;
 pub mod signal;
 
@@ -30,26 +32,57 @@ pub fn generate_rust_properties(
 
     for property in properties {
         let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
+        let mut property_generated = GeneratedRustFragment::default();
 
         if let Some(getter) = getter::generate(&idents, qobject_names, &property.ty, type_names)? {
-            generated
+            property_generated
                 .cxx_mod_contents
                 .append(&mut getter.cxx_bridge_as_items()?);
-            generated
+            property_generated
                 .cxx_qt_mod_contents
                 .append(&mut getter.implementation_as_items()?);
         };
 
         if let Some(setter) = setter::generate(&idents, qobject_names, &property.ty, type_names)? {
-            generated
+            property_generated
                 .cxx_mod_contents
                 .append(&mut setter.cxx_bridge_as_items()?);
-            generated
+            property_generated
                 .cxx_qt_mod_contents
                 .append(&mut setter.implementation_as_items()?);
         }
 
-        if let Some(notify) = signal::generate(&idents, qobject_names) {
+        if let Some(reset) = reset::generate(
+            &idents,
+            qobject_names,
+            &property.ty,
+            property.flags.reset_value.as_ref(),
+            type_names,
+        )? {
+            property_generated
+                .cxx_mod_contents
+                .append(&mut reset.cxx_bridge_as_items()?);
+            property_generated
+                .cxx_qt_mod_contents
+                .append(&mut reset.implementation_as_items()?);
+        }
+
+        if let Some(bindable) =
+            bindable::generate(&idents, qobject_names, &property.ty, type_names)?
+        {
+            property_generated
+                .cxx_mod_contents
+                .append(&mut bindable.cxx_bridge_as_items()?);
+            property_generated
+                .cxx_qt_mod_contents
+                .append(&mut bindable.implementation_as_items()?);
+        }
+
+        property_generated.add_cfgs(&property.cfgs);
+        generated.append(&mut property_generated);
+
+        if let Some(mut notify) = signal::generate(&idents, qobject_names) {
+            notify.cfgs = property.cfgs.clone();
             signals.push(notify)
         }
     }
@@ -67,7 +100,7 @@ pub fn generate_rust_properties(
 mod tests {
     use super::*;
     use crate::naming::Name;
-    use crate::parser::property::QPropertyFlags;
+    use crate::parser::property::{FlagState, QPropertyFlags};
     use crate::parser::qobject::ParsedQObject;
     use crate::{generator::naming::qobject::tests::create_qobjectname, tests::assert_tokens_eq};
     use syn::parse_quote;
@@ -78,16 +111,22 @@ mod tests {
                 name: Name::mock_name_with_cxx("trivial_property", "trivialProperty"),
                 ty: parse_quote! { i32 },
                 flags: QPropertyFlags::default(),
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedQProperty {
                 name: Name::mock_name_with_cxx("opaque_property", "opaqueProperty"),
                 ty: parse_quote! { UniquePtr<QColor> },
                 flags: QPropertyFlags::default(),
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedQProperty {
                 name: Name::mock_name_with_cxx("unsafe_property", "unsafeProperty"),
                 ty: parse_quote! { *mut T },
                 flags: QPropertyFlags::default(),
+                docs: vec![],
+                cfgs: vec![],
             },
         ];
         let qobject_names = create_qobjectname();
@@ -647,4 +686,209 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_properties_reset() {
+        let properties = vec![
+            ParsedQProperty {
+                name: Name::mock_name_with_cxx("trivial_property", "trivialProperty"),
+                ty: parse_quote! { i32 },
+                flags: QPropertyFlags {
+                    reset: Some(FlagState::Auto),
+                    ..QPropertyFlags::default()
+                },
+                docs: vec![],
+                cfgs: vec![],
+            },
+            ParsedQProperty {
+                name: Name::mock_name_with_cxx("valued_property", "valuedProperty"),
+                ty: parse_quote! { i32 },
+                flags: QPropertyFlags {
+                    read: FlagState::Auto,
+                    write: None,
+                    notify: None,
+                    reset: Some(FlagState::Auto),
+                    reset_value: Some(parse_quote! { 42 }),
+                    ..QPropertyFlags::default()
+                },
+                docs: vec![],
+                cfgs: vec![],
+            },
+        ];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let type_names = TypeNames::mock();
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &type_names,
+            &structured_qobject,
+        )
+        .unwrap();
+
+        // Getter, setter and reset for the first property, getter and reset for the second
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "resetTrivialProperty"]
+                    fn reset_trivial_property(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[2],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Reset for the Q_PROPERTY "]
+                    #[doc = "trivial_property"]
+                    pub fn reset_trivial_property(mut self: core::pin::Pin<&mut Self>) {
+                        use cxx_qt::CxxQtType;
+                        let value: i32 = <i32 as core::default::Default>::default();
+                        if self.trivial_property == value {
+                            return;
+                        }
+                        self.as_mut().rust_mut().trivial_property = value;
+                        self.as_mut().trivial_property_changed();
+                    }
+                }
+            },
+        );
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[4],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "resetValuedProperty"]
+                    fn reset_valued_property(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[4],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Reset for the Q_PROPERTY "]
+                    #[doc = "valued_property"]
+                    pub fn reset_valued_property(mut self: core::pin::Pin<&mut Self>) {
+                        use cxx_qt::CxxQtType;
+                        let value: i32 = 42;
+                        if self.valued_property == value {
+                            return;
+                        }
+                        self.as_mut().rust_mut().valued_property = value;
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_bindable() {
+        let properties = vec![ParsedQProperty {
+            name: Name::mock_name_with_cxx("trivial_property", "trivialProperty"),
+            ty: parse_quote! { i32 },
+            flags: QPropertyFlags {
+                bindable: true,
+                ..QPropertyFlags::default()
+            },
+            docs: vec![],
+            cfgs: vec![],
+        }];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let type_names = TypeNames::mock();
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &type_names,
+            &structured_qobject,
+        )
+        .unwrap();
+
+        // The setter keeps the bindable in sync with the Rust field
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "trivial_property"]
+                    pub fn set_trivial_property(mut self: core::pin::Pin<&mut Self>, value: i32) {
+                        use cxx_qt::CxxQtType;
+                        if self.trivial_property == value {
+                            return;
+                        }
+                        self.as_mut().rust_mut().trivial_property = value;
+                        self.as_mut().cxx_qt_update_trivial_property_bindable();
+                        self.as_mut().trivial_property_changed();
+                    }
+                }
+            },
+        );
+
+        // Getter and setter, then the bindable
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[doc = "Returns the bindable of the Q_PROPERTY "]
+                    #[doc = "trivial_property"]
+                    #[doc = ", which can be passed to the bind function of another property"]
+                    #[cxx_name = "cxxQtBindableTrivialProperty"]
+                    fn bindable_trivial_property(self: Pin<&mut MyObject>) -> CxxQtQUntypedBindable;
+
+                    #[doc = "Bind the Q_PROPERTY "]
+                    #[doc = "trivial_property"]
+                    #[doc = " to follow the value of the given bindable"]
+                    #[doc = ""]
+                    #[doc = "Returns false if the bindable is invalid or has a different type to the property."]
+                    #[doc = "The binding is removed when the property is set."]
+                    #[doc = ""]
+                    #[doc = "# Safety"]
+                    #[doc = ""]
+                    #[doc = "The object which the bindable belongs to must outlive the binding."]
+                    #[cxx_name = "cxxQtBindTrivialProperty"]
+                    unsafe fn bind_trivial_property(self: Pin<&mut MyObject>, source: CxxQtQUntypedBindable) -> bool;
+
+                    #[cxx_name = "cxxQtUpdateTrivialPropertyBindable"]
+                    #[doc(hidden)]
+                    fn cxx_qt_update_trivial_property_bindable(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[3],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "cxxQtSetTrivialPropertyFromBindable"]
+                    #[doc(hidden)]
+                    fn cxx_qt_set_trivial_property_from_bindable(self: Pin<&mut MyObject>, value: i32);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[2],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn cxx_qt_set_trivial_property_from_bindable(mut self: core::pin::Pin<&mut Self>, value: i32) {
+                        use cxx_qt::CxxQtType;
+                        if self.trivial_property == value {
+                            return;
+                        }
+                        self.as_mut().rust_mut().trivial_property = value;
+                        self.as_mut().trivial_property_changed();
+                    }
+                }
+            },
+        );
+    }
 }
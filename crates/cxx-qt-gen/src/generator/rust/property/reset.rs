@@ -0,0 +1,94 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::{
+            property::{NameState, QPropertyNames},
+            qobject::QObjectNames,
+        },
+        rust::fragment::RustFragmentPair,
+    },
+    naming::rust::syn_type_cxx_bridge_to_qualified,
+    naming::TypeNames,
+};
+use quote::quote;
+use syn::{Expr, Result, Type};
+
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_names: &QObjectNames,
+    cxx_ty: &Type,
+    reset_value: Option<&Expr>,
+    type_names: &TypeNames,
+) -> Result<Option<RustFragmentPair>> {
+    let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
+
+    if let Some(NameState::Auto(reset)) = &idents.reset {
+        let reset_cpp = reset.cxx_unqualified();
+        let reset_rust = reset.rust_unqualified();
+        let ident = &idents.name.rust_unqualified();
+        let ident_str = ident.to_string();
+
+        // Generate a notify name if it was provided, otherwise return empty
+        let notify_binding = match &idents.notify {
+            Some(notify) => {
+                let notify_ident = notify.rust_unqualified();
+                quote! {self.as_mut().#notify_ident();}
+            }
+            None => quote! {},
+        };
+
+        // Keep the bindable in sync with the Rust field if there is one
+        let bindable_update = idents.bindable.as_ref().map(|bindable| {
+            let update_ident = bindable.update.rust_unqualified();
+            quote! {self.as_mut().#update_ident();}
+        });
+
+        let qualified_ty = syn_type_cxx_bridge_to_qualified(cxx_ty, type_names)?;
+        let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+
+        // Reset to the value given with RESET(value), otherwise to the default of the type
+        let value = reset_value.map_or_else(
+            || quote! { <#qualified_ty as core::default::Default>::default() },
+            |value| quote! { #value },
+        );
+
+        let cxx_namespace = qobject_names.namespace_tokens();
+
+        Ok(Some(RustFragmentPair {
+            cxx_bridge: vec![quote! {
+                extern "Rust" {
+                    #[cxx_name = #reset_cpp]
+                    // Needed for QObjects to have a namespace on their type or extern block
+                    //
+                    // A Namespace from cxx_qt::bridge would be automatically applied to all children
+                    // but to apply it to only certain types, it is needed here too
+                    #cxx_namespace
+                    fn #reset_rust(self: Pin<&mut #cpp_class_name_rust>);
+                }
+            }],
+            implementation: vec![quote! {
+                impl #qualified_impl {
+                    #[doc = "Reset for the Q_PROPERTY "]
+                    #[doc = #ident_str]
+                    pub fn #reset_rust(mut self: core::pin::Pin<&mut Self>) {
+                        use cxx_qt::CxxQtType;
+                        let value: #qualified_ty = #value;
+                        if self.#ident == value {
+                            // don't want to set the value again and reemit the signal,
+                            // as this can cause binding loops
+                            return;
+                        }
+                        self.as_mut().rust_mut().#ident = value;
+                        #bindable_update
+                        #notify_binding
+                    }
+                }
+            }],
+        }))
+    } else {
+        Ok(None)
+    }
+}
@@ -41,6 +41,12 @@ pub fn generate(
             None => quote! {},
         };
 
+        // Keep the bindable in sync with the Rust field if there is one
+        let bindable_update = idents.bindable.as_ref().map(|bindable| {
+            let update_ident = bindable.update.rust_unqualified();
+            quote! {self.as_mut().#update_ident();}
+        });
+
         let qualified_ty = syn_type_cxx_bridge_to_qualified(cxx_ty, type_names)?;
         let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
 
@@ -77,6 +83,7 @@ pub fn generate(
                             return;
                         }
                         self.as_mut().rust_mut().#ident = value;
+                        #bindable_update
                         #notify_binding
                     }
                 }
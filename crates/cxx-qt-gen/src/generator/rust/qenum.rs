@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::parser::qenum::ParsedQEnum;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{parse_quote_spanned, spanned::Spanned, Item};
 
 pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
@@ -18,16 +18,26 @@ pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
             let variants = &item.variants;
             let docs = &qenum.docs;
             let cfgs = &qenum.cfgs;
+            let repr = &qenum.repr;
 
             let cxx_namespace = if namespace.is_none() {
                 quote! {}
             } else {
                 quote! { #[namespace = #namespace ] }
             };
+            // The QFlags type is defined outside of the bridge, see generate_cxx_qt_mod_contents
+            let flags_alias = qenum.flags.as_ref().map(|flags| {
+                let flags_ident = flags.rust_unqualified();
+                quote! {
+                    #(#cfgs)*
+                    #cxx_namespace
+                    type #flags_ident = super::#flags_ident;
+                }
+            });
             vec![
                 parse_quote_spanned! {
                     item.span() =>
-                    #[repr(i32)]
+                    #[repr(#repr)]
                     #(#cfgs)*
                     #(#docs)*
                     #cxx_namespace
@@ -41,6 +51,8 @@ pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
                         #(#cfgs)*
                         #cxx_namespace
                         type #qenum_ident;
+
+                        #flags_alias
                     }
                 },
             ]
@@ -49,6 +61,60 @@ pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
         .collect()
 }
 
+pub fn generate_cxx_qt_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
+    qenums
+        .iter()
+        .filter_map(|qenum| Some((qenum, qenum.flags.as_ref()?)))
+        .flat_map(|(qenum, flags)| {
+            let qenum_qualified = qenum.name.rust_qualified();
+            let flags_ident = flags.rust_unqualified();
+            let flags_cxx_qualified = flags.cxx_qualified();
+            let cfgs = &qenum.cfgs;
+            let repr = &qenum.repr;
+            let span = qenum.item.span();
+            let doc = format!(
+                " OR-combinations of [`{}`] values",
+                qenum_qualified
+                    .to_token_stream()
+                    .to_string()
+                    .replace(' ', "")
+            );
+
+            vec![
+                parse_quote_spanned! {
+                    span =>
+                    #(#cfgs)*
+                    #[doc = #doc]
+                    pub type #flags_ident = cxx_qt::QFlags<#qenum_qualified>;
+                },
+                parse_quote_spanned! {
+                    span =>
+                    #(#cfgs)*
+                    unsafe impl cxx_qt::QFlag for #qenum_qualified {
+                        type TypeId = cxx::type_id!(#flags_cxx_qualified);
+                        type Repr = #repr;
+
+                        fn to_repr(self) -> Self::Repr {
+                            self.repr
+                        }
+                    }
+                },
+                parse_quote_spanned! {
+                    span =>
+                    #(#cfgs)*
+                    impl core::ops::BitOr for #qenum_qualified {
+                        type Output = cxx_qt::QFlags<#qenum_qualified>;
+
+                        fn bitor(self, other: Self) -> Self::Output {
+                            cxx_qt::QFlags::from(self) | other
+                        }
+                    }
+                },
+            ]
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{generator::rust::fragment::GeneratedRustFragment, tests::assert_tokens_eq};
@@ -107,4 +173,118 @@ mod tests {
             },
         )
     }
+
+    #[test]
+    fn generates_discriminants_and_repr() {
+        let qenums = vec![ParsedQEnum::parse(
+            parse_quote! {
+                #[repr(i16)]
+                enum MyEnum {
+                    A = -1,
+                    B = 4,
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+
+        let generated = generate(&qenums);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                #[repr(i16)]
+                enum MyEnum {
+                    A = -1,
+                    B = 4,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn generates_flags() {
+        let qenums = vec![ParsedQEnum::parse(
+            parse_quote! {
+                #[qflags(MyFlags)]
+                #[namespace = "my_namespace"]
+                enum MyFlag {
+                    A = 1,
+                    B = 2,
+                }
+            },
+            None,
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+
+        let generated = generate(&qenums);
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "C++" {
+                    #[namespace = "my_namespace"]
+                    type MyFlag;
+
+                    #[namespace = "my_namespace"]
+                    type MyFlags = super::MyFlags;
+                }
+            },
+        );
+
+        let cxx_qt_mod_contents = generate_cxx_qt_mod_contents(&qenums);
+        assert_eq!(cxx_qt_mod_contents.len(), 3);
+        assert_tokens_eq(
+            &cxx_qt_mod_contents[0],
+            quote! {
+                #[doc = " OR-combinations of [`qobject::MyFlag`] values"]
+                pub type MyFlags = cxx_qt::QFlags<qobject::MyFlag>;
+            },
+        );
+        assert_tokens_eq(
+            &cxx_qt_mod_contents[1],
+            quote! {
+                unsafe impl cxx_qt::QFlag for qobject::MyFlag {
+                    type TypeId = cxx::type_id!("my_namespace::MyFlags");
+                    type Repr = i32;
+
+                    fn to_repr(self) -> Self::Repr {
+                        self.repr
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &cxx_qt_mod_contents[2],
+            quote! {
+                impl core::ops::BitOr for qobject::MyFlag {
+                    type Output = cxx_qt::QFlags<qobject::MyFlag>;
+
+                    fn bitor(self, other: Self) -> Self::Output {
+                        cxx_qt::QFlags::from(self) | other
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn generates_no_flags() {
+        let qenums = vec![ParsedQEnum::parse(
+            parse_quote! {
+                enum MyEnum {
+                    A,
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+
+        assert!(generate_cxx_qt_mod_contents(&qenums).is_empty());
+    }
 }
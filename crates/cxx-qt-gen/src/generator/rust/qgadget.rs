@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    rust::{fragment::GeneratedRustFragment, get_params_tokens},
+    structuring::StructuredQGadget,
+};
+use quote::quote;
+use syn::{parse_quote_spanned, spanned::Spanned, Item, Result};
+
+impl GeneratedRustFragment {
+    pub fn from_qgadget(qgadget: &StructuredQGadget) -> Result<Self> {
+        let declaration = qgadget.declaration;
+        let item = &declaration.item;
+        let cfgs = &declaration.cfgs;
+        let (ident, cxx_attrs, _) = declaration.name.clone().into_cxx_parts();
+
+        // The shared struct is declared as an extern type, so that CXX uses our Q_GADGET definition
+        // instead of generating the C++ struct itself.
+        let mut cxx_mod_contents: Vec<Item> = vec![
+            Item::Struct(item.clone()),
+            parse_quote_spanned! {
+                item.span() =>
+                extern "C++" {
+                    #(#cfgs)*
+                    #(#cxx_attrs)*
+                    type #ident;
+                }
+            },
+        ];
+
+        for method in &qgadget.methods {
+            let method_ident_cpp = method.name.cxx_unqualified();
+            let method_ident_rust = method.name.rust_unqualified();
+            let parameter_signatures = get_params_tokens(false, &method.parameters, &ident);
+            let return_type = &method.method.sig.output;
+            let unsafe_call = if method.safe {
+                None
+            } else {
+                Some(quote! { unsafe })
+            };
+            let method_cfgs = &method.cfgs;
+
+            cxx_mod_contents.push(parse_quote_spanned! {
+                method.method.span() =>
+                extern "Rust" {
+                    #[cxx_name = #method_ident_cpp]
+                    #(#cfgs)*
+                    #(#method_cfgs)*
+                    #[doc(hidden)]
+                    #unsafe_call fn #method_ident_rust(#parameter_signatures) #return_type;
+                }
+            });
+        }
+
+        Ok(GeneratedRustFragment {
+            cxx_mod_contents,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        parser::{method::ParsedMethod, qgadget::ParsedQGadget},
+        tests::assert_tokens_eq,
+    };
+    use quote::format_ident;
+    use syn::{parse_quote, ForeignItemFn};
+
+    #[test]
+    fn test_generate_rust_qgadget() {
+        let qgadget = ParsedQGadget::parse(
+            parse_quote! {
+                #[qgadget]
+                #[derive(PartialEq)]
+                struct Point {
+                    x: i32,
+                }
+            },
+            Some("my_namespace"),
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+        let method: ForeignItemFn = parse_quote! {
+            #[cxx_name = "lengthSquared"]
+            fn length_squared(self: &Point, scale: i32) -> i32;
+        };
+        let method = ParsedMethod::mock_qinvokable(&method);
+        let mut structured = StructuredQGadget::from_qgadget(&qgadget);
+        structured.methods.push(&method);
+
+        let generated = GeneratedRustFragment::from_qgadget(&structured).unwrap();
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert!(generated.cxx_qt_mod_contents.is_empty());
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                #[derive(PartialEq)]
+                struct Point {
+                    x: i32,
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "C++" {
+                    #[namespace = "my_namespace"]
+                    type Point;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "lengthSquared"]
+                    #[doc(hidden)]
+                    fn length_squared(self: &Point, scale: i32) -> i32;
+                }
+            },
+        );
+    }
+}
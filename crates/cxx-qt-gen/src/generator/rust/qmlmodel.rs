@@ -0,0 +1,277 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::qobject::QObjectNames,
+        rust::fragment::{GeneratedRustFragment, RustFragmentPair},
+    },
+    naming::TypeNames,
+    parser::qmlmodel::{ParsedQmlModel, ParsedQmlModelRow},
+};
+use quote::quote;
+use syn::Result;
+
+pub fn generate(
+    qml_model: &ParsedQmlModel,
+    row: &ParsedQmlModelRow,
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let mut generated = GeneratedRustFragment::default();
+
+    let cpp_class_name_rust = qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+    let row_ident = &row.ident;
+    let qualified_row = type_names.rust_qualified(row_ident)?;
+    let field = &qml_model.field;
+    let cxx_namespace = qobject_names.namespace_tokens();
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "cxxQtModelRowCount"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn cxx_qt_model_row_count(self: &#cpp_class_name_rust) -> i32;
+
+                    #[cxx_name = "cxxQtModelRow"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    unsafe fn cxx_qt_model_row<'a>(self: &'a #cpp_class_name_rust, row: i32) -> &'a #row_ident;
+
+                    #[cxx_name = "cxxQtModelSetRow"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn cxx_qt_model_set_row(self: Pin<&mut #cpp_class_name_rust>, row: i32, value: #row_ident);
+                }
+            },
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "cxxQtModelBeginInsertRows"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn cxx_qt_model_begin_insert_rows(self: Pin<&mut #cpp_class_name_rust>, first: i32, last: i32);
+
+                    #[cxx_name = "cxxQtModelEndInsertRows"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn cxx_qt_model_end_insert_rows(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[cxx_name = "cxxQtModelBeginRemoveRows"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn cxx_qt_model_begin_remove_rows(self: Pin<&mut #cpp_class_name_rust>, first: i32, last: i32);
+
+                    #[cxx_name = "cxxQtModelEndRemoveRows"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn cxx_qt_model_end_remove_rows(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[cxx_name = "cxxQtModelBeginMoveRows"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn cxx_qt_model_begin_move_rows(self: Pin<&mut #cpp_class_name_rust>, first: i32, last: i32, destination: i32) -> bool;
+
+                    #[cxx_name = "cxxQtModelEndMoveRows"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn cxx_qt_model_end_move_rows(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[cxx_name = "cxxQtModelBeginResetModel"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn cxx_qt_model_begin_reset_model(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[cxx_name = "cxxQtModelEndResetModel"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn cxx_qt_model_end_reset_model(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[cxx_name = "cxxQtModelRowChanged"]
+                    #[doc(hidden)]
+                    #cxx_namespace
+                    fn cxx_qt_model_row_changed(self: Pin<&mut #cpp_class_name_rust>, row: i32);
+                }
+            },
+        ],
+        implementation: vec![quote! {
+            impl #qualified_impl {
+                #[doc(hidden)]
+                pub fn cxx_qt_model_row_count(&self) -> i32 {
+                    self.#field.len() as i32
+                }
+
+                #[doc(hidden)]
+                pub fn cxx_qt_model_row(&self, row: i32) -> &#qualified_row {
+                    &self.#field[row as usize]
+                }
+
+                #[doc(hidden)]
+                pub fn cxx_qt_model_set_row(self: core::pin::Pin<&mut Self>, row: i32, value: #qualified_row) {
+                    self.set_row(row as usize, value);
+                }
+
+                /// Insert a row into the model at the given index, shifting all rows after it down
+                ///
+                /// # Panics
+                ///
+                /// Panics if `index > len`.
+                pub fn insert_row(mut self: core::pin::Pin<&mut Self>, index: usize, value: #qualified_row) {
+                    use cxx_qt::CxxQtType;
+                    assert!(
+                        index <= self.#field.len(),
+                        "insertion index (is {index}) should be <= len (is {len})",
+                        len = self.#field.len()
+                    );
+                    self.as_mut().cxx_qt_model_begin_insert_rows(index as i32, index as i32);
+                    self.as_mut().rust_mut().#field.insert(index, value);
+                    self.as_mut().cxx_qt_model_end_insert_rows();
+                }
+
+                /// Append a row to the end of the model
+                pub fn push_row(self: core::pin::Pin<&mut Self>, value: #qualified_row) {
+                    let index = self.#field.len();
+                    self.insert_row(index, value);
+                }
+
+                /// Remove and return the row at the given index, shifting all rows after it up
+                ///
+                /// # Panics
+                ///
+                /// Panics if `index` is out of bounds.
+                pub fn remove_row(mut self: core::pin::Pin<&mut Self>, index: usize) -> #qualified_row {
+                    use cxx_qt::CxxQtType;
+                    assert!(
+                        index < self.#field.len(),
+                        "removal index (is {index}) should be < len (is {len})",
+                        len = self.#field.len()
+                    );
+                    self.as_mut().cxx_qt_model_begin_remove_rows(index as i32, index as i32);
+                    let value = self.as_mut().rust_mut().#field.remove(index);
+                    self.as_mut().cxx_qt_model_end_remove_rows();
+                    value
+                }
+
+                /// Move the row at index `from` so that it ends up at index `to`
+                ///
+                /// # Panics
+                ///
+                /// Panics if `from` or `to` are out of bounds.
+                pub fn move_row(mut self: core::pin::Pin<&mut Self>, from: usize, to: usize) {
+                    use cxx_qt::CxxQtType;
+                    let len = self.#field.len();
+                    assert!(from < len, "source index (is {from}) should be < len (is {len})");
+                    assert!(to < len, "destination index (is {to}) should be < len (is {len})");
+                    if from == to {
+                        return;
+                    }
+
+                    // Qt expects the destination to be the index before which the row is
+                    // inserted, as if the row had not been removed yet
+                    let destination = if to > from { to + 1 } else { to };
+                    if !self.as_mut().cxx_qt_model_begin_move_rows(from as i32, from as i32, destination as i32) {
+                        return;
+                    }
+                    let value = self.as_mut().rust_mut().#field.remove(from);
+                    self.as_mut().rust_mut().#field.insert(to, value);
+                    self.as_mut().cxx_qt_model_end_move_rows();
+                }
+
+                /// Replace the row at the given index, notifying views that its data has changed
+                ///
+                /// # Panics
+                ///
+                /// Panics if `index` is out of bounds.
+                pub fn set_row(mut self: core::pin::Pin<&mut Self>, index: usize, value: #qualified_row) {
+                    use cxx_qt::CxxQtType;
+                    self.as_mut().rust_mut().#field[index] = value;
+                    self.as_mut().cxx_qt_model_row_changed(index as i32);
+                }
+
+                /// Replace all of the rows in the model
+                pub fn reset_rows(mut self: core::pin::Pin<&mut Self>, rows: Vec<#qualified_row>) {
+                    use cxx_qt::CxxQtType;
+                    self.as_mut().cxx_qt_model_begin_reset_model();
+                    self.as_mut().rust_mut().#field = rows;
+                    self.as_mut().cxx_qt_model_end_reset_model();
+                }
+
+                /// Remove all of the rows in the model
+                pub fn clear_rows(self: core::pin::Pin<&mut Self>) {
+                    self.reset_rows(Vec::new());
+                }
+            }
+        }],
+    };
+
+    generated
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    generated
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::tests::assert_tokens_eq;
+    use quote::format_ident;
+    use syn::{parse_quote, Attribute, ItemStruct};
+
+    #[test]
+    fn test_generate_rust_qml_model() {
+        let attr: Attribute = parse_quote! { #[qml_model(Row, rows)] };
+        let qml_model = ParsedQmlModel::parse(&attr).unwrap();
+        let mut item: ItemStruct = parse_quote! {
+            struct Row {
+                #[qrole]
+                value: i32,
+            }
+        };
+        let row = ParsedQmlModelRow::parse(&mut item).unwrap();
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("Row", Some(format_ident!("qobject")), None, None);
+
+        let generated = generate(&qml_model, &row, &create_qobjectname(), &type_names).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "cxxQtModelRowCount"]
+                    #[doc(hidden)]
+                    fn cxx_qt_model_row_count(self: &MyObject) -> i32;
+
+                    #[cxx_name = "cxxQtModelRow"]
+                    #[doc(hidden)]
+                    unsafe fn cxx_qt_model_row<'a>(self: &'a MyObject, row: i32) -> &'a Row;
+
+                    #[cxx_name = "cxxQtModelSetRow"]
+                    #[doc(hidden)]
+                    fn cxx_qt_model_set_row(self: Pin<&mut MyObject>, row: i32, value: Row);
+                }
+            },
+        );
+
+        let implementation = &generated.cxx_qt_mod_contents[0];
+        let implementation = quote! { #implementation }.to_string();
+        assert!(implementation.starts_with(&quote! { impl qobject::MyObject }.to_string()));
+        assert!(implementation
+            .contains(&quote! { self.as_mut().rust_mut().rows.insert(index, value); }.to_string()));
+        assert!(implementation.contains(
+            &quote! { pub fn reset_rows(mut self: core::pin::Pin<&mut Self>, rows: Vec<qobject::Row>) }
+                .to_string()
+        ));
+    }
+}
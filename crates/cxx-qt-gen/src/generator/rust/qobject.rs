@@ -12,14 +12,15 @@ use crate::{
             inherit,
             method::generate_rust_methods,
             property::generate_rust_properties,
+            qmlmodel,
             signals::generate_rust_signals,
-            threading,
+            singleton, threading,
         },
     },
     naming::TypeNames,
 };
-use quote::quote;
-use syn::{Ident, Result};
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, Ident, Item, Result};
 
 impl GeneratedRustFragment {
     // Might need to be refactored to use a StructuredQObject instead (confirm with Leon)
@@ -35,7 +36,7 @@ impl GeneratedRustFragment {
 
         generated.append(&mut generate_qobject_definitions(
             &qobject_names,
-            qobject.base_class.clone(),
+            &structured_qobject.base_classes,
             type_names,
         )?);
 
@@ -49,10 +50,12 @@ impl GeneratedRustFragment {
         generated.append(&mut generate_rust_methods(
             &structured_qobject.methods,
             &qobject_names,
+            type_names,
         )?);
         generated.append(&mut inherit::generate(
             &qobject_names,
             &structured_qobject.inherited_methods,
+            type_names,
         )?);
         generated.append(&mut generate_rust_signals(
             &structured_qobject.signals,
@@ -60,9 +63,19 @@ impl GeneratedRustFragment {
             type_names,
         )?);
 
-        // If this type is a singleton then we need to add an include
+        // If this type is a list model then generate the row accessors and helpers
+        if let (Some(qml_model), Some(row)) = (&qobject.qml_model, structured_qobject.model_row) {
+            generated.append(&mut qmlmodel::generate(
+                qml_model,
+                row,
+                &qobject_names,
+                type_names,
+            )?);
+        }
+
+        // If this type uses any of the QML macros then we need to add an include
         if let Some(qml_metadata) = &qobject.qml_metadata {
-            if qml_metadata.singleton {
+            if qml_metadata.requires_qml_include() {
                 let fragment = RustFragmentPair {
                     cxx_bridge: vec![quote! {
                         unsafe extern "C++" {
@@ -77,6 +90,16 @@ impl GeneratedRustFragment {
             }
         }
 
+        // If this singleton is created by a factory then add generation
+        if let Some(engine) = structured_qobject.singleton_factory {
+            generated.append(&mut singleton::generate(
+                &qobject_names,
+                &namespace_idents,
+                engine,
+                type_names,
+            )?);
+        }
+
         // If this type has threading enabled then add generation
         if structured_qobject.threading {
             generated.append(&mut threading::generate(
@@ -95,14 +118,32 @@ impl GeneratedRustFragment {
 
         generated.append(&mut cxxqttype::generate(&qobject_names, type_names)?);
 
+        // Everything that is generated for this type only exists when the type itself exists
+        generated.add_cfgs(&qobject.cfgs);
+
         Ok(generated)
     }
 }
 
+/// Generate the alias of `cxx_qt::QObject` which is used by the casts of all QObjects in the bridge
+pub fn generate_cxx_mod_contents() -> Item {
+    parse_quote! {
+        unsafe extern "C++" {
+            include!("cxx-qt/casting.h");
+
+            #[doc(hidden)]
+            #[namespace = ""]
+            #[rust_name = "CxxQtQObject"]
+            #[allow(dead_code)]
+            type QObject = cxx_qt::QObject;
+        }
+    }
+}
+
 /// Generate the C++ and Rust CXX definitions for the QObject
 fn generate_qobject_definitions(
     qobject_idents: &QObjectNames,
-    base: Option<Ident>,
+    base_classes: &[&Ident],
     type_names: &TypeNames,
 ) -> Result<GeneratedRustFragment> {
     let mut generated = GeneratedRustFragment::default();
@@ -125,22 +166,75 @@ fn generate_qobject_definitions(
 
     let cpp_struct_qualified = &qobject_idents.name.rust_qualified();
 
-    let base_upcast = if let Some(base) = base {
-        let base_name = type_names.lookup(&base)?.rust_qualified();
-        vec![
-            quote! { impl cxx_qt::Upcast<#base_name> for #cpp_struct_qualified {} },
-            // Until we can actually implement the Upcast trait properly, we just need to silence
-            // the warning that the base class is otherwise unused.
+    // Every QObject can be cast to and from cxx_qt::QObject, so only generate separate casts for
+    // base classes which are not QObject itself, otherwise the implementations would conflict.
+    let mut casts = vec![(
+        format_ident!("QObject"),
+        format_ident!("CxxQtQObject"),
+        quote! { cxx_qt::QObject },
+    )];
+    let mut base_unused = vec![];
+    for (index, base) in base_classes.iter().enumerate() {
+        let base_name = type_names.lookup(base)?;
+        let base_qualified = base_name.rust_qualified();
+        if base_name.cxx_unqualified() == "QObject" {
+            // A direct QObject base class is otherwise unused, so silence the warning.
             // This can be done with an unnamed import and the right attributes
-            quote! {
-                #[allow(unused_imports)]
-                #[allow(dead_code)]
-                use #base_name as _;
-            },
-        ]
-    } else {
-        vec![]
-    };
+            if index == 0 {
+                base_unused.push(quote! {
+                    #[allow(unused_imports)]
+                    #[allow(dead_code)]
+                    use #base_qualified as _;
+                });
+            }
+        } else {
+            casts.push((
+                base_name.rust_unqualified().clone(),
+                base_name.rust_unqualified().clone(),
+                base_qualified.to_token_stream(),
+            ));
+        }
+    }
+
+    let mut cast_bridge = vec![];
+    let mut cast_impls = vec![];
+    for (base_suffix, base_ident, base_qualified) in casts {
+        let (upcast_name, upcast_attrs, upcast_qualified) = qobject_idents
+            .cxx_qt_ffi_method("upcastPtr")
+            .with_rust_name(format_ident!(
+                "cxx_qt_ffi_{cpp_class_name_cpp}_upcast_{base_suffix}"
+            ))
+            .into_cxx_parts();
+        let (downcast_name, downcast_attrs, downcast_qualified) = qobject_idents
+            .cxx_qt_ffi_method("downcastPtr")
+            .with_rust_name(format_ident!(
+                "cxx_qt_ffi_{cpp_class_name_cpp}_downcast_{base_suffix}"
+            ))
+            .into_cxx_parts();
+        cast_bridge.push(quote! {
+            #[doc(hidden)]
+            #(#upcast_attrs)*
+            unsafe fn #upcast_name(qobject: *const #cpp_class_name_rust) -> *const #base_ident;
+
+            #[doc(hidden)]
+            #(#downcast_attrs)*
+            unsafe fn #downcast_name(base: *const #base_ident) -> *const #cpp_class_name_rust;
+        });
+        cast_impls.push(quote! {
+            impl cxx_qt::Upcast<#base_qualified> for #cpp_struct_qualified {
+                unsafe fn upcast_ptr(this: *const Self) -> *const #base_qualified {
+                    #upcast_qualified(this)
+                }
+            }
+        });
+        cast_impls.push(quote! {
+            impl cxx_qt::DowncastPtr<#base_qualified> for #cpp_struct_qualified {
+                unsafe fn downcast_ptr(base: *const #base_qualified) -> *const Self {
+                    #downcast_qualified(base)
+                }
+            }
+        });
+    }
 
     let fragment = RustFragmentPair {
         cxx_bridge: vec![
@@ -167,8 +261,13 @@ fn generate_qobject_definitions(
                     type #rust_struct_name_rust;
                 }
             },
+            quote! {
+                unsafe extern "C++" {
+                    #(#cast_bridge)*
+                }
+            },
         ],
-        implementation: base_upcast,
+        implementation: cast_impls.into_iter().chain(base_unused).collect(),
     };
 
     generated
@@ -213,6 +312,73 @@ mod tests {
         .is_ok());
     }
 
+    #[test]
+    fn test_generated_rust_qobject_base_chain() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "C++" {
+                    type QAbstractListModel;
+                }
+
+                extern "RustQt" {
+                    #[qobject]
+                    #[base = QAbstractListModel]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    #[base = MyObject]
+                    type MyOtherObject = super::MyOtherObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let rust = GeneratedRustFragment::from_qobject(&structures.qobjects[1], &parser.type_names)
+            .unwrap();
+        assert_tokens_eq(
+            &rust.cxx_qt_mod_contents[2],
+            quote! {
+                impl cxx_qt::Upcast<ffi::MyObject> for ffi::MyOtherObject {
+                    unsafe fn upcast_ptr(this: *const Self) -> *const ffi::MyObject {
+                        ffi::cxx_qt_ffi_MyOtherObject_upcast_MyObject(this)
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_qt_mod_contents[3],
+            quote! {
+                impl cxx_qt::DowncastPtr<ffi::MyObject> for ffi::MyOtherObject {
+                    unsafe fn downcast_ptr(base: *const ffi::MyObject) -> *const Self {
+                        ffi::cxx_qt_ffi_MyOtherObject_downcast_MyObject(base)
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_qt_mod_contents[4],
+            quote! {
+                impl cxx_qt::Upcast<ffi::QAbstractListModel> for ffi::MyOtherObject {
+                    unsafe fn upcast_ptr(this: *const Self) -> *const ffi::QAbstractListModel {
+                        ffi::cxx_qt_ffi_MyOtherObject_upcast_QAbstractListModel(this)
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_qt_mod_contents[5],
+            quote! {
+                impl cxx_qt::DowncastPtr<ffi::QAbstractListModel> for ffi::MyOtherObject {
+                    unsafe fn downcast_ptr(base: *const ffi::QAbstractListModel) -> *const Self {
+                        ffi::cxx_qt_ffi_MyOtherObject_downcast_QAbstractListModel(base)
+                    }
+                }
+            },
+        );
+    }
+
     #[test]
     fn test_generated_rust_qobject_blocks_singleton() {
         let module = mock_qml_singleton();
@@ -224,7 +390,7 @@ mod tests {
             &parser.type_names,
         )
         .unwrap();
-        assert_eq!(rust.cxx_mod_contents.len(), 6);
+        assert_eq!(rust.cxx_mod_contents.len(), 7);
         assert_tokens_eq(
             &rust.cxx_mod_contents[0],
             quote! {
@@ -253,12 +419,28 @@ mod tests {
             &rust.cxx_mod_contents[2],
             quote! {
                 unsafe extern "C++" {
-                    include!(<QtQml/QQmlEngine>);
+                    #[doc(hidden)]
+                    #[cxx_name = "upcastPtr"]
+                    #[namespace = "rust::cxxqt1"]
+                    unsafe fn cxx_qt_ffi_MyObject_upcast_QObject(qobject: *const MyObject) -> *const CxxQtQObject;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "downcastPtr"]
+                    #[namespace = "rust::cxxqt1"]
+                    unsafe fn cxx_qt_ffi_MyObject_downcast_QObject(base: *const CxxQtQObject) -> *const MyObject;
                 }
             },
         );
         assert_tokens_eq(
             &rust.cxx_mod_contents[3],
+            quote! {
+                unsafe extern "C++" {
+                    include!(<QtQml/QQmlEngine>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[4],
             quote! {
                 extern "Rust" {
                     #[cxx_name = "createRs"]
@@ -268,7 +450,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &rust.cxx_mod_contents[4],
+            &rust.cxx_mod_contents[5],
             quote! {
                 unsafe extern "C++" {
                     #[doc(hidden)]
@@ -279,7 +461,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &rust.cxx_mod_contents[5],
+            &rust.cxx_mod_contents[6],
             quote! {
                 unsafe extern "C++" {
                     #[doc(hidden)]
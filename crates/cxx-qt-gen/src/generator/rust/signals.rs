@@ -13,10 +13,10 @@ use crate::{
         rust::fragment::{GeneratedRustFragment, RustFragmentPair},
     },
     naming::{rust::syn_type_cxx_bridge_to_qualified, Name, TypeNames},
-    parser::signals::ParsedSignal,
+    parser::{signals::ParsedSignal, AccessSpecifier},
 };
 use quote::quote;
-use syn::{parse_quote, FnArg, Ident, Result, Type};
+use syn::{parse_quote, FnArg, Ident, Item, Path, Result, Type};
 
 pub fn generate_rust_signal(
     signal: &ParsedSignal,
@@ -32,7 +32,7 @@ pub fn generate_rust_signal(
 
     let signal_name_cpp = idents.name.cxx_unqualified();
     let connect_ident_rust = idents.connect_name.rust_unqualified();
-    let on_ident_rust = idents.on_name;
+    let on_ident_rust = &idents.on_name;
 
     let free_connect_ident_cpp = idents_helper.connect_name.cxx_unqualified();
     let free_connect_ident_rust = idents_helper.connect_name.rust_unqualified();
@@ -101,7 +101,6 @@ pub fn generate_rust_signal(
     let cpp_ident = idents.name.cxx_unqualified();
 
     let doc_comments = &signal.docs;
-    let cfgs = &signal.cfgs;
     let namespace = if let Some(namespace) = qobject_name.namespace() {
         quote! { #[namespace = #namespace ] }
     } else {
@@ -114,12 +113,44 @@ pub fn generate_rust_signal(
 
     let return_type = &signal.method.sig.output;
 
-    // TODO: what happens with RustQt signals, can they be private yet?
-    if !signal.private {
+    let mut emit_implementation = None;
+    if signal.access == AccessSpecifier::Private {
+        // A private signal of the base class cannot be emitted at all
+        if !signal.inherit {
+            // Private signals can only be emitted by a friend function of the QObject in C++,
+            // the Rust method has the visibility of the signal so that it is private to the bridge
+            let emit_ident_cpp = idents_helper.emit_name.cxx_unqualified();
+            let emit_ident_rust = idents_helper.emit_name.rust_unqualified();
+            let namespace_str = &idents_helper.namespace;
+            let vis = &signal.method.vis;
+            let emit_call = quote! { #module_ident::#emit_ident_rust(self, #(#parameters_name),*) };
+            let emit_body = if signal.safe {
+                emit_call
+            } else {
+                quote! { unsafe { #emit_call } }
+            };
+
+            cxx_bridge.push(quote! {
+                #unsafe_block extern "C++" {
+                    #[doc(hidden)]
+                    #[namespace = #namespace_str]
+                    #[cxx_name = #emit_ident_cpp]
+                    #unsafe_call fn #emit_ident_rust(self_value: #self_type_cxx, #(#parameters_cxx),*);
+                }
+            });
+            emit_implementation = Some(parse_quote! {
+                impl #qualified_impl {
+                    #(#doc_comments)*
+                    #vis #unsafe_call fn #signal_ident_cpp(self: #self_type_qualified, #(#parameters_qualified_arg),*) {
+                        #emit_body
+                    }
+                }
+            });
+        }
+    } else if !signal.private {
         cxx_bridge.push(quote! {
             #unsafe_block extern "C++" {
                 #[cxx_name = #cpp_ident]
-                #(#cfgs)*
                 #(#doc_comments)*
                 #namespace
                 #unsafe_call fn #signal_ident_cpp(#parameter_signatures) #return_type;
@@ -231,10 +262,88 @@ pub fn generate_rust_signal(
     generated
         .cxx_qt_mod_contents
         .append(&mut fragment.implementation_as_items()?);
+    generated.cxx_qt_mod_contents.extend(emit_implementation);
+    if signal.stream {
+        generated
+            .cxx_qt_mod_contents
+            .push(generate_rust_signal_stream(
+                &idents,
+                signal,
+                &self_type_qualified,
+                &qualified_impl,
+                type_names,
+            )?);
+    }
+    // The helpers of the signal only exist when the signal itself exists
+    generated.add_cfgs(&signal.cfgs);
 
     Ok(generated)
 }
 
+/// Generate the `<signal>_stream` and `next_<signal>` methods of a signal with a #[stream] attribute
+fn generate_rust_signal_stream(
+    idents: &QSignalNames,
+    signal: &ParsedSignal,
+    self_type_qualified: &Type,
+    qualified_impl: &Path,
+    type_names: &TypeNames,
+) -> Result<Item> {
+    let signal_name_cpp = idents.name.cxx_unqualified();
+    let on_ident_rust = &idents.on_name;
+    let stream_ident_rust = &idents.stream_name;
+    let next_ident_rust = &idents.next_name;
+
+    // References can't outlive the emission, so the items of the stream own a clone of them
+    let mut item_types = vec![];
+    let mut item_values = vec![];
+    for parameter in &signal.parameters {
+        let ident = &parameter.ident;
+        let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+        if let Type::Reference(reference) = ty {
+            item_types.push(*reference.elem);
+            item_values.push(quote! { #ident.clone() });
+        } else {
+            item_types.push(ty);
+            item_values.push(quote! { #ident });
+        }
+    }
+    let parameters_name = signal.parameters.iter().map(|parameter| &parameter.ident);
+    let (item_type, item_value) = if item_types.len() == 1 {
+        (quote! { #(#item_types)* }, quote! { #(#item_values)* })
+    } else {
+        (
+            quote! { (#(#item_types),*) },
+            quote! { (#(#item_values),*) },
+        )
+    };
+
+    Ok(parse_quote! {
+        impl #qualified_impl {
+            #[doc = "Create a Stream of the arguments of each emission of the signal "]
+            #[doc = #signal_name_cpp]
+            #[doc = "."]
+            #[doc = "\n"]
+            #[doc = "Note that the signal is disconnected when the stream is dropped."]
+            pub fn #stream_ident_rust(self: #self_type_qualified) -> cxx_qt::QSignalStream<#item_type>
+            {
+                cxx_qt::QSignalStream::new(|sender| {
+                    self.#on_ident_rust(move |_, #(#parameters_name),*| sender.send(#item_value))
+                })
+            }
+
+            #[doc = "Create a future which resolves to the arguments of the next emission of the signal "]
+            #[doc = #signal_name_cpp]
+            #[doc = "."]
+            #[doc = "\n"]
+            #[doc = "The future resolves to None if the QObject is destroyed first."]
+            pub fn #next_ident_rust(self: #self_type_qualified) -> impl core::future::Future<Output = Option<#item_type>>
+            {
+                self.#stream_ident_rust().into_next()
+            }
+        }
+    })
+}
+
 pub fn generate_rust_signals(
     signals: &Vec<&ParsedSignal>,
     qobject_names: &QObjectNames,
@@ -863,4 +972,132 @@ mod tests {
 
         common_asserts(&generated.cxx_mod_contents, &generated.cxx_qt_mod_contents);
     }
+
+    #[test]
+    fn test_generate_rust_signal_private_emit() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qsignal(private)]
+            fn value_changed(self: Pin<&mut MyObject>, value: i32);
+        };
+        let qsignal = ParsedSignal::mock(&method);
+
+        let type_names = TypeNames::mock();
+        let qobject_name = type_names.lookup(&qsignal.qobject_ident).unwrap().clone();
+        let generated = generate_rust_signal(&qsignal, &qobject_name, &type_names).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 9);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1"]
+                    #[cxx_name = "MyObject_value_changedEmit"]
+                    fn MyObject_emit_value_changed(self_value: Pin<&mut MyObject>, value: i32);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[8],
+            quote! {
+                impl qobject::MyObject {
+                    fn value_changed(self: core::pin::Pin<&mut qobject::MyObject>, value: i32) {
+                        qobject::MyObject_emit_value_changed(self, value)
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_signal_stream() {
+        let method: ForeignItemFn = parse_quote! {
+            #[cxx_name = "dataChanged"]
+            #[stream]
+            fn data_changed(self: Pin<&mut MyObject>, trivial: i32, opaque: &QColor);
+        };
+        let qsignal = ParsedSignal::mock(&method);
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QColor", None, None, None);
+        let qobject_name = type_names.lookup(&qsignal.qobject_ident).unwrap().clone();
+        let generated = generate_rust_signal(&qsignal, &qobject_name, &type_names).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 9);
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[8],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Create a Stream of the arguments of each emission of the signal "]
+                    #[doc = "dataChanged"]
+                    #[doc = "."]
+                    #[doc = "\n"]
+                    #[doc = "Note that the signal is disconnected when the stream is dropped."]
+                    pub fn data_changed_stream(self: core::pin::Pin<&mut qobject::MyObject>) -> cxx_qt::QSignalStream<(i32, QColor)>
+                    {
+                        cxx_qt::QSignalStream::new(|sender| {
+                            self.on_data_changed(move |_, trivial, opaque| sender.send((trivial, opaque.clone())))
+                        })
+                    }
+
+                    #[doc = "Create a future which resolves to the arguments of the next emission of the signal "]
+                    #[doc = "dataChanged"]
+                    #[doc = "."]
+                    #[doc = "\n"]
+                    #[doc = "The future resolves to None if the QObject is destroyed first."]
+                    pub fn next_data_changed(self: core::pin::Pin<&mut qobject::MyObject>) -> impl core::future::Future<Output = Option<(i32, QColor)> >
+                    {
+                        self.data_changed_stream().into_next()
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_signal_stream_single() {
+        let method: ForeignItemFn = parse_quote! {
+            #[stream]
+            fn ready(self: Pin<&mut MyObject>, value: i32);
+        };
+        let qsignal = ParsedSignal::mock(&method);
+
+        let type_names = TypeNames::mock();
+        let qobject_name = type_names.lookup(&qsignal.qobject_ident).unwrap().clone();
+        let generated = generate_rust_signal(&qsignal, &qobject_name, &type_names).unwrap();
+
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 9);
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[8],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Create a Stream of the arguments of each emission of the signal "]
+                    #[doc = "ready"]
+                    #[doc = "."]
+                    #[doc = "\n"]
+                    #[doc = "Note that the signal is disconnected when the stream is dropped."]
+                    pub fn ready_stream(self: core::pin::Pin<&mut qobject::MyObject>) -> cxx_qt::QSignalStream<i32>
+                    {
+                        cxx_qt::QSignalStream::new(|sender| {
+                            self.on_ready(move |_, value| sender.send(value))
+                        })
+                    }
+
+                    #[doc = "Create a future which resolves to the arguments of the next emission of the signal "]
+                    #[doc = "ready"]
+                    #[doc = "."]
+                    #[doc = "\n"]
+                    #[doc = "The future resolves to None if the QObject is destroyed first."]
+                    pub fn next_ready(self: core::pin::Pin<&mut qobject::MyObject>) -> impl core::future::Future<Output = Option<i32> >
+                    {
+                        self.ready_stream().into_next()
+                    }
+                }
+            },
+        );
+    }
 }
@@ -0,0 +1,103 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::{namespace::NamespaceName, qobject::QObjectNames},
+        rust::fragment::{GeneratedRustFragment, RustFragmentPair},
+    },
+    naming::TypeNames,
+};
+use quote::{format_ident, quote};
+use syn::{Ident, Result};
+
+pub fn generate(
+    qobject_names: &QObjectNames,
+    namespace_ident: &NamespaceName,
+    engine: &Ident,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let mut blocks = GeneratedRustFragment::default();
+
+    let qobject_ident = qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(qobject_ident)?;
+    let qualified_engine = type_names.rust_qualified(engine)?;
+    let namespace_internals = &namespace_ident.internal;
+    let create_singleton = format_ident!("create_singleton_{qobject_ident}");
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            extern "Rust" {
+                #[doc(hidden)]
+                #[namespace = #namespace_internals]
+                #[cxx_name = "createSingleton"]
+                fn #create_singleton(engine: Pin<&mut #engine>) -> *mut #qobject_ident;
+            }
+        }],
+        implementation: vec![quote! {
+            #[doc(hidden)]
+            pub fn #create_singleton(engine: core::pin::Pin<&mut #qualified_engine>) -> *mut #qualified_impl {
+                <#qualified_impl as cxx_qt::SingletonFactory<#qualified_engine>>::create(engine)
+            }
+        }],
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::qobject::tests::create_parsed_qobject;
+    use crate::tests::assert_tokens_eq;
+
+    #[test]
+    fn test_generate_rust_singleton() {
+        let qobject = create_parsed_qobject();
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QQmlEngine", Some(format_ident!("qobject")), None, None);
+        let qobject_names = QObjectNames::from_qobject(&qobject, &type_names).unwrap();
+        let namespace_ident = NamespaceName::from(&qobject);
+
+        let generated = generate(
+            &qobject_names,
+            &namespace_ident,
+            &format_ident!("QQmlEngine"),
+            &type_names,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[namespace = "cxx_qt_MyObject"]
+                    #[cxx_name = "createSingleton"]
+                    fn create_singleton_MyObject(engine: Pin<&mut QQmlEngine>) -> *mut MyObject;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                pub fn create_singleton_MyObject(engine: core::pin::Pin<&mut qobject::QQmlEngine>) -> *mut qobject::MyObject {
+                    <qobject::MyObject as cxx_qt::SingletonFactory<qobject::QQmlEngine> >::create(engine)
+                }
+            },
+        );
+    }
+}
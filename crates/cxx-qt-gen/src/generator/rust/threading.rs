@@ -46,6 +46,13 @@ pub fn generate(
         qobject_names
             .cxx_qt_ffi_method("cxxQtThreadIsDestroyed")
             .into_cxx_parts();
+    let (
+        thread_is_object_thread_name,
+        thread_is_object_thread_attrs,
+        thread_is_object_thread_qualified,
+    ) = qobject_names
+        .cxx_qt_ffi_method("cxxQtThreadIsObjectThread")
+        .into_cxx_parts();
 
     let cxx_qt_thread_namespace = &namespace_ident.namespace;
     let namespace_internals = &namespace_ident.internal;
@@ -94,6 +101,10 @@ pub fn generate(
                     #[doc(hidden)]
                     #(#thread_is_destroyed_attrs)*
                     fn #thread_is_destroyed_name(cxx_qt_thread: &#cxx_qt_thread_ident) -> bool;
+
+                    #[doc(hidden)]
+                    #(#thread_is_object_thread_attrs)*
+                    fn #thread_is_object_thread_name(cxx_qt_thread: &#cxx_qt_thread_ident) -> bool;
                 }
             },
             quote! {
@@ -120,6 +131,12 @@ pub fn generate(
                         #thread_is_destroyed_qualified(cxx_qt_thread)
                     }
 
+                    #[doc(hidden)]
+                    fn is_object_thread(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> bool
+                    {
+                        #thread_is_object_thread_qualified(cxx_qt_thread)
+                    }
+
                     #[doc(hidden)]
                     fn queue<F>(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, f: F) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
                     where
@@ -240,6 +257,11 @@ mod tests {
                     #[cxx_name = "cxxQtThreadIsDestroyed"]
                     #[namespace = "rust::cxxqt1"]
                     fn cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtThreadIsObjectThread"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_MyObject_cxxQtThreadIsObjectThread(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
                 }
             },
         );
@@ -271,6 +293,11 @@ mod tests {
                         qobject::cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread)
                     }
 
+                    #[doc(hidden)]
+                    fn is_object_thread(cxx_qt_thread: &qobject::MyObjectCxxQtThread) -> bool {
+                        qobject::cxx_qt_ffi_MyObject_cxxQtThreadIsObjectThread(cxx_qt_thread)
+                    }
+
                     #[doc(hidden)]
                     fn queue<F>(cxx_qt_thread: &qobject::MyObjectCxxQtThread, f: F) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
                     where
@@ -12,12 +12,17 @@
 /// This mostly means grouping QObjects with their QEnums, QSignals, etc...
 ///
 /// All resulting structures are listed in the `Structures` struct.
+pub mod qgadget;
 pub mod qobject;
 
+use crate::generator::naming::property::QPropertyNames;
 use crate::parser::{
     cxxqtdata::ParsedCxxQtData,
+    qmlmodel::{ParsedQmlModel, MODEL_ROW_HELPERS},
+    qobject::ParsedQObject,
     trait_impl::{TraitImpl, TraitKind},
 };
+pub use qgadget::StructuredQGadget;
 pub use qobject::StructuredQObject;
 use syn::{Error, Ident, Result};
 
@@ -26,6 +31,8 @@ use syn::{Error, Ident, Result};
 pub struct Structures<'a> {
     /// The list of qobjects
     pub qobjects: Vec<StructuredQObject<'a>>,
+    /// The list of qgadgets
+    pub qgadgets: Vec<StructuredQGadget<'a>>,
 }
 
 /// Error for looking up against a QObject which isn't registered in Structures
@@ -70,11 +77,111 @@ impl<'a> Structures<'a> {
                 }
                 // TODO: Check for duplicate declarations?
                 TraitKind::Constructor(ref constructor) => qobject.constructors.push(constructor),
+                TraitKind::SingletonFactory(ref engine) => {
+                    let is_singleton = qobject
+                        .declaration
+                        .qml_metadata
+                        .as_ref()
+                        .is_some_and(|qml_metadata| qml_metadata.singleton);
+                    if !is_singleton {
+                        return Err(Error::new_spanned(
+                            &imp.declaration,
+                            format!(
+                                "cxx_qt::SingletonFactory requires QObject {qobject} to be a #[qml_singleton]!",
+                                qobject = imp.qobject
+                            ),
+                        ));
+                    }
+                    if qobject.singleton_factory.is_some() {
+                        return Err(Error::new_spanned(
+                            &imp.declaration,
+                            format!(
+                                "SingletonFactory already declared on QObject {qobject}!",
+                                qobject = imp.qobject
+                            ),
+                        ));
+                    }
+                    qobject.singleton_factory = Some(engine);
+                }
             }
         }
         Ok(())
     }
 
+    /// Returns an error if a method, signal or property accessor of the given list model has
+    /// the same name as one of the generated row helpers
+    fn check_model_row_helpers(qobject: &StructuredQObject) -> Result<()> {
+        let mut names: Vec<(&Ident, &Ident)> = vec![];
+        for method in &qobject.methods {
+            names.push((method.name.rust_unqualified(), &method.method.sig.ident));
+        }
+        for method in &qobject.inherited_methods {
+            names.push((method.name.rust_unqualified(), &method.method.sig.ident));
+        }
+        for signal in &qobject.signals {
+            names.push((signal.name.rust_unqualified(), &signal.method.sig.ident));
+        }
+
+        let mut property_names = vec![];
+        for property in &qobject.declaration.properties {
+            let idents = QPropertyNames::try_from_property(property, qobject)?;
+            let span = property.name.rust_unqualified();
+            property_names.push((idents.getter.rust_unqualified().clone(), span));
+            for name in [idents.setter, idents.notify, idents.reset]
+                .into_iter()
+                .flatten()
+            {
+                property_names.push((name.rust_unqualified().clone(), span));
+            }
+        }
+        names.extend(property_names.iter().map(|(name, span)| (name, *span)));
+
+        if let Some((name, span)) = names
+            .into_iter()
+            .find(|(name, _)| MODEL_ROW_HELPERS.contains(&name.to_string().as_str()))
+        {
+            return Err(Error::new_spanned(
+                span,
+                format!(
+                    "`{name}` is generated to modify the rows of the #[qml_model] QObject {qobject}, so it cannot be used as the name of a method, signal or property!",
+                    qobject = qobject.declaration.name.rust_unqualified()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Collect the #[base] class of the given qobject and, if that is another QObject of this
+    /// bridge, its base classes in turn
+    fn base_classes(
+        qobjects: &'a [ParsedQObject],
+        qobject: &'a ParsedQObject,
+    ) -> Result<Vec<&'a Ident>> {
+        let mut base_classes: Vec<&Ident> = vec![];
+        let mut current = qobject;
+        while let Some(base) = &current.base_class {
+            if base == qobject.name.rust_unqualified() || base_classes.contains(&base) {
+                return Err(Error::new_spanned(
+                    base,
+                    format!(
+                        "The #[base] classes of QObject {qobject} form a cycle!",
+                        qobject = qobject.name.rust_unqualified()
+                    ),
+                ));
+            }
+            base_classes.push(base);
+
+            match qobjects
+                .iter()
+                .find(|other| other.name.rust_unqualified() == base)
+            {
+                Some(other) => current = other,
+                None => break,
+            }
+        }
+        Ok(base_classes)
+    }
+
     /// Create a new `Structures` object from the given `ParsedCxxQtData`
     /// Returns an error, if any references could not be resolved.
     pub fn new(cxxqtdata: &'a ParsedCxxQtData) -> Result<Self> {
@@ -91,8 +198,36 @@ impl<'a> Structures<'a> {
             }
         }
 
-        // Associate each method parsed with its appropriate qobject
+        let mut qgadgets: Vec<_> = cxxqtdata
+            .qgadgets
+            .iter()
+            .map(StructuredQGadget::from_qgadget)
+            .collect();
+
+        // Associate each method parsed with its appropriate qobject or qgadget
         for method in &cxxqtdata.methods {
+            if let Some(qgadget) = qgadgets
+                .iter_mut()
+                .find(|qgadget| qgadget.has_qgadget_name(&method.qobject_ident))
+            {
+                // A gadget is a value type, so it cannot be pinned like a QObject
+                if method.mutable {
+                    return Err(Error::new_spanned(
+                        &method.method.sig,
+                        "Methods of a #[qgadget] must take `self` by shared reference!",
+                    ));
+                }
+                // There is no thread to resume on for a value type
+                if method.is_async {
+                    return Err(Error::new_spanned(
+                        &method.method.sig,
+                        "Methods of a #[qgadget] cannot be async!",
+                    ));
+                }
+                qgadget.methods.push(method);
+                continue;
+            }
+
             let qobject = find_qobject(&mut qobjects, &method.qobject_ident)?;
             qobject.methods.push(method);
         }
@@ -109,9 +244,52 @@ impl<'a> Structures<'a> {
             qobject.signals.push(signal);
         }
 
+        // Associate each list model with its row struct
+        for qobject in qobjects.iter_mut() {
+            if let Some(qml_model) = &qobject.declaration.qml_model {
+                if let Some(base_class) = &qobject.declaration.base_class {
+                    ParsedQmlModel::check_base_class(base_class)?;
+                }
+                let row = cxxqtdata
+                    .model_rows
+                    .iter()
+                    .find(|row| row.ident == qml_model.row)
+                    .ok_or_else(|| {
+                        not_found_error("Struct with #[qrole] fields", &qml_model.row)
+                    })?;
+                qobject.model_row = Some(row);
+            }
+        }
+
         Self::structure_trait_impls(&mut qobjects, &cxxqtdata.trait_impls)?;
 
-        Ok(Structures { qobjects })
+        for qobject in qobjects.iter_mut() {
+            qobject.base_classes = Self::base_classes(&cxxqtdata.qobjects, qobject.declaration)?;
+        }
+
+        // The row helpers of a list model must not clash with other methods of the QObject
+        for qobject in &qobjects {
+            if qobject.model_row.is_some() {
+                Self::check_model_row_helpers(qobject)?;
+            }
+        }
+
+        // Async invokables are resumed on the thread of the QObject, so they require threading
+        for qobject in &qobjects {
+            if let Some(method) = qobject.methods.iter().find(|method| method.is_async) {
+                if !qobject.threading {
+                    return Err(Error::new_spanned(
+                        &method.method.sig,
+                        format!(
+                            "Async invokables require `impl cxx_qt::Threading for {} {{}}`!",
+                            qobject.declaration.name.rust_unqualified()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(Structures { qobjects, qgadgets })
     }
 }
 
@@ -252,6 +430,117 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_model_row() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_model(Row, rows)]
+                    type MyModel = super::MyModelRust;
+                }
+            },
+            parse_quote! {
+                struct Row {
+                    #[qrole]
+                    value: i32,
+                }
+            },
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        assert!(structures.qobjects[0].model_row.is_none());
+        let row = structures.qobjects[1].model_row.unwrap();
+        assert_eq!(row.ident, "Row");
+    }
+
+    #[test]
+    fn test_qgadget_methods() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {
+                #[qgadget]
+                struct Point {
+                    x: i32,
+                }
+            },
+            parse_quote! {
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    fn length(self: &Point) -> f64;
+
+                    #[qinvokable]
+                    fn invokable(self: &MyObject);
+                }
+            },
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        assert_eq!(structures.qgadgets.len(), 1);
+        assert_eq!(structures.qgadgets[0].methods.len(), 1);
+        assert_eq!(structures.qobjects[0].methods.len(), 1);
+    }
+
+    #[test]
+    fn test_singleton_factory() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_singleton]
+                    type MySingleton = super::MySingletonRust;
+                }
+            },
+            parse_quote! {impl cxx_qt::SingletonFactory<QQmlEngine> for MySingleton {}},
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        assert!(structures.qobjects[0].singleton_factory.is_none());
+        assert_eq!(
+            structures.qobjects[1].singleton_factory.unwrap(),
+            "QQmlEngine"
+        );
+    }
+
+    #[test]
+    fn test_base_classes() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[base = QAbstractListModel]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    #[base = MyObject]
+                    type MyOtherObject = super::MyOtherObjectRust;
+                }
+            }
+        };
+
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        assert_eq!(
+            structures.qobjects[0].base_classes,
+            [&format_ident!("QAbstractListModel")]
+        );
+        assert_eq!(
+            structures.qobjects[1].base_classes,
+            [
+                &format_ident!("MyObject"),
+                &format_ident!("QAbstractListModel")
+            ]
+        );
+    }
+
     #[test]
     fn test_incompatible_trait_impl() {
         let mut bridge = mock_bridge();
@@ -319,6 +608,168 @@ mod tests {
                 }
             }
 
+            {
+                // Mutable method on a QGadget
+                #[cxx_qt::bridge]
+                mod ffi {
+                    #[qgadget]
+                    struct Point {
+                        x: i32,
+                    }
+
+                    unsafe extern "RustQt" {
+                        #[qinvokable]
+                        fn reset(self: Pin<&mut Point>);
+                    }
+                }
+            }
+
+            {
+                // Async method on a QGadget
+                #[cxx_qt::bridge]
+                mod ffi {
+                    #[qgadget]
+                    struct Point {
+                        x: i32,
+                    }
+
+                    unsafe extern "RustQt" {
+                        #[qinvokable]
+                        async fn length(self: &Point) -> i32;
+                    }
+                }
+            }
+
+            {
+                // Async invokable without threading
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qinvokable]
+                        async fn fetch(self: &MyObject) -> i32;
+                    }
+                }
+            }
+
+            {
+                // Singleton factory on a QObject which isn't a singleton
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    impl cxx_qt::SingletonFactory<QQmlEngine> for MyObject {}
+                }
+            }
+
+            {
+                // Singleton factory declared twice
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        #[qml_singleton]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    impl cxx_qt::SingletonFactory<QQmlEngine> for MyObject {}
+                    impl cxx_qt::SingletonFactory<QJSEngine> for MyObject {}
+                }
+            }
+
+            {
+                // Unknown row struct for list model
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qml_model(Row, rows)]
+                        type MyObject = super::MyObjectRust;
+                    }
+                }
+            }
+
+            {
+                // List model with a base class which is not a list model
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[base = QAbstractTableModel]
+                        #[qml_model(Row, rows)]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    struct Row {
+                        #[qrole]
+                        name: i32,
+                    }
+                }
+            }
+
+            {
+                // Property setter which clashes with a list model helper
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qml_model(Row, rows)]
+                        #[qproperty(i32, row)]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    struct Row {
+                        #[qrole]
+                        name: i32,
+                    }
+                }
+            }
+
+            {
+                // Invokable which clashes with a list model helper
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qml_model(Row, rows)]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    struct Row {
+                        #[qrole]
+                        name: i32,
+                    }
+
+                    unsafe extern "RustQt" {
+                        #[qinvokable]
+                        fn push_row(self: Pin<&mut MyObject>, name: i32);
+                    }
+                }
+            }
+
+            {
+                // Cyclic base classes
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[base = MyOtherObject]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qobject]
+                        #[base = MyObject]
+                        type MyOtherObject = super::MyOtherObjectRust;
+                    }
+                }
+            }
+
             {
                 // Undeclared QObject for inherited method
                 #[cxx_qt::bridge]
@@ -0,0 +1,26 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::parser::{method::ParsedMethod, qgadget::ParsedQGadget};
+use proc_macro2::Ident;
+
+/// The StructuredQGadget contains the parsed QGadget and its methods
+pub struct StructuredQGadget<'a> {
+    pub declaration: &'a ParsedQGadget,
+    pub methods: Vec<&'a ParsedMethod>,
+}
+
+impl<'a> StructuredQGadget<'a> {
+    pub fn has_qgadget_name(&self, ident: &Ident) -> bool {
+        self.declaration.name.rust_unqualified() == ident
+    }
+
+    /// Creates a [StructuredQGadget] from a [ParsedQGadget] without any methods
+    pub fn from_qgadget(qgadget: &'a ParsedQGadget) -> Self {
+        Self {
+            declaration: qgadget,
+            methods: vec![],
+        }
+    }
+}
@@ -8,6 +8,7 @@ use crate::naming::Name;
 use crate::parser::constructor::Constructor;
 use crate::parser::inherit::ParsedInheritedMethod;
 use crate::parser::method::ParsedMethod;
+use crate::parser::qmlmodel::ParsedQmlModelRow;
 use crate::parser::signals::ParsedSignal;
 use crate::parser::{qenum::ParsedQEnum, qobject::ParsedQObject};
 use proc_macro2::Ident;
@@ -23,6 +24,12 @@ pub struct StructuredQObject<'a> {
     pub signals: Vec<&'a ParsedSignal>,
     pub constructors: Vec<&'a Constructor>,
     pub threading: bool,
+    /// The engine type given to the cxx_qt::SingletonFactory, if the singleton is created by it
+    pub singleton_factory: Option<&'a Ident>,
+    /// The row of the list model, if the QObject has a #[qml_model] attribute
+    pub model_row: Option<&'a ParsedQmlModelRow>,
+    /// The chain of #[base] classes, followed through the other QObjects of this bridge
+    pub base_classes: Vec<&'a Ident>,
 }
 
 fn lookup<T>(invokables: &[T], id: &Ident, name_getter: impl Fn(&T) -> &Name) -> Option<Name> {
@@ -48,6 +55,9 @@ impl<'a> StructuredQObject<'a> {
             signals: vec![],
             constructors: vec![],
             threading: false,
+            singleton_factory: None,
+            model_row: None,
+            base_classes: vec![],
         }
     }
 
@@ -8,19 +8,26 @@
 
 //! The cxx-qt-gen crate provides methods for generated C++ and Rust code from a TokenStream.
 
+mod description;
 mod generator;
 mod naming;
 mod parser;
 mod syntax;
 mod writer;
 
+pub use description::BridgeDescription;
 pub use generator::{
+    cfg::{CfgEvaluator, CfgResult, GeneratedOpt},
     cpp::{fragment::CppFragment, GeneratedCppBlocks},
     rust::GeneratedRustBlocks,
 };
 pub use parser::Parser;
 pub use syntax::{parse_qt_file, CxxQtFile, CxxQtItem};
-pub use writer::{cpp::write_cpp, rust::write_rust};
+pub use writer::{
+    cpp::write_cpp,
+    file::{generate_file, GeneratedFile},
+    rust::write_rust,
+};
 
 pub use syn::{Error, Result};
 
@@ -28,6 +35,7 @@ pub use syn::{Error, Result};
 mod tests {
     use super::*;
 
+    use crate::generator::cfg::tests::MockCfgEvaluator;
     use crate::generator::cpp::property::tests::require_pair;
     use clang_format::{clang_format_with_style, ClangFormatStyle};
     use generator::{cpp::GeneratedCppBlocks, rust::GeneratedRustBlocks};
@@ -80,7 +88,7 @@ mod tests {
         // NOTE: this error handling is pretty rough so should only used for tests
         let mut command = std::process::Command::new("rustfmt");
         let mut child = command
-            .args(["--emit", "stdout"])
+            .args(["--edition", "2021", "--emit", "stdout"])
             .stdin(std::process::Stdio::piped())
             .stdout(std::process::Stdio::piped())
             .spawn()
@@ -158,7 +166,11 @@ mod tests {
     ) {
         let parser = Parser::from(syn::parse_str(input).unwrap()).unwrap();
 
-        let generated_cpp = GeneratedCppBlocks::from(&parser).unwrap();
+        // Evaluate any cfgs with feature = "enabled" as the only enabled cfg
+        let opt = GeneratedOpt {
+            cfg_evaluator: Some(Box::new(MockCfgEvaluator)),
+        };
+        let generated_cpp = GeneratedCppBlocks::from(&parser, &opt).unwrap();
         let (mut header, mut source) =
             require_pair(&write_cpp(&generated_cpp, "directory/file_ident")).unwrap();
         header = sanitize_code(header);
@@ -222,6 +234,21 @@ mod tests {
         test_code_generation!("qenum");
     }
 
+    #[test]
+    fn generates_qml_model() {
+        test_code_generation!("qml_model");
+    }
+
+    #[test]
+    fn generates_cfgs() {
+        test_code_generation!("cfgs");
+    }
+
+    #[test]
+    fn generates_qgadget() {
+        test_code_generation!("qgadget");
+    }
+
     #[test]
     #[should_panic]
     fn fail_token_assert() {
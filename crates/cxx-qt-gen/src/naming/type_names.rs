@@ -160,6 +160,14 @@ impl TypeNames {
         // Find and register the names of any QEnums in the bridge
         for qenum in &cxx_qt_data.qenums {
             self.insert(qenum.name.clone())?;
+            if let Some(flags) = &qenum.flags {
+                self.insert(flags.clone())?;
+            }
+        }
+
+        // Find and register the names of any QGadgets in the bridge
+        for qgadget in &cxx_qt_data.qgadgets {
+            self.insert(qgadget.name.clone())?;
         }
 
         for extern_cxxqt in &cxx_qt_data.extern_cxxqt_blocks {
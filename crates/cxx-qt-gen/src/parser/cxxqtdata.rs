@@ -9,15 +9,20 @@ use crate::naming::cpp::err_unsupported_item;
 use crate::parser::CaseConversion;
 use crate::{
     parser::{
-        externcxxqt::ParsedExternCxxQt, inherit::ParsedInheritedMethod, method::ParsedMethod,
-        qenum::ParsedQEnum, qobject::ParsedQObject, require_attributes, signals::ParsedSignal,
+        externcxxqt::ParsedExternCxxQt, extract_cfgs, inherit::ParsedInheritedMethod,
+        method::ParsedMethod, qenum::ParsedQEnum, qgadget::ParsedQGadget,
+        qmlmodel::ParsedQmlModelRow, qobject::ParsedQObject, require_attributes,
+        signals::ParsedSignal,
     },
     syntax::{
         attribute::attribute_get_path, expr::expr_to_string, foreignmod::ForeignTypeIdentAlias,
         path::path_compare_str, safety::Safety,
     },
 };
-use syn::{ForeignItem, Ident, Item, ItemEnum, ItemForeignMod, ItemImpl, ItemMacro, Meta, Result};
+use syn::{
+    ForeignItem, Ident, Item, ItemEnum, ItemForeignMod, ItemImpl, ItemMacro, ItemStruct, Meta,
+    Result,
+};
 
 pub struct ParsedCxxQtData {
     /// Map of the QObjects defined in the module that will be used for code generation
@@ -35,6 +40,10 @@ pub struct ParsedCxxQtData {
     pub inherited_methods: Vec<ParsedInheritedMethod>,
     /// List of QNamespace declarations
     pub qnamespaces: Vec<ParsedQNamespace>,
+    /// List of shared structs with #[qrole] fields, used as rows of list models
+    pub model_rows: Vec<ParsedQmlModelRow>,
+    /// List of shared structs with a #[qgadget] attribute
+    pub qgadgets: Vec<ParsedQGadget>,
     /// Blocks of extern "C++Qt"
     pub extern_cxxqt_blocks: Vec<ParsedExternCxxQt>,
     /// The namespace of the CXX-Qt module
@@ -55,6 +64,8 @@ impl ParsedCxxQtData {
             signals: vec![],
             inherited_methods: vec![],
             qnamespaces: vec![],
+            model_rows: vec![],
+            qgadgets: vec![],
             trait_impls: vec![],
             extern_cxxqt_blocks: Vec::<ParsedExternCxxQt>::default(),
             module_ident,
@@ -71,10 +82,31 @@ impl ParsedCxxQtData {
             Item::ForeignMod(foreign_mod) => self.parse_foreign_mod(foreign_mod),
             Item::Enum(enum_item) => self.parse_enum(enum_item),
             Item::Macro(mac) => self.parse_macro(mac),
+            Item::Struct(struct_item) => self.parse_struct(struct_item),
             _ => Ok(Some(item)),
         }
     }
 
+    fn parse_struct(&mut self, mut item: ItemStruct) -> Result<Option<Item>> {
+        if attribute_get_path(&item.attrs, &["qgadget"]).is_some() {
+            // The shared struct is generated again along with the gadget
+            self.qgadgets.push(ParsedQGadget::parse(
+                item,
+                self.namespace.as_deref(),
+                &self.module_ident,
+            )?);
+            return Ok(None);
+        }
+
+        if ParsedQmlModelRow::has_roles(&item) {
+            // The struct is still passed through to CXX as a shared struct,
+            // but without the #[qrole] attributes
+            self.model_rows.push(ParsedQmlModelRow::parse(&mut item)?);
+        }
+
+        Ok(Some(Item::Struct(item)))
+    }
+
     fn parse_enum(&mut self, item: ItemEnum) -> Result<Option<Item>> {
         if let Some(qenum_attribute) = attribute_get_path(&item.attrs, &["qenum"]) {
             // A Meta::Path indicates no arguments were provided to the enum
@@ -128,10 +160,9 @@ impl ParsedCxxQtData {
     }
 
     fn parse_foreign_mod_rust_qt(&mut self, mut foreign_mod: ItemForeignMod) -> Result<()> {
-        // TODO: support cfg on foreign mod blocks
         let attrs = require_attributes(
             &foreign_mod.attrs,
-            &["namespace", "auto_cxx_name", "auto_rust_name"],
+            &["cfg", "namespace", "auto_cxx_name", "auto_rust_name"],
         )?;
 
         let auto_case = CaseConversion::from_attrs(&attrs)?;
@@ -142,6 +173,9 @@ impl ParsedCxxQtData {
             .transpose()?
             .or_else(|| self.namespace.clone());
 
+        // Any cfgs on the block apply to all of the items within it
+        let cfgs = extract_cfgs(&foreign_mod.attrs);
+
         let safe_call = if foreign_mod.unsafety.is_some() {
             Safety::Safe
         } else {
@@ -150,7 +184,9 @@ impl ParsedCxxQtData {
 
         for item in foreign_mod.items.drain(..) {
             match item {
-                ForeignItem::Fn(foreign_fn) => {
+                ForeignItem::Fn(mut foreign_fn) => {
+                    foreign_fn.attrs.splice(0..0, cfgs.iter().cloned());
+
                     // Test if the function is a signal
                     if attribute_get_path(&foreign_fn.attrs, &["qsignal"]).is_some() {
                         let parsed_signal_method =
@@ -168,6 +204,10 @@ impl ParsedCxxQtData {
                         // Remaining methods are either C++ methods or invokables
                     } else {
                         let parsed_method = ParsedMethod::parse(foreign_fn, safe_call, auto_case)?;
+                        // Async invokables deliver their result through a generated signal
+                        if parsed_method.is_async {
+                            self.signals.push(parsed_method.finished_signal()?);
+                        }
                         self.methods.push(parsed_method);
                     }
                 }
@@ -175,12 +215,13 @@ impl ParsedCxxQtData {
                     let foreign_alias: ForeignTypeIdentAlias = syn::parse2(tokens.clone())?;
 
                     // Load the QObject
-                    let qobject = ParsedQObject::parse(
+                    let mut qobject = ParsedQObject::parse(
                         foreign_alias,
                         namespace.as_deref(),
                         &self.module_ident,
                         auto_case,
                     )?;
+                    qobject.cfgs.splice(0..0, cfgs.iter().cloned());
 
                     // Note that we assume a compiler error will occur later
                     // if you had two structs with the same name
@@ -268,6 +309,27 @@ mod tests {
                     B
                 }
             }
+            {
+                // Async methods must be invokables
+                unsafe extern "RustQt" {
+                    async fn method(self: &MyObject);
+                }
+            }
+            {
+                // Async invokables cannot be virtual
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    #[cxx_virtual]
+                    async fn invokable(self: &MyObject);
+                }
+            }
+            {
+                // Async invokables cannot borrow their arguments
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    async fn invokable(self: &MyObject, url: &QString);
+                }
+            }
             {
                 // Unsupported Item
                 extern "RustQt" {
@@ -314,6 +376,39 @@ mod tests {
         assert!(result.is_some());
     }
 
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_struct_model_row() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            struct Row {
+                #[qrole]
+                value: i32,
+            }
+        };
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_some());
+        assert_eq!(cxx_qt_data.model_rows.len(), 1);
+        assert_eq!(cxx_qt_data.model_rows[0].ident, "Row");
+        assert_eq!(cxx_qt_data.model_rows[0].roles.len(), 1);
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_struct_qgadget() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            #[qgadget]
+            struct Point {
+                x: i32,
+            }
+        };
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_none());
+        assert_eq!(cxx_qt_data.qgadgets.len(), 1);
+        assert_eq!(cxx_qt_data.qgadgets[0].name.cxx_unqualified(), "Point");
+    }
+
     #[test]
     fn test_find_and_merge_cxx_qt_item_impl_valid_qobject() {
         let mut cxx_qt_data = create_parsed_cxx_qt_data();
@@ -607,6 +702,65 @@ mod tests {
         assert!(!signals[0].inherit);
     }
 
+    #[test]
+    fn test_parse_async_invokable() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[cxx_name = "fetchData"]
+                async fn fetch_data(self: &MyObject, url: QString) -> QString;
+
+                #[qinvokable]
+                async fn reset(self: Pin<&mut MyObject>);
+            }
+        };
+        cxxqtdata.parse_cxx_qt_item(block).unwrap();
+
+        assert_eq!(cxxqtdata.methods.len(), 2);
+        assert!(cxxqtdata.methods[0].is_async);
+        assert!(cxxqtdata.methods[1].is_async);
+
+        let signals = &cxxqtdata.signals;
+        assert_eq!(signals.len(), 2);
+        assert_eq!(
+            signals[0].name,
+            Name::mock_name_with_cxx("fetch_data_finished", "fetchDataFinished")
+        );
+        assert_eq!(signals[0].parameters.len(), 1);
+        assert_eq!(signals[0].parameters[0].ident, "result");
+        assert_eq!(signals[0].docs.len(), 1);
+        assert_eq!(
+            signals[1].name,
+            Name::mock_name_with_cxx("reset_finished", "resetFinished")
+        );
+        assert!(signals[1].parameters.is_empty());
+    }
+
+    #[test]
+    fn test_parse_block_cfgs() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            #[cfg(feature = "block")]
+            unsafe extern "RustQt" {
+                #[qobject]
+                type MyObject = super::MyObjectRust;
+
+                #[qsignal]
+                fn ready(self: Pin<&mut MyObject>);
+
+                #[cfg(feature = "method")]
+                #[qinvokable]
+                fn invokable(self: &MyObject);
+            }
+        };
+        cxxqtdata.parse_cxx_qt_item(block).unwrap();
+
+        assert_eq!(cxxqtdata.qobjects.last().unwrap().cfgs.len(), 1);
+        assert_eq!(cxxqtdata.signals[0].cfgs.len(), 1);
+        assert_eq!(cxxqtdata.methods[0].cfgs.len(), 2);
+    }
+
     #[test]
     fn test_parse_threading() {
         let mut cxxqtdata = create_parsed_cxx_qt_data();
@@ -5,12 +5,12 @@
 
 use crate::{
     parser::{
-        externqobject::ParsedExternQObject, require_attributes, signals::ParsedSignal,
-        CaseConversion,
+        externqobject::ParsedExternQObject, extract_cfgs, require_attributes,
+        signals::ParsedSignal, CaseConversion,
     },
     syntax::{attribute::attribute_get_path, expr::expr_to_string, safety::Safety},
 };
-use syn::{spanned::Spanned, Error, ForeignItem, Ident, ItemForeignMod, Result, Token};
+use syn::{spanned::Spanned, Attribute, Error, ForeignItem, Ident, ItemForeignMod, Result, Token};
 
 /// Representation of an extern "C++Qt" block
 #[derive(Default)]
@@ -25,6 +25,8 @@ pub struct ParsedExternCxxQt {
     pub signals: Vec<ParsedSignal>,
     /// QObject types that need generation in the extern "C++Qt" block
     pub qobjects: Vec<ParsedExternQObject>,
+    /// Cfgs for the whole extern "C++Qt" block
+    pub cfgs: Vec<Attribute>,
 }
 
 impl ParsedExternCxxQt {
@@ -33,10 +35,9 @@ impl ParsedExternCxxQt {
         module_ident: &Ident,
         parent_namespace: Option<&str>,
     ) -> Result<Self> {
-        // TODO: support cfg on foreign mod blocks
         let attrs = require_attributes(
             &foreign_mod.attrs,
-            &["namespace", "auto_cxx_name", "auto_rust_name"],
+            &["cfg", "namespace", "auto_cxx_name", "auto_rust_name"],
         )?;
 
         let auto_case = CaseConversion::from_attrs(&attrs)?;
@@ -51,6 +52,7 @@ impl ParsedExternCxxQt {
         let mut extern_cxx_block = ParsedExternCxxQt {
             namespace,
             unsafety: foreign_mod.unsafety,
+            cfgs: extract_cfgs(&foreign_mod.attrs),
             ..Default::default()
         };
 
@@ -70,6 +72,11 @@ impl ParsedExternCxxQt {
                         // extern "C++Qt" signals are always inherit = true
                         // as they always exist on an existing QObject
                         signal.inherit = true;
+                        // The helpers of the signal are generated outside of the block,
+                        // so they need the cfgs of the block too
+                        signal
+                            .cfgs
+                            .splice(0..0, extern_cxx_block.cfgs.iter().cloned());
                         extern_cxx_block.signals.push(signal);
                     } else {
                         extern_cxx_block
@@ -80,7 +87,8 @@ impl ParsedExternCxxQt {
                 ForeignItem::Type(foreign_ty) => {
                     // Test that there is a #[qobject] attribute on any type
                     //
-                    // TODO: what happens to any docs here?
+                    // Any docs are kept on the declaration and passed through to the Rust type,
+                    // the C++ type already exists so there is no header to document.
                     if attribute_get_path(&foreign_ty.attrs, &["qobject"]).is_some() {
                         let extern_ty =
                             ParsedExternQObject::parse(foreign_ty, module_ident, parent_namespace)?;
@@ -137,6 +145,29 @@ mod tests {
         assert!(extern_cxx_qt.unsafety.is_some());
     }
 
+    #[test]
+    fn test_extern_cxx_qt_cfgs() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                #[cfg(feature = "block")]
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    type QPushButton;
+
+                    #[qsignal]
+                    fn clicked(self: Pin<&mut QPushButton>, checked: bool);
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(extern_cxx_qt.cfgs.len(), 1);
+        assert_eq!(extern_cxx_qt.signals.len(), 1);
+        assert_eq!(extern_cxx_qt.signals[0].cfgs.len(), 1);
+    }
+
     #[test]
     fn test_extern_cxxqt_type_missing_qobject() {
         let extern_cxx_qt = ParsedExternCxxQt::parse(
@@ -3,14 +3,15 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::naming::Name;
 use crate::parser::{
     check_safety, extract_cfgs, extract_docs, method::MethodFields, require_attributes,
-    CaseConversion,
+    AccessSpecifier, CaseConversion,
 };
 use crate::syntax::safety::Safety;
 use core::ops::Deref;
 use quote::format_ident;
-use syn::{Attribute, ForeignItemFn, Ident, Result};
+use syn::{spanned::Spanned, Attribute, Error, ForeignItemFn, Ident, Result};
 
 /// Describes a method found in an extern "RustQt" with #[inherit]
 pub struct ParsedInheritedMethod {
@@ -20,6 +21,8 @@ pub struct ParsedInheritedMethod {
     pub docs: Vec<Attribute>,
     /// Cfgs for the inherited method
     pub cfgs: Vec<Attribute>,
+    /// The access of the method in the base class, from `#[inherit(protected)]`
+    pub access: AccessSpecifier,
 }
 
 impl ParsedInheritedMethod {
@@ -34,7 +37,18 @@ impl ParsedInheritedMethod {
 
     pub fn parse(method: ForeignItemFn, safety: Safety, auto_case: CaseConversion) -> Result<Self> {
         check_safety(&method, &safety)?;
-        require_attributes(&method.attrs, &Self::ALLOWED_ATTRS)?;
+        let attrs = require_attributes(&method.attrs, &Self::ALLOWED_ATTRS)?;
+        let access = attrs
+            .get("inherit")
+            .map(|attr| AccessSpecifier::from_attr(attr))
+            .transpose()?
+            .unwrap_or_default();
+        if access == AccessSpecifier::Private {
+            return Err(Error::new(
+                attrs["inherit"].span(),
+                "Private methods of the base class cannot be called, use either `public` or `protected`",
+            ));
+        }
         let docs = extract_docs(&method.attrs);
         let cfgs = extract_cfgs(&method.attrs);
 
@@ -42,6 +56,7 @@ impl ParsedInheritedMethod {
             method_fields: MethodFields::parse(method, auto_case)?,
             docs,
             cfgs,
+            access,
         })
     }
 
@@ -49,6 +64,21 @@ impl ParsedInheritedMethod {
     pub fn wrapper_ident(&self) -> Ident {
         format_ident!("{}CxxQtInherit", self.name.cxx_unqualified())
     }
+
+    /// the name of the free friend function which calls a protected method, as a protected
+    /// method cannot be called through a public wrapper without leaking it to C++
+    pub fn friend_wrapper_name(&self) -> Name {
+        Name::new(format_ident!(
+            "{}_inherit_{}",
+            self.qobject_ident,
+            self.name.rust_unqualified()
+        ))
+        .with_cxx_name(format!(
+            "{}_{}CxxQtInherit",
+            self.qobject_ident,
+            self.name.cxx_unqualified()
+        ))
+    }
 }
 
 impl Deref for ParsedInheritedMethod {
@@ -89,6 +119,16 @@ mod tests {
             { fn test(self: &mut T); }
             // Attributes
             { fn test(#[test] self: &T); }
+            // Private methods cannot be called
+            {
+                #[inherit(private)]
+                fn test(self: &T);
+            }
+            // Unknown access specifier
+            {
+                #[inherit(internal)]
+                fn test(self: &T);
+            }
         }
     }
 
@@ -137,5 +177,29 @@ mod tests {
         );
         assert!(parsed.mutable);
         assert!(parsed.safe);
+        assert_eq!(parsed.access, AccessSpecifier::Public);
+    }
+
+    #[test]
+    fn test_parse_protected() {
+        let function: ForeignItemFn = parse_quote! {
+            #[inherit(protected)]
+            #[cxx_name = "beginResetModel"]
+            fn begin_reset_model(self: Pin<&mut T>);
+        };
+
+        let parsed =
+            ParsedInheritedMethod::parse(function, Safety::Safe, CaseConversion::none()).unwrap();
+
+        assert_eq!(parsed.access, AccessSpecifier::Protected);
+        let friend_wrapper = parsed.friend_wrapper_name();
+        assert_eq!(
+            friend_wrapper.rust_unqualified(),
+            &format_ident!("T_inherit_begin_reset_model")
+        );
+        assert_eq!(
+            friend_wrapper.cxx_unqualified(),
+            "T_beginResetModelCxxQtInherit"
+        );
     }
 }
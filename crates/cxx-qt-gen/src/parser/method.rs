@@ -5,12 +5,19 @@
 use crate::parser::CaseConversion;
 use crate::{
     naming::Name,
-    parser::{check_safety, extract_cfgs, parameter::ParsedFunctionParameter, require_attributes},
+    parser::{
+        check_safety, extract_cfgs, extract_docs, parameter::ParsedFunctionParameter,
+        require_attributes, signals::ParsedSignal,
+    },
     syntax::{foreignmod, safety::Safety, types},
 };
 use core::ops::Deref;
+use quote::{format_ident, quote};
 use std::collections::{BTreeMap, HashSet};
-use syn::{Attribute, ForeignItemFn, Ident, Result};
+use syn::{
+    parse_quote_spanned, spanned::Spanned, Attribute, Error, ForeignItemFn, Ident, Result,
+    ReturnType, Type,
+};
 
 /// Describes a C++ specifier for the Q_INVOKABLE
 #[derive(Eq, Hash, PartialEq)]
@@ -52,8 +59,10 @@ pub struct ParsedMethod {
     pub specifiers: HashSet<ParsedQInvokableSpecifiers>,
     /// Whether the method is qinvokable
     pub is_qinvokable: bool,
-    // No docs field since the docs should be on the method implementation outside the bridge
-    // This means any docs on the bridge declaration would be ignored
+    /// Whether the method is an async invokable, whose result is returned to C++ as a QFuture
+    pub is_async: bool,
+    /// All the docs (each line) of the method, these are written into the C++ header
+    pub docs: Vec<Attribute>,
     /// Cfgs for the method
     pub cfgs: Vec<Attribute>,
 }
@@ -110,18 +119,78 @@ impl ParsedMethod {
         let fields = MethodFields::parse(method, auto_case)?;
         let attrs = require_attributes(&fields.method.attrs, &Self::ALLOWED_ATTRS)?;
         let cfgs = extract_cfgs(&fields.method.attrs);
+        let docs = extract_docs(&fields.method.attrs);
 
         // Determine if the method is invokable
         let is_qinvokable = attrs.contains_key("qinvokable");
         let specifiers = ParsedQInvokableSpecifiers::from_attrs(attrs);
 
+        let is_async = fields.method.sig.asyncness.is_some();
+        if is_async {
+            if !is_qinvokable {
+                return Err(Error::new_spanned(
+                    fields.method.sig.asyncness,
+                    "Only #[qinvokable] methods can be async!",
+                ));
+            }
+            if !specifiers.is_empty() {
+                return Err(Error::new_spanned(
+                    &fields.method.sig,
+                    "Async invokables cannot be virtual, override or final!",
+                ));
+            }
+            // The arguments are moved into the future, which outlives the call from C++
+            if let Some(parameter) = fields
+                .parameters
+                .iter()
+                .find(|parameter| matches!(parameter.ty, Type::Reference(_)))
+            {
+                return Err(Error::new_spanned(
+                    &parameter.ty,
+                    "Parameters of async invokables cannot be references!",
+                ));
+            }
+        }
+
         Ok(Self {
             method_fields: fields,
             specifiers,
             is_qinvokable,
+            is_async,
+            docs,
             cfgs,
         })
     }
+
+    /// The signal which an async invokable emits with its result once it has finished
+    ///
+    /// For an invokable `fetch` this is `fetch_finished` in Rust and `fetchFinished` in C++.
+    pub fn finished_signal(&self) -> Result<ParsedSignal> {
+        let qobject_ident = &self.qobject_ident;
+        let ident = format_ident!("{}_finished", self.name.rust_unqualified());
+        let cxx_name = format!("{}Finished", self.name.cxx_unqualified());
+        let doc = format!(
+            " Emitted with the result of {} once it has finished",
+            self.name.cxx_unqualified()
+        );
+        let result = match &self.method.sig.output {
+            ReturnType::Default => None,
+            ReturnType::Type(_, ty) => Some(quote! { result: #ty }),
+        };
+        let cfgs = &self.cfgs;
+
+        ParsedSignal::parse(
+            parse_quote_spanned! {
+                self.method.span() =>
+                #(#cfgs)*
+                #[doc = #doc]
+                #[cxx_name = #cxx_name]
+                fn #ident(self: Pin<&mut #qobject_ident>, #result);
+            },
+            Safety::Safe,
+            CaseConversion::none(),
+        )
+    }
 }
 
 impl Deref for ParsedMethod {
@@ -12,6 +12,8 @@ pub mod method;
 pub mod parameter;
 pub mod property;
 pub mod qenum;
+pub mod qgadget;
+pub mod qmlmodel;
 pub mod qnamespace;
 pub mod qobject;
 pub mod signals;
@@ -86,6 +88,40 @@ impl CaseConversion {
     }
 }
 
+/// The C++ access specifier of a member, eg from `#[inherit(protected)]` or `#[qsignal(private)]`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum AccessSpecifier {
+    #[default]
+    Public,
+    Protected,
+    Private,
+}
+
+impl AccessSpecifier {
+    /// Parse the access specifier from the arguments of an attribute, which is public without arguments
+    pub fn from_attr(attr: &Attribute) -> Result<Self> {
+        match &attr.meta {
+            Meta::Path(_) => Ok(Self::Public),
+            Meta::List(list) => {
+                let ident: Ident = list.parse_args()?;
+                match ident.to_string().as_str() {
+                    "public" => Ok(Self::Public),
+                    "protected" => Ok(Self::Protected),
+                    "private" => Ok(Self::Private),
+                    _ => Err(Error::new(
+                        ident.span(),
+                        "Invalid access specifier! You can use either `public`, `protected` or `private`",
+                    )),
+                }
+            }
+            Meta::NameValue(_) => Err(Error::new(
+                attr.span(),
+                "Invalid attribute format! Use like `inherit(protected)` or `qsignal(private)`",
+            )),
+        }
+    }
+}
+
 /// Validates that an invokable is either unsafe, or is in an unsafe extern block
 fn check_safety(method: &ForeignItemFn, safety: &Safety) -> Result<()> {
     if safety == &Safety::Unsafe && method.sig.unsafety.is_none() {
@@ -543,4 +579,29 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    fn test_access_specifier() {
+        let parse = |attr: Attribute| AccessSpecifier::from_attr(&attr);
+        assert_eq!(
+            parse(parse_quote! { #[inherit] }).unwrap(),
+            AccessSpecifier::Public
+        );
+        assert_eq!(
+            parse(parse_quote! { #[inherit(public)] }).unwrap(),
+            AccessSpecifier::Public
+        );
+        assert_eq!(
+            parse(parse_quote! { #[inherit(protected)] }).unwrap(),
+            AccessSpecifier::Protected
+        );
+        assert_eq!(
+            parse(parse_quote! { #[qsignal(private)] }).unwrap(),
+            AccessSpecifier::Private
+        );
+
+        assert!(parse(parse_quote! { #[inherit(internal)] }).is_err());
+        assert!(parse(parse_quote! { #[inherit(protected, private)] }).is_err());
+        assert!(parse(parse_quote! { #[inherit = "protected"] }).is_err());
+    }
 }
@@ -31,7 +31,11 @@ pub struct QPropertyFlags {
     pub(crate) read: FlagState,
     pub(crate) write: Option<FlagState>,
     pub(crate) notify: Option<FlagState>,
-    pub(crate) reset: Option<Ident>, // TODO: in future might be able to generate the function if T has a default
+    pub(crate) reset: Option<FlagState>,
+    /// The value an automatically generated reset function assigns, `T::default()` if this is None
+    pub(crate) reset_value: Option<Expr>,
+    /// Whether the property has a Qt 6 `QBindable` accessor
+    pub(crate) bindable: bool,
     pub(crate) is_final: bool,
     pub(crate) constant: bool,
     pub(crate) required: bool,
@@ -45,6 +49,8 @@ impl Default for QPropertyFlags {
             write: Some(FlagState::Auto),
             notify: Some(FlagState::Auto),
             reset: None,
+            reset_value: None,
+            bindable: false,
             is_final: false,
             constant: false,
             required: false,
@@ -60,6 +66,10 @@ pub struct ParsedQProperty {
     pub ty: Type,
     /// Property flag collection
     pub flags: QPropertyFlags,
+    /// All the docs (each line) of the property
+    pub docs: Vec<Attribute>,
+    /// Cfgs for the property
+    pub cfgs: Vec<Attribute>,
 }
 
 fn parse_meta_name_value(name_value: &MetaNameValue) -> Result<(Ident, Ident)> {
@@ -114,6 +124,8 @@ impl ParsedQProperty {
                     name: Name::new(ident).with_options(None, None, auto_case),
                     ty,
                     flags: QPropertyFlags::default(),
+                    docs: vec![],
+                    cfgs: vec![],
                 })
             } else {
                 let _comma = input.parse::<Token![,]>()?; // Start of final identifiers
@@ -133,6 +145,8 @@ impl ParsedQProperty {
                 let mut required = false;
                 let mut is_final = false;
                 let mut reset = None;
+                let mut reset_value = None;
+                let mut bindable = false;
                 let mut cxx_name = None;
                 let mut rust_name = None;
 
@@ -165,10 +179,11 @@ impl ParsedQProperty {
                                 "CONSTANT" => constant = true,
                                 "REQUIRED" => required = true,
                                 "FINAL" => is_final = true,
-                                "RESET" => reset = require_value("RESET flag", "RESET = my_reset_fn")?,
+                                "RESET" => map_auto_or_custom(&mut reset, &value),
+                                "BINDABLE" => bindable = true,
                                 _ => return Err(Error::new(
                                     ident.span(),
-                                    "Invalid flag passed!, must be one of\n  READ, WRITE, NOTIFY, RESET, CONSTANT, REQUIRED, FINAL or cxx_name / rust_name",
+                                    "Invalid flag passed!, must be one of\n  READ, WRITE, NOTIFY, RESET, BINDABLE, CONSTANT, REQUIRED, FINAL or cxx_name / rust_name",
                                 ))
                             }
                         }
@@ -178,6 +193,15 @@ impl ParsedQProperty {
                 };
 
                 for flag in flags {
+                    // RESET(value) is an automatic reset which assigns the given value
+                    if let Meta::List(list) = &flag {
+                        if list.path.is_ident("RESET") {
+                            reset_value = Some(list.parse_args::<Expr>()?);
+                            update_fields(list.path.require_ident()?, None)?;
+                            continue;
+                        }
+                    }
+
                     let (field, maybe_value) = parse_meta(flag)?;
                     update_fields(&field, maybe_value)?;
                 }
@@ -190,6 +214,14 @@ impl ParsedQProperty {
                     ))
                 }
 
+                // The bindable is kept in sync with the Rust field by the generated setter
+                if bindable && write != Some(FlagState::Auto) {
+                    return Err(Error::new(
+                        punctuated_flags.span(),
+                        "QProperties marked as BINDABLE must have an automatically generated setter, pass WRITE without a custom function!",
+                    ))
+                }
+
                 let name = Name::new(ident).with_options(cxx_name.map(|ident| ident.to_string()), rust_name, auto_case);
 
                 // This check is needed otherwise this fn would error unless READ, WRITE, etc... was passed with cxx_name
@@ -203,10 +235,14 @@ impl ParsedQProperty {
                                 write,
                                 notify,
                                 reset,
+                                reset_value,
+                                bindable,
                                 is_final,
                                 constant,
                                 required,
                             },
+                            docs: vec![],
+                            cfgs: vec![],
                         })
                     } else {
                         Err(Error::new(
@@ -219,6 +255,8 @@ impl ParsedQProperty {
                         name,
                         ty,
                         flags: QPropertyFlags::default(), // This block is hit if no flags, or only cxx / rust name were passed
+                        docs: vec![],
+                        cfgs: vec![],
                     })
                 }
             }
@@ -317,8 +355,12 @@ mod tests {
 
             // Non-constant property with constant flag
             { #[qproperty(T, name, READ, WRITE, NOTIFY, CONSTANT)] }
-            // Reset was not provided a function
-            { #[qproperty(T, name, READ, RESET)] }
+            // Reset value is not an expression
+            { #[qproperty(T, name, READ, RESET(=))] }
+            // Bindable property without a setter
+            { #[qproperty(T, name, READ, BINDABLE)] }
+            // Bindable property with a custom setter
+            { #[qproperty(T, name, READ, WRITE = my_setter, BINDABLE)] }
             // Unknown flag
             { #[qproperty(T, name, READ = blah, a, NOTIFY = blahblah)] }
             // Invalid function specification syntax
@@ -395,7 +437,10 @@ mod tests {
         assert!(property.flags.required);
         assert!(property.flags.is_final);
 
-        assert_eq!(property.flags.reset, Some(format_ident!("my_reset")));
+        assert_eq!(
+            property.flags.reset,
+            Some(FlagState::Custom(format_ident!("my_reset")))
+        );
         assert_eq!(property.flags.notify, Some(FlagState::Auto));
         assert_eq!(property.flags.write, Some(FlagState::Auto));
     }
@@ -422,4 +467,47 @@ mod tests {
             Some(FlagState::Custom(format_ident!("my_notifier")))
         );
     }
+
+    #[test]
+    fn test_parse_flags_reset_auto() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, READ, WRITE, NOTIFY, RESET)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.flags.reset, Some(FlagState::Auto));
+        assert!(property.flags.reset_value.is_none());
+    }
+
+    #[test]
+    fn test_parse_flags_reset_value() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, READ, RESET(T::new(42)), NOTIFY)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.flags.reset, Some(FlagState::Auto));
+        assert_eq!(
+            property.flags.reset_value,
+            Some(parse_quote! { T::new(42) })
+        );
+        assert_eq!(property.flags.notify, Some(FlagState::Auto));
+    }
+
+    #[test]
+    fn test_parse_flags_bindable() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, READ, WRITE, NOTIFY, BINDABLE)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert!(property.flags.bindable);
+        assert_eq!(property.flags.write, Some(FlagState::Auto));
+
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name)]
+            struct MyStruct;
+        };
+        assert!(!mock_property(input).flags.bindable);
+    }
 }
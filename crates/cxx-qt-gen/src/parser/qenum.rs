@@ -5,14 +5,26 @@
 
 use crate::parser::{extract_cfgs, extract_docs, CaseConversion};
 use crate::{naming::Name, parser::require_attributes, syntax::path::path_compare_str};
-use quote::ToTokens;
-use syn::{Attribute, Ident, ItemEnum, Result, Variant};
+use quote::{format_ident, ToTokens};
+use syn::{Attribute, Expr, ExprLit, ExprUnary, Ident, ItemEnum, Lit, Result, UnOp, Variant};
+
+/// A single variant of a QEnum
+pub struct ParsedQEnumVariant {
+    /// The name of the variant
+    pub ident: Ident,
+    /// The explicit value of the variant, if one was given
+    pub discriminant: Option<i128>,
+}
 
 pub struct ParsedQEnum {
     /// The name of the QObject
     pub name: Name,
     /// the values of the QEnum
-    pub variants: Vec<Ident>,
+    pub variants: Vec<ParsedQEnumVariant>,
+    /// The integer type used to represent the QEnum, from `#[repr(...)]` or `i32` by default
+    pub repr: Ident,
+    /// The name of the QFlags type for this QEnum, from `#[qflags(...)]`
+    pub flags: Option<Name>,
     /// The QObject to which this QEnum belongs.
     pub qobject: Option<Ident>,
     /// The original enum item
@@ -24,10 +36,103 @@ pub struct ParsedQEnum {
 }
 
 impl ParsedQEnum {
-    const ALLOWED_ATTRS: [&'static str; 6] =
-        ["cfg", "doc", "cxx_name", "rust_name", "namespace", "qenum"];
-    fn parse_variant(variant: &Variant) -> Result<Ident> {
-        fn err(spanned: &impl ToTokens, message: &str) -> Result<Ident> {
+    const ALLOWED_ATTRS: [&'static str; 8] = [
+        "cfg",
+        "doc",
+        "cxx_name",
+        "rust_name",
+        "namespace",
+        "qenum",
+        "qflags",
+        "repr",
+    ];
+
+    /// The integer types which can be used with `#[repr(...)]` and their range of values
+    ///
+    /// 64-bit types are not allowed, as the meta-object system stores enum values in an int.
+    const ALLOWED_REPRS: [(&'static str, i128, i128); 6] = [
+        ("i8", i8::MIN as i128, i8::MAX as i128),
+        ("i16", i16::MIN as i128, i16::MAX as i128),
+        ("i32", i32::MIN as i128, i32::MAX as i128),
+        ("u8", u8::MIN as i128, u8::MAX as i128),
+        ("u16", u16::MIN as i128, u16::MAX as i128),
+        ("u32", u32::MIN as i128, u32::MAX as i128),
+    ];
+
+    fn parse_repr(attrs: &[Attribute]) -> Result<Ident> {
+        let Some(attr) = attrs
+            .iter()
+            .find(|attr| path_compare_str(attr.path(), &["repr"]))
+        else {
+            return Ok(format_ident!("i32"));
+        };
+
+        let repr: Ident = attr.parse_args()?;
+        if Self::ALLOWED_REPRS
+            .iter()
+            .any(|(allowed, _, _)| repr == allowed)
+        {
+            Ok(repr)
+        } else {
+            Err(syn::Error::new_spanned(
+                repr,
+                "QEnum repr must be one of i8, i16, i32, u8, u16 or u32",
+            ))
+        }
+    }
+
+    fn parse_flags(attrs: &[Attribute], name: &Name, repr: &Ident) -> Result<Option<Name>> {
+        let Some(attr) = attrs
+            .iter()
+            .find(|attr| path_compare_str(attr.path(), &["qflags"]))
+        else {
+            return Ok(None);
+        };
+
+        let flags_ident: Ident = attr.parse_args()?;
+        // QFlags stores the value in either an int or an unsigned int
+        if repr != "i32" && repr != "u32" {
+            return Err(syn::Error::new_spanned(
+                repr,
+                "QFlags can only be used with a QEnum with a repr of i32 or u32",
+            ));
+        }
+
+        let mut flags = Name::new(flags_ident);
+        if let Some(module) = name.module() {
+            flags = flags.with_module(module.clone());
+        }
+        flags.set_namespace(name.namespace().map(str::to_owned));
+        Ok(Some(flags))
+    }
+
+    fn parse_discriminant(expr: &Expr) -> Result<i128> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => lit.base10_parse(),
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => match expr.as_ref() {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit), ..
+                }) => lit.base10_parse::<i128>().map(|value| -value),
+                _ => Err(syn::Error::new_spanned(
+                    expr,
+                    "QEnum variant values must be integer literals",
+                )),
+            },
+            _ => Err(syn::Error::new_spanned(
+                expr,
+                "QEnum variant values must be integer literals",
+            )),
+        }
+    }
+
+    fn parse_variant(variant: &Variant) -> Result<ParsedQEnumVariant> {
+        fn err(spanned: &impl ToTokens, message: &str) -> Result<ParsedQEnumVariant> {
             Err(syn::Error::new_spanned(spanned, message))
         }
 
@@ -44,14 +149,16 @@ impl ParsedQEnum {
                 "QEnum variants can only have #[doc=\"...\"] attributes",
             );
         }
-        if let Some(discriminant) = variant.discriminant.as_ref() {
-            return err(
-                &discriminant.1,
-                "QEnum variants with explicit values are not supported (yet)",
-            );
-        }
+        let discriminant = variant
+            .discriminant
+            .as_ref()
+            .map(|(_, expr)| Self::parse_discriminant(expr))
+            .transpose()?;
 
-        Ok(variant.ident.clone())
+        Ok(ParsedQEnumVariant {
+            ident: variant.ident.clone(),
+            discriminant,
+        })
     }
 
     pub fn parse(
@@ -86,16 +193,37 @@ impl ParsedQEnum {
             ));
         }
 
-        let variants = qenum
+        let repr = Self::parse_repr(&qenum.attrs)?;
+        let flags = Self::parse_flags(&qenum.attrs, &name, &repr)?;
+        let variants: Vec<ParsedQEnumVariant> = qenum
             .variants
             .iter()
             .map(Self::parse_variant)
             .collect::<Result<_>>()?;
 
+        // Ensure that all of the values, including implicit ones, fit into the repr
+        let (_, min, max) = Self::ALLOWED_REPRS
+            .iter()
+            .find(|(allowed, _, _)| repr == allowed)
+            .expect("repr has already been validated");
+        let mut next_value = 0;
+        for (variant, parsed) in qenum.variants.iter().zip(variants.iter()) {
+            let value = parsed.discriminant.unwrap_or(next_value);
+            if value < *min || value > *max {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    format!("QEnum variant value {value} does not fit into {repr}"),
+                ));
+            }
+            next_value = value + 1;
+        }
+
         Ok(Self {
             name,
             qobject,
             variants,
+            repr,
+            flags,
             docs,
             cfgs,
             item: qenum,
@@ -115,7 +243,7 @@ mod tests {
         qenum
             .variants
             .iter()
-            .map(Ident::to_string)
+            .map(|variant| variant.ident.to_string())
             .collect::<Vec<_>>()
     }
 
@@ -179,10 +307,35 @@ mod tests {
                 enum MyEnum { A }
             }
             {
-                // Repr is not allowed either
-                #[repr(u32)]
+                // Only integer reprs are allowed
+                #[repr(C)]
+                enum MyEnum { A }
+            }
+            {
+                // 64-bit reprs are not supported by the meta-object system
+                #[repr(i64)]
+                enum MyEnum { A }
+            }
+            {
+                // 64-bit reprs are not supported by the meta-object system
+                #[repr(u64)]
                 enum MyEnum { A }
             }
+            {
+                // Value does not fit into the repr
+                #[repr(u8)]
+                enum MyEnum { A = 256 }
+            }
+            {
+                // Implicit value does not fit into the repr
+                #[repr(i8)]
+                enum MyEnum { A = 127, B }
+            }
+            {
+                // Negative values need a signed repr
+                #[repr(u32)]
+                enum MyEnum { A = -1 }
+            }
             {
                 // Fields are not allowed
                 enum MyEnum {
@@ -203,14 +356,85 @@ mod tests {
                 }
             }
             {
-                // TODO: Allow discriminants
+                // Values must be integer literals
                 enum MyEnum {
-                    A = 1
+                    A = 1 + 2
                 }
             }
+            {
+                // QFlags need an int sized repr
+                #[qflags(MyFlags)]
+                #[repr(u8)]
+                enum MyEnum { A = 1 }
+            }
+            {
+                // QFlags must be given a name
+                #[qflags]
+                enum MyEnum { A = 1 }
+            }
         }
     }
 
+    #[test]
+    fn parse_flags() {
+        let qenum: ItemEnum = parse_quote! {
+            #[qflags(MyFlags)]
+            #[namespace = "my_namespace"]
+            #[repr(u32)]
+            enum MyFlag {
+                A = 1,
+                B = 2,
+            }
+        };
+        let parsed = ParsedQEnum::parse(qenum, None, None, &mock_module()).unwrap();
+        let flags = parsed.flags.unwrap();
+        assert_eq!(flags.rust_unqualified(), "MyFlags");
+        assert_eq!(flags.namespace(), Some("my_namespace"));
+        assert_eq!(flags.cxx_qualified(), "my_namespace::MyFlags");
+        assert_tokens_eq(&flags.rust_qualified(), quote::quote! { qobject::MyFlags });
+    }
+
+    #[test]
+    fn parse_discriminants() {
+        let qenum: ItemEnum = parse_quote! {
+            #[repr(i16)]
+            enum MyEnum {
+                A = 4,
+                B,
+                C = -0x10,
+            }
+        };
+
+        let parsed =
+            ParsedQEnum::parse(qenum, Some(format_ident!("MyObject")), None, &mock_module())
+                .unwrap();
+        assert_eq!(parsed.repr, "i16");
+        assert_eq!(*variants_to_strings(&parsed), ["A", "B", "C"]);
+        assert_eq!(
+            parsed
+                .variants
+                .iter()
+                .map(|variant| variant.discriminant)
+                .collect::<Vec<_>>(),
+            [Some(4), None, Some(-16)]
+        );
+    }
+
+    #[test]
+    fn parse_default_repr() {
+        let qenum: ItemEnum = parse_quote! {
+            enum MyEnum {
+                A,
+            }
+        };
+        let parsed =
+            ParsedQEnum::parse(qenum, Some(format_ident!("MyObject")), None, &mock_module())
+                .unwrap();
+        assert_eq!(parsed.repr, "i32");
+        assert!(parsed.variants[0].discriminant.is_none());
+        assert!(parsed.flags.is_none());
+    }
+
     #[test]
     fn parse_missing_namespace() {
         let qenum: ItemEnum = parse_quote! {
@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    naming::Name,
+    parser::{extract_cfgs, extract_docs, require_attributes, CaseConversion},
+    syntax::{expr::expr_to_string, path::path_compare_str},
+};
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Attribute, Error, Fields, Ident, ItemStruct, Meta,
+    Path, Result, Token, Type,
+};
+
+/// A field of a `#[qgadget]` struct, which is exposed as a `Q_PROPERTY` of the gadget
+pub struct ParsedQGadgetField {
+    /// The ident of the field, which is used as the name in both Rust and C++
+    pub ident: Ident,
+    /// The [syn::Type] of the field
+    pub ty: Type,
+    /// Docs of the field
+    pub docs: Vec<Attribute>,
+}
+
+/// A shared struct marked with `#[qgadget]`, which is defined as a `Q_GADGET` in C++
+pub struct ParsedQGadget {
+    /// The name of the gadget
+    pub name: Name,
+    /// The fields of the gadget, in declaration order
+    pub fields: Vec<ParsedQGadgetField>,
+    /// The name of the QML value type, if the struct has a `#[qml_element]` attribute
+    pub qml_name: Option<String>,
+    /// Whether the struct derives `PartialEq`, so CXX defines the equality operators
+    pub partial_eq: bool,
+    /// Whether the struct derives `PartialOrd`, so CXX defines the comparison operators
+    pub partial_ord: bool,
+    /// The original struct item, without any CXX-Qt attributes
+    pub item: ItemStruct,
+    /// Docs from the gadget
+    pub docs: Vec<Attribute>,
+    /// Cfgs from the gadget
+    pub cfgs: Vec<Attribute>,
+}
+
+impl ParsedQGadget {
+    const ALLOWED_ATTRS: [&'static str; 7] = [
+        "cfg",
+        "cxx_name",
+        "derive",
+        "doc",
+        "namespace",
+        "qgadget",
+        "qml_element",
+    ];
+
+    pub fn parse(
+        mut item: ItemStruct,
+        parent_namespace: Option<&str>,
+        module: &Ident,
+    ) -> Result<Self> {
+        let attributes = require_attributes(&item.attrs, &Self::ALLOWED_ATTRS)?;
+        let cfgs = extract_cfgs(&item.attrs);
+        let docs = extract_docs(&item.attrs);
+
+        let name = Name::from_ident_and_attrs(
+            &item.ident,
+            &item.attrs,
+            parent_namespace,
+            Some(module),
+            CaseConversion::none(),
+        )?;
+
+        let qml_name = attributes
+            .get("qml_element")
+            .map(|attr| match &attr.meta {
+                Meta::NameValue(name_value) => expr_to_string(&name_value.value),
+                // QML value types must start with a lowercase letter
+                _ => Ok(lowercase_first(&name.cxx_unqualified())),
+            })
+            .transpose()?;
+
+        let mut partial_eq = false;
+        let mut partial_ord = false;
+        for attr in item
+            .attrs
+            .iter()
+            .filter(|attr| path_compare_str(attr.meta.path(), &["derive"]))
+        {
+            let derives = attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?;
+            partial_eq |= derives.iter().any(|path| path.is_ident("PartialEq"));
+            partial_ord |= derives.iter().any(|path| path.is_ident("PartialOrd"));
+        }
+
+        let Fields::Named(named_fields) = &item.fields else {
+            return Err(Error::new(
+                item.span(),
+                "A #[qgadget] struct must have named fields!",
+            ));
+        };
+        let fields = named_fields
+            .named
+            .iter()
+            .map(|field| {
+                require_attributes(&field.attrs, &["doc"])?;
+                Ok(ParsedQGadgetField {
+                    // Named fields always have an ident
+                    ident: field.ident.clone().unwrap(),
+                    ty: field.ty.clone(),
+                    docs: extract_docs(&field.attrs),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // The struct is passed through to CXX as a shared struct, so remove our attributes
+        item.attrs.retain(|attr| {
+            !path_compare_str(attr.meta.path(), &["qgadget"])
+                && !path_compare_str(attr.meta.path(), &["qml_element"])
+        });
+
+        Ok(Self {
+            name,
+            fields,
+            qml_name,
+            partial_eq,
+            partial_ord,
+            item,
+            docs,
+            cfgs,
+        })
+    }
+}
+
+fn lowercase_first(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_parse_errors;
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    fn parse(item: ItemStruct) -> Result<ParsedQGadget> {
+        ParsedQGadget::parse(item, Some("my_namespace"), &format_ident!("qobject"))
+    }
+
+    #[test]
+    fn test_parse_qgadget() {
+        let qgadget = parse(parse_quote! {
+            /// A point
+            #[qgadget]
+            #[derive(Clone, PartialEq)]
+            struct Point {
+                /// The x coordinate
+                x: i32,
+                y: i32,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(qgadget.name.cxx_unqualified(), "Point");
+        assert_eq!(qgadget.name.namespace(), Some("my_namespace"));
+        assert_eq!(qgadget.fields.len(), 2);
+        assert_eq!(qgadget.fields[0].ident, "x");
+        assert_eq!(qgadget.fields[0].docs.len(), 1);
+        assert!(qgadget.fields[1].docs.is_empty());
+        assert!(qgadget.qml_name.is_none());
+        assert!(qgadget.partial_eq);
+        assert!(!qgadget.partial_ord);
+        assert_eq!(qgadget.docs.len(), 1);
+        // Only the CXX-Qt attributes are removed from the struct
+        assert_eq!(qgadget.item.attrs.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_qml_element() {
+        let qgadget = parse(parse_quote! {
+            #[qgadget]
+            #[qml_element]
+            struct MyPoint {
+                x: i32,
+            }
+        })
+        .unwrap();
+        assert_eq!(qgadget.qml_name.as_deref(), Some("myPoint"));
+
+        let qgadget = parse(parse_quote! {
+            #[qgadget]
+            #[qml_element = "point2d"]
+            struct MyPoint {
+                x: i32,
+            }
+        })
+        .unwrap();
+        assert_eq!(qgadget.qml_name.as_deref(), Some("point2d"));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_parse_errors! {
+            parse =>
+
+            { #[qgadget] struct Point(i32, i32); }
+            {
+                #[qgadget]
+                #[repr(C)]
+                struct Point { x: i32 }
+            }
+            {
+                #[qgadget]
+                struct Point {
+                    #[cxx_name = "X"]
+                    x: i32,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::{attribute::attribute_get_path, expr::expr_to_string, path::path_compare_str};
+use syn::{
+    parse::ParseStream, spanned::Spanned, Attribute, Error, Fields, Ident, ItemStruct, Meta,
+    Result, Token, Type,
+};
+
+/// The methods which are generated for a `#[qml_model]` QObject to modify its rows
+pub const MODEL_ROW_HELPERS: [&str; 7] = [
+    "clear_rows",
+    "insert_row",
+    "move_row",
+    "push_row",
+    "remove_row",
+    "reset_rows",
+    "set_row",
+];
+
+/// The base classes which a `#[qml_model]` QObject cannot have, as they are not list models
+const NON_LIST_MODEL_BASES: [&str; 3] = ["QAbstractItemModel", "QAbstractTableModel", "QObject"];
+
+/// The `#[qml_model(RowType, rows_field)]` attribute on a QObject
+///
+/// This makes the QObject a list model where each row is an element of `rows_field`,
+/// which must be a `Vec<RowType>` on the Rust struct of the QObject.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsedQmlModel {
+    /// The ident of the shared struct used for each row of the model
+    pub row: Ident,
+    /// The ident of the `Vec` field on the Rust struct storing the rows
+    pub field: Ident,
+}
+
+impl ParsedQmlModel {
+    pub fn parse(attr: &Attribute) -> Result<Self> {
+        attr.parse_args_with(|input: ParseStream| -> Result<Self> {
+            let row = input.parse()?;
+            let _comma = input.parse::<Token![,]>()?;
+            let field = input.parse()?;
+            // Allow for a trailing comma
+            if input.peek(Token![,]) {
+                let _comma = input.parse::<Token![,]>()?;
+            }
+            Ok(Self { row, field })
+        })
+    }
+
+    /// Returns an error if the given #[base] class is known not to be a QAbstractListModel
+    ///
+    /// The generated overrides and helpers use the row based methods of QAbstractListModel.
+    pub fn check_base_class(base_class: &Ident) -> Result<()> {
+        if NON_LIST_MODEL_BASES.contains(&base_class.to_string().as_str()) {
+            return Err(Error::new_spanned(
+                base_class,
+                "#[qml_model] requires QAbstractListModel or a subclass of it as the #[base] class!",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A field of a row struct which is exposed as a role of the model
+pub struct ParsedQRole {
+    /// The ident of the field in the row struct
+    pub ident: Ident,
+    /// The name of the role as seen from QML
+    pub name: String,
+    /// The [syn::Type] of the field
+    pub ty: Type,
+}
+
+/// A shared struct which has fields marked with `#[qrole]`
+pub struct ParsedQmlModelRow {
+    /// The ident of the shared struct
+    pub ident: Ident,
+    /// The roles of the row, in declaration order
+    pub roles: Vec<ParsedQRole>,
+}
+
+impl ParsedQmlModelRow {
+    /// Returns true if any field of the struct has a `#[qrole]` attribute
+    pub fn has_roles(item: &ItemStruct) -> bool {
+        item.fields
+            .iter()
+            .any(|field| attribute_get_path(&field.attrs, &["qrole"]).is_some())
+    }
+
+    /// Parse the roles of the given struct and remove the `#[qrole]` attributes,
+    /// so that the struct can be passed through to CXX as a shared struct.
+    pub fn parse(item: &mut ItemStruct) -> Result<Self> {
+        let Fields::Named(fields) = &mut item.fields else {
+            return Err(Error::new(
+                item.span(),
+                "Structs with #[qrole] fields must have named fields!",
+            ));
+        };
+
+        let mut roles = vec![];
+        for field in fields.named.iter_mut() {
+            let Some(index) = field
+                .attrs
+                .iter()
+                .position(|attr| path_compare_str(attr.meta.path(), &["qrole"]))
+            else {
+                continue;
+            };
+            let attr = field.attrs.remove(index);
+            // Named fields always have an ident
+            let ident = field.ident.clone().unwrap();
+            let name = match &attr.meta {
+                Meta::Path(_) => ident.to_string(),
+                Meta::NameValue(name_value) => expr_to_string(&name_value.value)?,
+                Meta::List(_) => {
+                    return Err(Error::new(
+                        attr.span(),
+                        "Invalid syntax, roles must be specified as either `#[qrole]` or `#[qrole = \"name\"]`!",
+                    ))
+                }
+            };
+
+            if roles.iter().any(|role: &ParsedQRole| role.name == name) {
+                return Err(Error::new(
+                    attr.span(),
+                    format!("Duplicate role name `{name}`!"),
+                ));
+            }
+
+            roles.push(ParsedQRole {
+                ident,
+                name,
+                ty: field.ty.clone(),
+            });
+        }
+
+        Ok(Self {
+            ident: item.ident.clone(),
+            roles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_parse_errors;
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_qml_model() {
+        let attr: Attribute = parse_quote! { #[qml_model(Row, rows)] };
+        let model = ParsedQmlModel::parse(&attr).unwrap();
+        assert_eq!(model.row, "Row");
+        assert_eq!(model.field, "rows");
+    }
+
+    #[test]
+    fn test_parse_row() {
+        let mut item: ItemStruct = parse_quote! {
+            struct Row {
+                #[qrole]
+                name: QString,
+                #[qrole = "itemValue"]
+                value: i32,
+                hidden: bool,
+            }
+        };
+        assert!(ParsedQmlModelRow::has_roles(&item));
+
+        let row = ParsedQmlModelRow::parse(&mut item).unwrap();
+        assert_eq!(row.ident, "Row");
+        assert_eq!(row.roles.len(), 2);
+        assert_eq!(row.roles[0].ident, "name");
+        assert_eq!(row.roles[0].name, "name");
+        assert_eq!(row.roles[0].ty, parse_quote! { QString });
+        assert_eq!(row.roles[1].ident, "value");
+        assert_eq!(row.roles[1].name, "itemValue");
+        assert_eq!(row.roles[1].ty, parse_quote! { i32 });
+
+        // The qrole attributes are stripped for CXX
+        assert_eq!(
+            quote! { #item }.to_string(),
+            quote! {
+                struct Row {
+                    name: QString,
+                    value: i32,
+                    hidden: bool,
+                }
+            }
+            .to_string()
+        );
+        assert!(!ParsedQmlModelRow::has_roles(&item));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_errors! {
+            |mut item| ParsedQmlModelRow::parse(&mut item) =>
+
+            { struct Row(#[qrole] i32); }
+            {
+                struct Row {
+                    #[qrole(name)]
+                    value: i32,
+                }
+            }
+            {
+                struct Row {
+                    #[qrole = "value"]
+                    a: i32,
+                    #[qrole = "value"]
+                    b: i32,
+                }
+            }
+        }
+        assert_parse_errors! {
+            |attr| ParsedQmlModel::parse(&attr) =>
+
+            { #[qml_model(Row)] }
+            { #[qml_model] }
+        }
+    }
+}
@@ -5,14 +5,20 @@
 
 use crate::{
     naming::Name,
-    parser::{property::ParsedQProperty, require_attributes},
+    parser::{
+        extract_cfgs, extract_docs, property::ParsedQProperty, qmlmodel::ParsedQmlModel,
+        require_attributes,
+    },
     syntax::{expr::expr_to_string, foreignmod::ForeignTypeIdentAlias, path::path_compare_str},
 };
 #[cfg(test)]
 use quote::format_ident;
 
 use crate::parser::CaseConversion;
-use syn::{Attribute, Error, Expr, Ident, Meta, Result};
+use syn::{
+    parse::ParseStream, punctuated::Punctuated, Attribute, Error, Expr, Ident, LitStr, Meta,
+    Result, Token,
+};
 
 /// Metadata for registering QML element
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -20,6 +26,27 @@ pub struct QmlElementMetadata {
     pub name: String,
     pub uncreatable: bool,
     pub singleton: bool,
+    /// Whether the type is registered without a name, from #[qml_anonymous]
+    pub anonymous: bool,
+    /// Whether the type is registered as an interface, from #[qml_interface]
+    pub interface: bool,
+    /// The type which extends this type in QML, from #[qml_extended = T]
+    pub extended: Option<Ident>,
+    /// The type which this type registers in QML on its behalf, from #[qml_foreign = T]
+    pub foreign: Option<Ident>,
+    /// The type of the attached properties object, from #[qml_attached = T]
+    pub attached: Option<Ident>,
+}
+
+impl QmlElementMetadata {
+    /// Whether the generated C++ uses macros from QtQml and therefore needs its headers
+    pub fn requires_qml_include(&self) -> bool {
+        self.singleton
+            || self.interface
+            || self.extended.is_some()
+            || self.foreign.is_some()
+            || self.attached.is_some()
+    }
 }
 /// A representation of a QObject within a CXX-Qt [syn::ItemMod]
 ///
@@ -38,14 +65,27 @@ pub struct ParsedQObject {
     pub properties: Vec<ParsedQProperty>,
     /// List of specifiers to register with in QML
     pub qml_metadata: Option<QmlElementMetadata>,
+    /// The row type and field if this QObject is a list model
+    pub qml_model: Option<ParsedQmlModel>,
+    /// The key and value pairs from any #[qclassinfo] attributes, written as Q_CLASSINFO
+    pub class_info: Vec<(String, String)>,
+    /// The interfaces from any #[qinterfaces] attributes, which are additional base classes
+    ///
+    /// These will be exposed as Q_INTERFACES on the C++ object
+    pub interfaces: Vec<Ident>,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
+    /// All the docs (each line) of the QObject, excluding those of its properties
+    pub docs: Vec<Attribute>,
+    /// Cfgs for the QObject, excluding those of its properties
+    pub cfgs: Vec<Attribute>,
     /// The original declaration entered by the user, i.e. a type alias with a list of attributes
     pub declaration: ForeignTypeIdentAlias,
 }
 
 impl ParsedQObject {
-    const ALLOWED_ATTRS: [&'static str; 10] = [
+    const ALLOWED_ATTRS: [&'static str; 19] = [
+        "cfg",
         "cxx_name",
         "rust_name",
         "namespace",
@@ -55,7 +95,15 @@ impl ParsedQObject {
         "qml_element",
         "qml_uncreatable",
         "qml_singleton",
+        "qml_anonymous",
+        "qml_interface",
+        "qml_extended",
+        "qml_foreign",
+        "qml_attached",
         "qproperty",
+        "qml_model",
+        "qclassinfo",
+        "qinterfaces",
     ];
     #[cfg(test)]
     pub fn mock() -> Self {
@@ -65,7 +113,12 @@ impl ParsedQObject {
             rust_type: format_ident!("MyObjectRust"),
             properties: vec![],
             qml_metadata: None,
+            qml_model: None,
+            class_info: vec![],
+            interfaces: vec![],
             has_qobject_macro: false,
+            docs: vec![],
+            cfgs: vec![],
             declaration: ForeignTypeIdentAlias {
                 attrs: vec![],
                 ident_left: format_ident!("MyObject"),
@@ -82,9 +135,6 @@ impl ParsedQObject {
         auto_case: CaseConversion,
     ) -> Result<Self> {
         let attributes = require_attributes(&declaration.attrs, &Self::ALLOWED_ATTRS)?;
-        // TODO: handle docs through to generation
-        //
-        // TODO: handle cfgs on qobject
         let has_qobject_macro = attributes.contains_key("qobject");
 
         let base_class = attributes
@@ -121,9 +171,30 @@ impl ParsedQObject {
         // Find any QML metadata
         let qml_metadata = Self::parse_qml_metadata(&name, &declaration.attrs)?;
 
-        // Parse any properties in the type
+        // Find if this QObject is a list model
+        let qml_model = attributes
+            .get("qml_model")
+            .map(|attr| ParsedQmlModel::parse(attr))
+            .transpose()?;
+
+        // Find any class info for the QObject
+        let class_info = Self::parse_class_info(&declaration.attrs)?;
+
+        // Find any interfaces which the QObject implements
+        let interfaces = Self::parse_interfaces(&declaration.attrs)?;
+        if let (false, Some(interface)) = (has_qobject_macro, interfaces.first()) {
+            return Err(Error::new_spanned(
+                interface,
+                "Interfaces require a #[qobject] attribute, as Q_INTERFACES is only valid with Q_OBJECT!",
+            ));
+        }
+
+        // Parse any properties in the type along with their docs and cfgs
         // and remove the #[qproperty] attribute
-        let properties = Self::parse_property_attributes(&declaration.attrs, auto_case)?;
+        let (properties, qobject_attrs) =
+            Self::parse_property_attributes(&declaration.attrs, auto_case)?;
+        let docs = extract_docs(&qobject_attrs);
+        let cfgs = extract_cfgs(&qobject_attrs);
         let inner = declaration.ident_right.clone();
 
         Ok(Self {
@@ -133,42 +204,163 @@ impl ParsedQObject {
             rust_type: inner,
             properties,
             qml_metadata,
+            qml_model,
+            class_info,
+            interfaces,
             has_qobject_macro,
+            docs,
+            cfgs,
         })
     }
 
     fn parse_qml_metadata(name: &Name, attrs: &[Attribute]) -> Result<Option<QmlElementMetadata>> {
         let attributes = require_attributes(attrs, &Self::ALLOWED_ATTRS)?;
-        if let Some(attr) = attributes.get("qml_element") {
-            // Extract the name of the qml_element from macro, else use the c++ name
-            // This will use the name provided by cxx_name if that attr was present
-            let name = match &attr.meta {
-                Meta::NameValue(name_value) => expr_to_string(&name_value.value)?,
-                _ => name.cxx_unqualified(),
-            };
-            let uncreatable = attributes.contains_key("qml_uncreatable");
-            let singleton = attributes.contains_key("qml_singleton");
-            return Ok(Some(QmlElementMetadata {
-                name,
-                uncreatable,
-                singleton,
-            }));
+        let element = attributes.get("qml_element");
+        let anonymous = attributes.get("qml_anonymous");
+        let interface = attributes.get("qml_interface");
+
+        // A type is either a named element, an anonymous type or an interface
+        let registrations = [element, anonymous, interface];
+        if let Some(attr) = registrations.iter().flatten().nth(1) {
+            return Err(Error::new_spanned(
+                attr,
+                "Only one of #[qml_element], #[qml_anonymous] or #[qml_interface] can be used!",
+            ));
+        }
+
+        let parse_type = |key: &str| -> Result<Option<Ident>> {
+            attributes
+                .get(key)
+                .map(|attr| {
+                    let expr = &attr.meta.require_name_value()?.value;
+                    if let Expr::Path(path_expr) = expr {
+                        Ok(path_expr.path.require_ident()?.clone())
+                    } else {
+                        Err(Error::new_spanned(
+                            expr,
+                            format!("#[{key}] must be an identifier of a type!"),
+                        ))
+                    }
+                })
+                .transpose()
+        };
+        let extended = parse_type("qml_extended")?;
+        let foreign = parse_type("qml_foreign")?;
+        let attached = parse_type("qml_attached")?;
+
+        if registrations.iter().all(Option::is_none) {
+            // The other QML attributes have no effect without a registration
+            if let Some(attr) = ["qml_extended", "qml_foreign", "qml_attached"]
+                .iter()
+                .find_map(|key| attributes.get(*key))
+            {
+                return Err(Error::new_spanned(
+                    attr,
+                    "A #[qml_element], #[qml_anonymous] or #[qml_interface] attribute is required to register the type with QML!",
+                ));
+            }
+            return Ok(None);
         }
-        Ok(None)
+
+        // Extract the name of the qml_element from macro, else use the c++ name
+        // This will use the name provided by cxx_name if that attr was present
+        let name = match element.map(|attr| &attr.meta) {
+            Some(Meta::NameValue(name_value)) => expr_to_string(&name_value.value)?,
+            _ => name.cxx_unqualified(),
+        };
+        Ok(Some(QmlElementMetadata {
+            name,
+            uncreatable: attributes.contains_key("qml_uncreatable"),
+            singleton: attributes.contains_key("qml_singleton"),
+            anonymous: anonymous.is_some(),
+            interface: interface.is_some(),
+            extended,
+            foreign,
+            attached,
+        }))
+    }
+
+    /// Parse the key and value pairs of any #[qclassinfo(key = "value", ...)] attributes
+    ///
+    /// The key can be an identifier or a string literal, for keys such as "D-Bus Interface".
+    fn parse_class_info(attrs: &[Attribute]) -> Result<Vec<(String, String)>> {
+        let mut class_info = vec![];
+        for attr in attrs
+            .iter()
+            .filter(|attr| path_compare_str(attr.meta.path(), &["qclassinfo"]))
+        {
+            let pairs = attr.parse_args_with(|input: ParseStream| {
+                Punctuated::<(String, String), Token![,]>::parse_terminated_with(input, |input| {
+                    let key = if input.peek(LitStr) {
+                        input.parse::<LitStr>()?.value()
+                    } else {
+                        input.parse::<Ident>()?.to_string()
+                    };
+                    input.parse::<Token![=]>()?;
+                    let value = input.parse::<LitStr>()?.value();
+                    Ok((key, value))
+                })
+            })?;
+            if pairs.is_empty() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "Expected at least one key = \"value\" pair in #[qclassinfo]!",
+                ));
+            }
+            class_info.extend(pairs);
+        }
+        Ok(class_info)
+    }
+
+    /// Parse the identifiers of any #[qinterfaces(Interface, ...)] attributes
+    fn parse_interfaces(attrs: &[Attribute]) -> Result<Vec<Ident>> {
+        let mut interfaces = vec![];
+        for attr in attrs
+            .iter()
+            .filter(|attr| path_compare_str(attr.meta.path(), &["qinterfaces"]))
+        {
+            let idents = attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+            if idents.is_empty() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "Expected at least one interface in #[qinterfaces]!",
+                ));
+            }
+            interfaces.extend(idents);
+        }
+        Ok(interfaces)
     }
 
+    /// Parse the #[qproperty] attributes, returning the properties and the docs and cfgs of the QObject
+    ///
+    /// Any doc comments or cfgs directly before a #[qproperty] belong to that property,
+    /// all others belong to the QObject itself.
     fn parse_property_attributes(
         attrs: &[Attribute],
         auto_case: CaseConversion,
-    ) -> Result<Vec<ParsedQProperty>> {
-        // Once extract_if is stable, this would allow comparing all the elements using
-        // path_compare_str and building ParsedQProperty from the extracted elements.
-        // https://doc.rust-lang.org/nightly/std/vec/struct.Vec.html#method.extract_if
-        attrs
-            .iter()
-            .filter(|attr| path_compare_str(attr.meta.path(), &["qproperty"]))
-            .map(|attr| ParsedQProperty::parse(attr, auto_case))
-            .collect::<Result<Vec<_>>>()
+    ) -> Result<(Vec<ParsedQProperty>, Vec<Attribute>)> {
+        let mut properties = vec![];
+        let mut qobject_attrs = vec![];
+        let mut pending_attrs = vec![];
+
+        for attr in attrs {
+            if path_compare_str(attr.meta.path(), &["doc"])
+                || path_compare_str(attr.meta.path(), &["cfg"])
+            {
+                pending_attrs.push(attr.clone());
+            } else if path_compare_str(attr.meta.path(), &["qproperty"]) {
+                let mut property = ParsedQProperty::parse(attr, auto_case)?;
+                property.docs = extract_docs(&pending_attrs);
+                property.cfgs = extract_cfgs(&pending_attrs);
+                pending_attrs.clear();
+                properties.push(property);
+            } else {
+                qobject_attrs.append(&mut pending_attrs);
+            }
+        }
+        qobject_attrs.append(&mut pending_attrs);
+
+        Ok((properties, qobject_attrs))
     }
 }
 
@@ -241,6 +433,64 @@ pub mod tests {
         assert_eq!(qobject.properties.len(), 2);
     }
 
+    #[test]
+    fn test_from_struct_docs() {
+        let qobject = parse_qobject! {
+            /// The object
+            #[qobject]
+            /// The first property
+            /// over two lines
+            #[qproperty(i32, documented_property)]
+            #[qproperty(i32, undocumented_property)]
+            /// More docs for the object
+            type MyObject = super::MyObjectRust;
+        };
+
+        assert_eq!(qobject.docs.len(), 2);
+        assert_eq!(qobject.properties.len(), 2);
+        assert_eq!(qobject.properties[0].docs.len(), 2);
+        assert!(qobject.properties[1].docs.is_empty());
+    }
+
+    #[test]
+    fn test_from_struct_cfgs() {
+        let qobject = parse_qobject! {
+            #[cfg(feature = "object")]
+            #[qobject]
+            #[cfg(feature = "property")]
+            #[qproperty(i32, cfg_property)]
+            #[qproperty(i32, property)]
+            type MyObject = super::MyObjectRust;
+        };
+
+        assert_eq!(qobject.cfgs.len(), 1);
+        assert_eq!(qobject.properties.len(), 2);
+        assert_eq!(qobject.properties[0].cfgs.len(), 1);
+        assert!(qobject.properties[1].cfgs.is_empty());
+    }
+
+    #[test]
+    fn test_from_struct_class_info() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qclassinfo(DefaultProperty = "children")]
+            #[qclassinfo("D-Bus Interface" = "org.example.MyObject", Author = "KDAB")]
+            type MyObject = super::MyObjectRust;
+        };
+
+        assert_eq!(
+            qobject.class_info,
+            vec![
+                ("DefaultProperty".to_owned(), "children".to_owned()),
+                (
+                    "D-Bus Interface".to_owned(),
+                    "org.example.MyObject".to_owned()
+                ),
+                ("Author".to_owned(), "KDAB".to_owned()),
+            ]
+        );
+    }
+
     #[test]
     fn test_from_struct_fields() {
         let qobject = create_parsed_qobject();
@@ -266,13 +516,26 @@ pub mod tests {
         assert_eq!(properties[1].ty, f64_type());
     }
 
+    #[test]
+    fn test_qml_model() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qml_model(Row, rows)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qml_model = qobject.qml_model.unwrap();
+        assert_eq!(qml_model.row, "Row");
+        assert_eq!(qml_model.field, "rows");
+
+        assert!(create_parsed_qobject().qml_model.is_none());
+    }
+
     fn assert_qml_name(obj: ParsedQObject, str_name: &str) {
         assert_eq!(
             obj.qml_metadata,
             Some(QmlElementMetadata {
                 name: str_name.to_string(),
-                uncreatable: false,
-                singleton: false,
+                ..Default::default()
             })
         );
     }
@@ -327,8 +590,8 @@ pub mod tests {
             qobject.qml_metadata,
             Some(QmlElementMetadata {
                 name: "MyObject".to_string(),
-                uncreatable: false,
                 singleton: true,
+                ..Default::default()
             })
         );
     }
@@ -346,11 +609,81 @@ pub mod tests {
             Some(QmlElementMetadata {
                 name: "MyObject".to_string(),
                 uncreatable: true,
-                singleton: false,
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_qml_metadata_anonymous() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qml_anonymous]
+            type MyObject = super::MyObjectRust;
+        };
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_string(),
+                anonymous: true,
+                ..Default::default()
             })
         );
     }
 
+    #[test]
+    fn test_qml_metadata_interface() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qml_interface]
+            type MyObject = super::MyObjectRust;
+        };
+        let qml_metadata = qobject.qml_metadata.unwrap();
+        assert!(qml_metadata.interface);
+        assert!(qml_metadata.requires_qml_include());
+    }
+
+    #[test]
+    fn test_qml_metadata_types() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qml_element]
+            #[qml_extended = MyExtension]
+            #[qml_foreign = QLine]
+            #[qml_attached = MyAttached]
+            type MyObject = super::MyObjectRust;
+        };
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_string(),
+                extended: Some(format_ident!("MyExtension")),
+                foreign: Some(format_ident!("QLine")),
+                attached: Some(format_ident!("MyAttached")),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_struct_interfaces() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qinterfaces(QQmlParserStatus)]
+            #[qinterfaces(FirstInterface, SecondInterface)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert_eq!(
+            qobject.interfaces,
+            vec![
+                format_ident!("QQmlParserStatus"),
+                format_ident!("FirstInterface"),
+                format_ident!("SecondInterface")
+            ]
+        );
+        assert!(create_parsed_qobject().interfaces.is_empty());
+    }
+
     #[test]
     fn test_parse_errors() {
         assert_parse_errors! {
@@ -362,6 +695,65 @@ pub mod tests {
                 type MyObject = super::T;
             }
             { type MyObject = super::T; }
+            {
+                #[qobject]
+                #[qclassinfo]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qclassinfo()]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qclassinfo(DefaultProperty = children)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qinterfaces()]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qinterfaces("QQmlParserStatus")]
+                type MyObject = super::T;
+            }
+            {
+                #[base = QAbstractItemModel]
+                #[qinterfaces(QQmlParserStatus)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_element]
+                #[qml_anonymous]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_anonymous]
+                #[qml_interface]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_foreign = QLine]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_element]
+                #[qml_attached = "MyAttached"]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_element]
+                #[qml_extended]
+                type MyObject = super::T;
+            }
         }
     }
 }
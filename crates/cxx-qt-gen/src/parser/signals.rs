@@ -4,7 +4,10 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use crate::parser::CaseConversion;
 use crate::{
-    parser::{check_safety, extract_cfgs, extract_docs, method::MethodFields, require_attributes},
+    parser::{
+        check_safety, extract_cfgs, extract_docs, method::MethodFields, require_attributes,
+        AccessSpecifier,
+    },
     syntax::{path::path_compare_str, safety::Safety},
 };
 use core::ops::Deref;
@@ -19,6 +22,13 @@ pub struct ParsedSignal {
     pub inherit: bool,
     /// Whether the signal is private
     pub private: bool,
+    /// The access of the signal from `#[qsignal(private)]`
+    ///
+    /// Private signals have a `QPrivateSignal` parameter in C++, so they can only be emitted by
+    /// the QObject itself
+    pub access: AccessSpecifier,
+    /// Whether a Stream of the emissions should be generated, from a #[stream] attribute
+    pub stream: bool,
     /// All the doc attributes (each line) of the signal
     pub docs: Vec<Attribute>,
     /// Cfgs for signal
@@ -26,7 +36,15 @@ pub struct ParsedSignal {
 }
 
 impl ParsedSignal {
-    const ALLOWED_ATTRS: [&'static str; 5] = ["cxx_name", "rust_name", "inherit", "doc", "qsignal"];
+    const ALLOWED_ATTRS: [&'static str; 7] = [
+        "cfg",
+        "cxx_name",
+        "rust_name",
+        "inherit",
+        "doc",
+        "qsignal",
+        "stream",
+    ];
 
     #[cfg(test)]
     /// Test fn for creating a mocked signal from a method body
@@ -50,6 +68,19 @@ impl ParsedSignal {
         }
 
         let inherit = attrs.contains_key("inherit");
+        let stream = attrs.contains_key("stream");
+
+        let access = attrs
+            .get("qsignal")
+            .map(|attr| AccessSpecifier::from_attr(attr))
+            .transpose()?
+            .unwrap_or_default();
+        if access == AccessSpecifier::Protected {
+            return Err(Error::new(
+                attrs["qsignal"].span(),
+                "Signals cannot be protected, use either `public` or `private`",
+            ));
+        }
 
         let private = if let Visibility::Restricted(vis_restricted) = &fields.method.vis {
             path_compare_str(&vis_restricted.path, &["self"])
@@ -61,6 +92,8 @@ impl ParsedSignal {
             method_fields: fields,
             inherit,
             private,
+            access,
+            stream,
             docs,
             cfgs,
         })
@@ -101,6 +134,11 @@ mod tests {
             { fn ready(x: f64); }
             // Self needs to be receiver like self: &T instead of &self
             { fn ready(&self); }
+            {
+                // Signals are either public or private
+                #[qsignal(protected)]
+                fn ready(self: Pin<&mut MyObject>);
+            }
         }
     }
 
@@ -162,6 +200,17 @@ mod tests {
         assert!(!signal.private);
     }
 
+    #[test]
+    fn test_parse_signal_stream() {
+        let method: ForeignItemFn = parse_quote! {
+            #[stream]
+            fn ready(self: Pin<&mut MyObject>, x: f64);
+        };
+        let signal = ParsedSignal::parse(method, Safety::Safe, CaseConversion::none()).unwrap();
+        assert!(signal.stream);
+        assert!(!signal.inherit);
+    }
+
     #[test]
     fn test_parse_signal_parameters() {
         let method: ForeignItemFn = parse_quote! {
@@ -198,6 +247,25 @@ mod tests {
         assert!(signal.safe);
         assert!(!signal.inherit);
         assert!(signal.private);
+        assert_eq!(signal.access, AccessSpecifier::Public);
+    }
+
+    #[test]
+    fn test_parse_signal_qsignal_private() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qsignal(private)]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let signal = ParsedSignal::parse(method, Safety::Safe, CaseConversion::none()).unwrap();
+        assert_eq!(signal.access, AccessSpecifier::Private);
+        assert!(!signal.private);
+
+        let method: ForeignItemFn = parse_quote! {
+            #[qsignal(public)]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let signal = ParsedSignal::parse(method, Safety::Safe, CaseConversion::none()).unwrap();
+        assert_eq!(signal.access, AccessSpecifier::Public);
     }
 
     #[test]
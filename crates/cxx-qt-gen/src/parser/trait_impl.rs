@@ -3,7 +3,10 @@ use indoc::formatdoc;
 // SPDX-FileContributor: Leon Matthes <leon.matthes@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
-use syn::{Error, Ident, ItemImpl, Path, Result, Token, Type, TypePath};
+use syn::{
+    AngleBracketedGenericArguments, Error, GenericArgument, Ident, ItemImpl, Path, PathArguments,
+    Result, Token, Type, TypePath,
+};
 
 use crate::{parser::constructor::Constructor, syntax::path::path_compare_str};
 
@@ -12,6 +15,8 @@ use crate::{parser::constructor::Constructor, syntax::path::path_compare_str};
 pub enum TraitKind {
     Threading,
     Constructor(Constructor),
+    /// The singleton is created by a factory, which is given the engine of the stated type
+    SingletonFactory(Ident),
 }
 
 impl TraitKind {
@@ -36,6 +41,33 @@ impl TraitKind {
         Ok(Self::Constructor(constructor))
     }
 
+    fn parse_singleton_factory(path: &Path, imp: &ItemImpl) -> Result<Self> {
+        if let Some(unsafety) = imp.unsafety.as_ref() {
+            return Err(Error::new_spanned(
+                unsafety,
+                "Unnecessary unsafe, cxx_qt::SingletonFactory is safe to implement!",
+            ));
+        }
+
+        // The engine type is the only generic argument, e.g. cxx_qt::SingletonFactory<QQmlEngine>
+        if let Some(PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+            args, ..
+        })) = path.segments.last().map(|segment| &segment.arguments)
+        {
+            if let (1, Some(GenericArgument::Type(Type::Path(TypePath { path, qself: None })))) =
+                (args.len(), args.first())
+            {
+                if let Some(engine) = path.get_ident() {
+                    return Ok(Self::SingletonFactory(engine.clone()));
+                }
+            }
+        }
+        Err(Error::new_spanned(
+            path,
+            "cxx_qt::SingletonFactory requires the engine type declared in the bridge as its generic argument!",
+        ))
+    }
+
     fn parse(imp: &ItemImpl) -> Result<Self> {
         let (not, path, _) = &imp
             .trait_
@@ -46,6 +78,8 @@ impl TraitKind {
             Self::parse_threading(not, path, imp)
         } else if path_compare_str(path, &["cxx_qt", "Constructor"]) {
             Self::parse_constructor(imp)
+        } else if path_compare_str(path, &["cxx_qt", "SingletonFactory"]) {
+            Self::parse_singleton_factory(path, imp)
         } else {
             // TODO: Give suggestions on which trait might have been meant
             Err(Error::new_spanned(
@@ -55,6 +89,7 @@ impl TraitKind {
                     CXX-Qt currently only supports:
                       - cxx_qt::Threading
                       - cxx_qt::Constructor
+                      - cxx_qt::SingletonFactory
                       - (cxx_qt::Locking has been removed as of CXX-Qt 0.7)
                     Note that the trait must always be fully-qualified.
                     "},
@@ -130,6 +165,19 @@ mod tests {
         assert!(matches!(marker.kind, TraitKind::Constructor(_)))
     }
 
+    #[test]
+    fn parse_singleton_factory() {
+        let imp = parse_quote! {
+            impl cxx_qt::SingletonFactory<QQmlEngine> for MyObject {}
+        };
+        let marker = TraitImpl::parse(imp).unwrap();
+        assert_eq!(marker.qobject, format_ident!("MyObject"));
+        assert_eq!(
+            marker.kind,
+            TraitKind::SingletonFactory(format_ident!("QQmlEngine"))
+        );
+    }
+
     use crate::tests::assert_parse_errors;
 
     #[test]
@@ -141,6 +189,12 @@ mod tests {
             { unsafe impl cxx_qt::Threading for QObject {} }
             // Threading cannot be negated
             { impl !cxx_qt::Threading for QObject {} }
+            // SingletonFactory is safe to implement
+            { unsafe impl cxx_qt::SingletonFactory<QQmlEngine> for QObject {} }
+            // SingletonFactory requires the engine type
+            { impl cxx_qt::SingletonFactory for QObject {} }
+            { impl cxx_qt::SingletonFactory<QQmlEngine, QJSEngine> for QObject {} }
+            { impl cxx_qt::SingletonFactory<*mut QQmlEngine> for QObject {} }
             // Invalid QObject name
             { impl cxx_qt::Locking for my::path {} }
             // Invalid trait name
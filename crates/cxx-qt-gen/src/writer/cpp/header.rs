@@ -81,7 +81,7 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
         let class_definition = namespaced(
             qobject.name.namespace().unwrap_or_default(),
             &formatdoc! { r#"
-                class {ident} : {base_classes}
+                {docs}class {ident} : {base_classes}
                 {{
                   {qobject_macro}
                 public:
@@ -94,6 +94,7 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
                 }};
 
                 {qobject_assert}"#,
+            docs = qobject.docs,
             // Note that there is always a base class as we always have CxxQtType
             base_classes = qobject.blocks.base_classes.iter().map(|base| format!("public {}", base)).collect::<Vec<String>>().join(", "),
             metaobjects = qobject.blocks.metaobjects.join("\n  "),
@@ -163,10 +164,11 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks, include_path: &str) -> S
         {forward_declare}
         #include "{include_path}.cxx.h"
 
-        {extern_cxx_qt}
+        {qgadgets}{extern_cxx_qt}
         {qobjects}
     "#,
     forward_declare = forward_declare(generated).join("\n"),
+    qgadgets = generated.qgadgets.join("\n"),
     qobjects = qobjects_header(generated).join("\n"),
     }
 }
@@ -181,7 +183,7 @@ mod tests {
         create_generated_cpp_no_namespace, expected_header, expected_header_multi_qobjects,
         expected_header_no_namespace,
     };
-    use crate::Parser;
+    use crate::{GeneratedOpt, Parser};
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use syn::{parse_quote, ItemMod};
@@ -250,7 +252,7 @@ mod tests {
 
         let parser = Parser::from(module.clone()).unwrap();
 
-        let generated = GeneratedCppBlocks::from(&parser).unwrap();
+        let generated = GeneratedCppBlocks::from(&parser, &GeneratedOpt::default()).unwrap();
         let header = write_cpp_header(&generated, "cxx-qt-gen/ffi");
         let expected = indoc! {r#"
 #pragma once
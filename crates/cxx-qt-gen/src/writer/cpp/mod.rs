@@ -95,6 +95,7 @@ mod tests {
         GeneratedCppBlocks {
             forward_declares: vec![],
             includes: BTreeSet::default(),
+            qgadgets: vec![],
             extern_cxx_qt: vec![],
             qobjects: vec![
                 GeneratedCppQObject {
@@ -110,6 +111,7 @@ mod tests {
                         "cxx_qt_my_object".to_owned()
                     },
                     has_qobject_macro: true,
+                    docs: "".to_owned(),
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["QStringListModel".to_owned()],
                         includes: {
@@ -225,6 +227,7 @@ mod tests {
         GeneratedCppBlocks {
             forward_declares: vec![],
             includes: BTreeSet::default(),
+            qgadgets: vec![],
             extern_cxx_qt: vec![],
             qobjects: vec![
                 GeneratedCppQObject {
@@ -232,6 +235,7 @@ mod tests {
                     rust_struct: Name::mock("FirstObjectRust"),
                     namespace_internals: "cxx_qt::cxx_qt_first_object".to_owned(),
                     has_qobject_macro: true,
+                    docs: "".to_owned(),
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["QStringListModel".to_owned()],
                         includes: {
@@ -275,6 +279,7 @@ mod tests {
                     rust_struct: Name::mock("SecondObjectRust"),
                     namespace_internals: "cxx_qt::cxx_qt_second_object".to_owned(),
                     has_qobject_macro: true,
+                    docs: "".to_owned(),
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["QStringListModel".to_owned()],
                         includes: {
@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{cfg::GeneratedOpt, cpp::fragment::CppFragment},
+    writer::{cpp::write_cpp, rust::write_rust},
+    BridgeDescription, CxxQtFile, CxxQtItem, GeneratedCppBlocks, GeneratedRustBlocks, Parser,
+};
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{Error, Result};
+
+/// The generated code of one Rust file, containing at most one CXX or CXX-Qt bridge
+pub struct GeneratedFile {
+    /// The C++ code of the CXX-Qt bridge, if the file contains one
+    pub cxx_qt: Option<CppFragment>,
+    /// The description of the API of the CXX-Qt bridge, if the file contains one
+    pub description: Option<BridgeDescription>,
+    /// The Rust code of the whole file, with any CXX-Qt bridge expanded into a CXX bridge
+    ///
+    /// This can be passed to cxx_gen to generate the C++ code of the CXX bridge.
+    pub rust: TokenStream,
+}
+
+/// Generate the C++ and Rust code of the bridge in the given file
+///
+/// The include_path is the path the generated header is included as, without the `.cxxqt.h`
+/// extension, eg `crate_name/src/lib`.
+pub fn generate_file(
+    file: &CxxQtFile,
+    include_path: &str,
+    opt: &GeneratedOpt,
+) -> Result<GeneratedFile> {
+    let mut cxx_qt = None;
+    let mut description = None;
+    let mut rust = TokenStream::new();
+
+    // Add any attributes in the file into the tokenstream
+    for attr in &file.attrs {
+        rust.extend(attr.into_token_stream());
+    }
+
+    // Loop through the items looking for any CXX or CXX-Qt blocks
+    //
+    // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
+    let mut found_bridge = false;
+    for item in &file.items {
+        match item {
+            CxxQtItem::Cxx(module) | CxxQtItem::CxxQt(module) if found_bridge => {
+                return Err(Error::new_spanned(
+                    module,
+                    "Only files with either a single cxx or a single cxx_qt module are currently supported",
+                ));
+            }
+            CxxQtItem::Cxx(module) => {
+                found_bridge = true;
+                rust.extend(module.into_token_stream());
+            }
+            CxxQtItem::CxxQt(module) => {
+                found_bridge = true;
+
+                let parser = Parser::from(module.clone())?;
+                let generated_cpp = GeneratedCppBlocks::from(&parser, opt)?;
+                let generated_rust = GeneratedRustBlocks::from(&parser)?;
+                description = Some(BridgeDescription::from(&parser)?);
+
+                cxx_qt = Some(write_cpp(&generated_cpp, include_path));
+                // The CXX bridge in the Rust code is needed to generate the C++ code of CXX,
+                // so we cannot rely on the macro expansion here
+                rust.extend(write_rust(&generated_rust, Some(include_path)));
+            }
+            CxxQtItem::Item(item) => {
+                rust.extend(item.into_token_stream());
+            }
+        }
+    }
+
+    Ok(GeneratedFile {
+        cxx_qt,
+        description,
+        rust,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_file_cxx_qt_bridge() {
+        let file: CxxQtFile = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+
+            #[derive(Default)]
+            pub struct MyObjectRust;
+        };
+
+        let generated = generate_file(&file, "crate/src/lib", &GeneratedOpt::default()).unwrap();
+
+        let Some(CppFragment::Pair { header, source }) = generated.cxx_qt else {
+            panic!("Expected a header and source pair");
+        };
+        assert!(header.contains("class MyObject"));
+        assert!(source.contains("#include \"crate/src/lib.cxxqt.h\""));
+
+        let description = generated.description.unwrap();
+        assert_eq!(description.qobjects.len(), 1);
+
+        let rust = generated.rust.to_string();
+        assert!(rust.contains("cxx :: bridge"));
+        assert!(rust.contains("crate/src/lib.cxxqt.h"));
+        assert!(rust.contains("pub struct MyObjectRust"));
+    }
+
+    #[test]
+    fn test_generate_file_cxx_bridge() {
+        let file: CxxQtFile = parse_quote! {
+            #[cxx::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn function();
+                }
+            }
+        };
+
+        let generated = generate_file(&file, "crate/src/lib", &GeneratedOpt::default()).unwrap();
+        assert!(generated.cxx_qt.is_none());
+        assert!(generated.description.is_none());
+        assert!(generated.rust.to_string().contains("cxx :: bridge"));
+    }
+
+    #[test]
+    fn test_generate_file_multiple_bridges() {
+        let file: CxxQtFile = parse_quote! {
+            #[cxx::bridge]
+            mod ffi {}
+
+            #[cxx_qt::bridge]
+            mod qobject {}
+        };
+
+        assert!(generate_file(&file, "crate/src/lib", &GeneratedOpt::default()).is_err());
+    }
+}
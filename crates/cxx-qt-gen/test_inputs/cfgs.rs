@@ -0,0 +1,54 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qproperty(i32, number)]
+        #[cfg(feature = "enabled")]
+        #[qproperty(i32, enabled_number)]
+        #[cfg(feature = "disabled")]
+        #[qproperty(i32, disabled_number)]
+        type MyObject = super::MyObjectRust;
+
+        #[cfg(feature = "enabled")]
+        #[qinvokable]
+        fn enabled_invokable(self: &MyObject);
+
+        #[cfg(feature = "disabled")]
+        #[qinvokable]
+        fn disabled_invokable(self: &MyObject);
+
+        #[cfg(not(feature = "disabled"))]
+        #[qsignal]
+        fn enabled_signal(self: Pin<&mut MyObject>);
+
+        #[cfg(feature = "disabled")]
+        #[qsignal]
+        fn disabled_signal(self: Pin<&mut MyObject>);
+    }
+
+    #[cfg(feature = "disabled")]
+    unsafe extern "RustQt" {
+        #[qobject]
+        type DisabledObject = super::DisabledObjectRust;
+
+        #[qinvokable]
+        fn invokable(self: &DisabledObject);
+    }
+
+    extern "RustQt" {
+        #[cfg(feature = "disabled")]
+        #[qobject]
+        type OtherDisabledObject = super::OtherDisabledObjectRust;
+    }
+
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++Qt" {
+        include!(<QtCore/QTimer>);
+
+        #[qobject]
+        type QTimer;
+
+        #[qsignal]
+        fn timeout(self: Pin<&mut QTimer>);
+    }
+}
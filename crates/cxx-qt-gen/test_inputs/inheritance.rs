@@ -7,11 +7,15 @@ mod inheritance {
         type QVariant = cxx_qt_lib::QVariant;
 
         type QAbstractItemModel;
+
+        include!(<QtQml/QQmlParserStatus>);
+        type QQmlParserStatus;
     }
 
     extern "RustQt" {
         #[qobject]
         #[base = QAbstractItemModel]
+        #[qinterfaces(QQmlParserStatus)]
         type MyObject = super::MyObjectRust;
     }
 
@@ -24,6 +28,11 @@ mod inheritance {
         #[rust_name = "hello_world"]
         #[inherit]
         fn helloWorld(self: &MyObject, parent: &QModelIndex) -> bool;
+
+        /// Inherited protected createIndex from the base class
+        #[cxx_name = "createIndex"]
+        #[inherit(protected)]
+        fn create_index(self: &MyObject, row: i32, column: i32) -> QModelIndex;
     }
 
     extern "RustQt" {
@@ -40,5 +49,14 @@ mod inheritance {
         #[qinvokable]
         #[cxx_override]
         fn has_children(self: &MyObject, _parent: &QModelIndex) -> bool;
+
+        /// Implements the pure virtual of the QQmlParserStatus interface
+        #[cxx_name = "classBegin"]
+        #[cxx_override]
+        fn class_begin(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "componentComplete"]
+        #[cxx_override]
+        fn component_complete(self: Pin<&mut MyObject>);
     }
 }
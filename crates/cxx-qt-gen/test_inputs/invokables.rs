@@ -21,6 +21,7 @@ mod ffi {
 
         fn cpp_method(self: &MyObject);
 
+        /// An invokable which can be called from QML
         #[qinvokable]
         fn invokable(self: &MyObject);
 
@@ -53,6 +54,9 @@ mod ffi {
 
         #[qinvokable]
         fn invokable_result_type(self: &MyObject) -> Result<String>;
+
+        #[qinvokable]
+        async fn invokable_fetch(self: &MyObject, value: i32) -> QPoint;
     }
 
     impl cxx_qt::Threading for MyObject {}
@@ -8,7 +8,10 @@ mod ffi {
 
     #[auto_cxx_name]
     extern "RustQt" {
+        /// An object with many kinds of properties
         #[qobject]
+        #[qclassinfo(DefaultProperty = "primitive")]
+        /// A primitive property
         #[qproperty(i32, primitive)]
         #[qproperty(QPoint, trivial)]
         #[qproperty(i32, prop_auto_cxx_name)]
@@ -24,8 +27,11 @@ mod ffi {
         #[qproperty(i32, custom_on_changed_prop, cxx_name = "customOnChangedProp", READ, WRITE, NOTIFY = my_on_changed)]
         #[qproperty(i32, const_prop, cxx_name = "constProp", READ, CONSTANT)]
         #[qproperty(i32, resettable_prop, cxx_name = "resettableProp", READ, WRITE, RESET = myResetFn)]
+        #[qproperty(i32, auto_reset_prop, cxx_name = "autoResetProp", READ, WRITE, RESET)]
+        #[qproperty(i32, valued_reset_prop, cxx_name = "valuedResetProp", READ, RESET(42))]
         #[qproperty(i32, required_prop, cxx_name = "requiredProp", READ, WRITE, REQUIRED)]
         #[qproperty(i32, final_prop, cxx_name = "finalProp", READ, WRITE, FINAL)]
+        #[qproperty(i32, bindable_prop, cxx_name = "bindableProp", READ, WRITE, NOTIFY, BINDABLE)]
         type MyObject = super::MyObjectRust;
     }
 
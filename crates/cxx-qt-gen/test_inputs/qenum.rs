@@ -14,6 +14,15 @@ mod ffi {
         Z,
     }
 
+    /// QEnums can be combined into QFlags
+    #[qenum(MyObject)]
+    #[qflags(MyFlags)]
+    enum MyFlag {
+        A = 1,
+        B = 2,
+        C = 4,
+    }
+
     #[qml_element]
     qnamespace!("cxx_qt::my_object");
 
@@ -24,6 +33,23 @@ mod ffi {
         C,
     }
 
+    /// QEnums can have explicit values and a repr
+    #[qenum]
+    #[repr(i16)]
+    enum MyValuedEnum {
+        A = -1,
+        B = 4,
+        C,
+    }
+
+    #[qenum]
+    #[qflags(MyNamespacedFlags)]
+    #[repr(u32)]
+    enum MyNamespacedFlag {
+        A = 1,
+        B = 2,
+    }
+
     qnamespace!("other_namespace");
 
     #[qenum]
@@ -38,7 +64,12 @@ mod ffi {
         type MyObject = super::MyObjectRust;
 
         #[qinvokable]
-        fn my_invokable(self: &MyObject, qenum: MyEnum, other_qenum: MyOtherEnum);
+        fn my_invokable(
+            self: &MyObject,
+            qenum: MyEnum,
+            other_qenum: MyOtherEnum,
+            flags: MyFlags,
+        );
     }
 
     // Test that we can correctly associate a QEnum to a renamed QObject
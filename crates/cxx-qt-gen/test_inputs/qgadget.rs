@@ -0,0 +1,25 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    /// A point in 2D space
+    #[qgadget]
+    #[qml_element]
+    #[derive(Clone, PartialEq)]
+    struct Point {
+        /// The x coordinate
+        x: i32,
+        /// The y coordinate
+        y: i32,
+    }
+
+    unsafe extern "RustQt" {
+        #[qinvokable]
+        #[cxx_name = "lengthSquared"]
+        fn length_squared(self: &Point) -> i32;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qproperty(Point, point)]
+        type MyObject = super::MyObjectRust;
+    }
+}
@@ -0,0 +1,24 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_model")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    /// A row of the model
+    struct TodoItem {
+        #[qrole]
+        title: QString,
+        #[qrole = "isDone"]
+        done: bool,
+        id: i32,
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qml_model(TodoItem, items)]
+        type TodoModel = super::TodoModelRust;
+    }
+}
@@ -23,7 +23,9 @@ mod ffi {
         #[qobject]
         type MyObject = super::MyObjectRust;
 
+        /// Emitted when the object is ready
         #[qsignal]
+        #[stream]
         fn ready(self: Pin<&mut MyObject>);
 
         #[qsignal]
@@ -35,6 +37,10 @@ mod ffi {
             fourth: &QPoint,
         );
 
+        /// Emitted when the value changes, only MyObject can emit this signal
+        #[qsignal(private)]
+        fn value_changed(self: Pin<&mut MyObject>, value: i32);
+
         #[cxx_name = "newData"]
         #[inherit]
         #[qsignal]
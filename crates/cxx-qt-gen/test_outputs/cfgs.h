@@ -0,0 +1,86 @@
+#pragma once
+
+#include <cxx-qt/signalhandler.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_object {
+class MyObject;
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlernumberChanged =
+  ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamsnumberChanged*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlerenabled_numberChanged =
+  ::rust::cxxqt1::SignalHandler<
+    struct MyObjectCxxQtSignalParamsenabled_numberChanged*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlerenabled_signal = ::rust::cxxqt1::SignalHandler<
+  struct MyObjectCxxQtSignalParamsenabled_signal*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_numberChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlernumberChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_enabled_numberChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerenabled_numberChanged closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_enabled_signalConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerenabled_signal
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object {
+class MyObject
+  : public QObject
+  , public ::rust::cxxqt1::CxxQtType<MyObjectRust>
+{
+  Q_OBJECT
+public:
+  Q_PROPERTY(
+    ::std::int32_t number READ getNumber WRITE setNumber NOTIFY numberChanged)
+  Q_PROPERTY(::std::int32_t enabled_number READ getEnabled_number WRITE
+               setEnabled_number NOTIFY enabled_numberChanged)
+
+  virtual ~MyObject() = default;
+
+public:
+  ::std::int32_t const& getNumber() const noexcept;
+  Q_SLOT void setNumber(::std::int32_t value) noexcept;
+  ::std::int32_t const& getEnabled_number() const noexcept;
+  Q_SLOT void setEnabled_number(::std::int32_t value) noexcept;
+  Q_SIGNAL void numberChanged();
+  Q_SIGNAL void enabled_numberChanged();
+  Q_INVOKABLE void enabled_invokable() const noexcept;
+  Q_SIGNAL void enabled_signal();
+  explicit MyObject(QObject* parent = nullptr);
+};
+
+static_assert(::std::is_base_of<QObject, MyObject>::value,
+              "MyObject must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
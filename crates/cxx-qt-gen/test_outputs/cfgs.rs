@@ -0,0 +1,1019 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+#[allow(unused_unsafe)]
+mod ffi {
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MyObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/qobject/generated-qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type MyObject;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type MyObjectRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcast_QObject(
+            qobject: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
+    extern "Rust" {
+        #[cxx_name = "getNumber"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn number<'a>(self: &'a MyObject) -> &'a i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "setNumber"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_number(self: Pin<&mut MyObject>, value: i32);
+    }
+    #[cfg(feature = "enabled")]
+    extern "Rust" {
+        #[cxx_name = "getEnabled_number"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn enabled_number<'a>(self: &'a MyObject) -> &'a i32;
+    }
+    #[cfg(feature = "enabled")]
+    extern "Rust" {
+        #[cxx_name = "setEnabled_number"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_enabled_number(self: Pin<&mut MyObject>, value: i32);
+    }
+    #[cfg(feature = "disabled")]
+    extern "Rust" {
+        #[cxx_name = "getDisabled_number"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn disabled_number<'a>(self: &'a MyObject) -> &'a i32;
+    }
+    #[cfg(feature = "disabled")]
+    extern "Rust" {
+        #[cxx_name = "setDisabled_number"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_disabled_number(self: Pin<&mut MyObject>, value: i32);
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "numberChanged"]
+        #[doc = "Notify for the Q_PROPERTY"]
+        #[namespace = "cxx_qt::my_object"]
+        fn number_changed(self: Pin<&mut MyObject>);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlernumberChanged = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::MyObjectCxxQtSignalClosurenumberChanged,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_numberChangedConnect"]
+        fn MyObject_connect_number_changed(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlernumberChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_numberChanged(
+            handler: MyObjectCxxQtSignalHandlernumberChanged,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_numberChanged(
+            handler: &mut MyObjectCxxQtSignalHandlernumberChanged,
+            self_value: Pin<&mut MyObject>,
+        );
+    }
+    #[cfg(feature = "enabled")]
+    unsafe extern "C++" {
+        #[cxx_name = "enabled_numberChanged"]
+        #[doc = "Notify for the Q_PROPERTY"]
+        #[namespace = "cxx_qt::my_object"]
+        fn enabled_number_changed(self: Pin<&mut MyObject>);
+    }
+    #[cfg(feature = "enabled")]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlerenabled_numberChanged =
+            cxx_qt::signalhandler::CxxQtSignalHandler<
+                super::MyObjectCxxQtSignalClosureenabled_numberChanged,
+            >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_enabled_numberChangedConnect"]
+        fn MyObject_connect_enabled_number_changed(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlerenabled_numberChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[cfg(feature = "enabled")]
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_enabled_numberChanged(
+            handler: MyObjectCxxQtSignalHandlerenabled_numberChanged,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_enabled_numberChanged(
+            handler: &mut MyObjectCxxQtSignalHandlerenabled_numberChanged,
+            self_value: Pin<&mut MyObject>,
+        );
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[cxx_name = "disabled_numberChanged"]
+        #[doc = "Notify for the Q_PROPERTY"]
+        #[namespace = "cxx_qt::my_object"]
+        fn disabled_number_changed(self: Pin<&mut MyObject>);
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlerdisabled_numberChanged =
+            cxx_qt::signalhandler::CxxQtSignalHandler<
+                super::MyObjectCxxQtSignalClosuredisabled_numberChanged,
+            >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_disabled_numberChangedConnect"]
+        fn MyObject_connect_disabled_number_changed(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlerdisabled_numberChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[cfg(feature = "disabled")]
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_disabled_numberChanged(
+            handler: MyObjectCxxQtSignalHandlerdisabled_numberChanged,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_disabled_numberChanged(
+            handler: &mut MyObjectCxxQtSignalHandlerdisabled_numberChanged,
+            self_value: Pin<&mut MyObject>,
+        );
+    }
+    extern "Rust" {
+        #[cxx_name = "enabled_invokable"]
+        #[namespace = "cxx_qt::my_object"]
+        #[cfg(feature = "enabled")]
+        #[doc(hidden)]
+        fn enabled_invokable(self: &MyObject);
+    }
+    extern "Rust" {
+        #[cxx_name = "disabled_invokable"]
+        #[namespace = "cxx_qt::my_object"]
+        #[cfg(feature = "disabled")]
+        #[doc(hidden)]
+        fn disabled_invokable(self: &MyObject);
+    }
+    #[cfg(not(feature = "disabled"))]
+    unsafe extern "C++" {
+        #[cxx_name = "enabled_signal"]
+        #[namespace = "cxx_qt::my_object"]
+        fn enabled_signal(self: Pin<&mut MyObject>);
+    }
+    #[cfg(not(feature = "disabled"))]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlerenabled_signal = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::MyObjectCxxQtSignalClosureenabled_signal,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_enabled_signalConnect"]
+        fn MyObject_connect_enabled_signal(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlerenabled_signal,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[cfg(not(feature = "disabled"))]
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_enabled_signal(
+            handler: MyObjectCxxQtSignalHandlerenabled_signal,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_enabled_signal(
+            handler: &mut MyObjectCxxQtSignalHandlerenabled_signal,
+            self_value: Pin<&mut MyObject>,
+        );
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[cxx_name = "disabled_signal"]
+        #[namespace = "cxx_qt::my_object"]
+        fn disabled_signal(self: Pin<&mut MyObject>);
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlerdisabled_signal = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::MyObjectCxxQtSignalClosuredisabled_signal,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_disabled_signalConnect"]
+        fn MyObject_connect_disabled_signal(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlerdisabled_signal,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[cfg(feature = "disabled")]
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_disabled_signal(
+            handler: MyObjectCxxQtSignalHandlerdisabled_signal,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_disabled_signal(
+            handler: &mut MyObjectCxxQtSignalHandlerdisabled_signal,
+            self_value: Pin<&mut MyObject>,
+        );
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_MyObject"]
+        fn create_rs_MyObjectRust() -> Box<MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRust(outer: &MyObject) -> &MyObjectRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRustMut(outer: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "DisabledObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/qobject/generated-qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type DisabledObject;
+    }
+    #[cfg(feature = "disabled")]
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type DisabledObjectRust;
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_DisabledObject_upcast_QObject(
+            qobject: *const DisabledObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_DisabledObject_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const DisabledObject;
+    }
+    #[cfg(feature = "disabled")]
+    extern "Rust" {
+        #[cxx_name = "invokable"]
+        #[namespace = "cxx_qt::my_object"]
+        #[cfg(feature = "disabled")]
+        #[doc(hidden)]
+        fn invokable(self: &DisabledObject);
+    }
+    #[cfg(feature = "disabled")]
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_DisabledObject"]
+        fn create_rs_DisabledObjectRust() -> Box<DisabledObjectRust>;
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_DisabledObject_unsafeRust(outer: &DisabledObject) -> &DisabledObjectRust;
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_DisabledObject_unsafeRustMut(
+            outer: Pin<&mut DisabledObject>,
+        ) -> Pin<&mut DisabledObjectRust>;
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "OtherDisabledObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/qobject/generated-qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type OtherDisabledObject;
+    }
+    #[cfg(feature = "disabled")]
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type OtherDisabledObjectRust;
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_OtherDisabledObject_upcast_QObject(
+            qobject: *const OtherDisabledObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_OtherDisabledObject_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const OtherDisabledObject;
+    }
+    #[cfg(feature = "disabled")]
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_OtherDisabledObject"]
+        fn create_rs_OtherDisabledObjectRust() -> Box<OtherDisabledObjectRust>;
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_OtherDisabledObject_unsafeRust(
+            outer: &OtherDisabledObject,
+        ) -> &OtherDisabledObjectRust;
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_OtherDisabledObject_unsafeRustMut(
+            outer: Pin<&mut OtherDisabledObject>,
+        ) -> Pin<&mut OtherDisabledObjectRust>;
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        include ! (< QtCore / QTimer >);
+        #[namespace = "cxx_qt::my_object"]
+        type QTimer;
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[cxx_name = "timeout"]
+        #[namespace = "cxx_qt::my_object"]
+        fn timeout(self: Pin<&mut QTimer>);
+    }
+    #[cfg(feature = "disabled")]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type QTimerCxxQtSignalHandlertimeout =
+            cxx_qt::signalhandler::CxxQtSignalHandler<super::QTimerCxxQtSignalClosuretimeout>;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "QTimer_timeoutConnect"]
+        fn QTimer_connect_timeout(
+            self_value: Pin<&mut QTimer>,
+            signal_handler: QTimerCxxQtSignalHandlertimeout,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[cfg(feature = "disabled")]
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_QTimer_signal_handler_timeout(handler: QTimerCxxQtSignalHandlertimeout);
+        #[doc(hidden)]
+        fn call_QTimer_signal_handler_timeout(
+            handler: &mut QTimerCxxQtSignalHandlertimeout,
+            self_value: Pin<&mut QTimer>,
+        );
+    }
+}
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObject_upcast_QObject(this)
+    }
+}
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcast_QObject(base)
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "number"]
+    pub fn number(&self) -> &i32 {
+        &self.number
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "number"]
+    pub fn set_number(mut self: core::pin::Pin<&mut Self>, value: i32) {
+        use cxx_qt::CxxQtType;
+        if self.number == value {
+            return;
+        }
+        self.as_mut().rust_mut().number = value;
+        self.as_mut().number_changed();
+    }
+}
+#[cfg(feature = "enabled")]
+impl ffi::MyObject {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "enabled_number"]
+    pub fn enabled_number(&self) -> &i32 {
+        &self.enabled_number
+    }
+}
+#[cfg(feature = "enabled")]
+impl ffi::MyObject {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "enabled_number"]
+    pub fn set_enabled_number(mut self: core::pin::Pin<&mut Self>, value: i32) {
+        use cxx_qt::CxxQtType;
+        if self.enabled_number == value {
+            return;
+        }
+        self.as_mut().rust_mut().enabled_number = value;
+        self.as_mut().enabled_number_changed();
+    }
+}
+#[cfg(feature = "disabled")]
+impl ffi::MyObject {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "disabled_number"]
+    pub fn disabled_number(&self) -> &i32 {
+        &self.disabled_number
+    }
+}
+#[cfg(feature = "disabled")]
+impl ffi::MyObject {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "disabled_number"]
+    pub fn set_disabled_number(mut self: core::pin::Pin<&mut Self>, value: i32) {
+        use cxx_qt::CxxQtType;
+        if self.disabled_number == value {
+            return;
+        }
+        self.as_mut().rust_mut().disabled_number = value;
+        self.as_mut().disabled_number_changed();
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "numberChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    pub fn connect_number_changed<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_number_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosurenumberChanged > :: new (Box :: new (closure)) , conn_type ,))
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "numberChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    pub fn on_number_changed<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_number_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosurenumberChanged > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosurenumberChanged {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosurenumberChanged {
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlernumberChanged"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send;
+}
+use core::mem::drop as drop_MyObject_signal_handler_numberChanged;
+fn call_MyObject_signal_handler_numberChanged(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosurenumberChanged,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+) {
+    handler.closure()(self_value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurenumberChanged>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurenumberChanged>,
+    [usize; 2]
+);
+#[cfg(feature = "enabled")]
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "enabled_numberChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    pub fn connect_enabled_number_changed<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_enabled_number_changed(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<
+                MyObjectCxxQtSignalClosureenabled_numberChanged,
+            >::new(Box::new(closure)),
+            conn_type,
+        ))
+    }
+}
+#[cfg(feature = "enabled")]
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "enabled_numberChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    pub fn on_enabled_number_changed<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_enabled_number_changed(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<
+                MyObjectCxxQtSignalClosureenabled_numberChanged,
+            >::new(Box::new(closure)),
+            cxx_qt::ConnectionType::AutoConnection,
+        ))
+    }
+}
+#[cfg(feature = "enabled")]
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosureenabled_numberChanged {}
+#[cfg(feature = "enabled")]
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for MyObjectCxxQtSignalClosureenabled_numberChanged
+{
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerenabled_numberChanged"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send;
+}
+#[cfg(feature = "enabled")]
+use core::mem::drop as drop_MyObject_signal_handler_enabled_numberChanged;
+#[cfg(feature = "enabled")]
+fn call_MyObject_signal_handler_enabled_numberChanged(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosureenabled_numberChanged,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+) {
+    handler.closure()(self_value);
+}
+#[cfg(feature = "enabled")]
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureenabled_numberChanged>,
+    usize
+);
+#[cfg(feature = "enabled")]
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureenabled_numberChanged>,
+    [usize; 2]
+);
+#[cfg(feature = "disabled")]
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "disabled_numberChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    pub fn connect_disabled_number_changed<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_disabled_number_changed(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<
+                MyObjectCxxQtSignalClosuredisabled_numberChanged,
+            >::new(Box::new(closure)),
+            conn_type,
+        ))
+    }
+}
+#[cfg(feature = "disabled")]
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "disabled_numberChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    pub fn on_disabled_number_changed<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_disabled_number_changed(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<
+                MyObjectCxxQtSignalClosuredisabled_numberChanged,
+            >::new(Box::new(closure)),
+            cxx_qt::ConnectionType::AutoConnection,
+        ))
+    }
+}
+#[cfg(feature = "disabled")]
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosuredisabled_numberChanged {}
+#[cfg(feature = "disabled")]
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for MyObjectCxxQtSignalClosuredisabled_numberChanged
+{
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdisabled_numberChanged"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send;
+}
+#[cfg(feature = "disabled")]
+use core::mem::drop as drop_MyObject_signal_handler_disabled_numberChanged;
+#[cfg(feature = "disabled")]
+fn call_MyObject_signal_handler_disabled_numberChanged(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosuredisabled_numberChanged,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+) {
+    handler.closure()(self_value);
+}
+#[cfg(feature = "disabled")]
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredisabled_numberChanged>,
+    usize
+);
+#[cfg(feature = "disabled")]
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredisabled_numberChanged>,
+    [usize; 2]
+);
+#[cfg(not(feature = "disabled"))]
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "enabled_signal"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    pub fn connect_enabled_signal<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_enabled_signal (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosureenabled_signal > :: new (Box :: new (closure)) , conn_type ,))
+    }
+}
+#[cfg(not(feature = "disabled"))]
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "enabled_signal"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    pub fn on_enabled_signal<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_enabled_signal (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosureenabled_signal > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
+    }
+}
+#[cfg(not(feature = "disabled"))]
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosureenabled_signal {}
+#[cfg(not(feature = "disabled"))]
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosureenabled_signal {
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerenabled_signal"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send;
+}
+#[cfg(not(feature = "disabled"))]
+use core::mem::drop as drop_MyObject_signal_handler_enabled_signal;
+#[cfg(not(feature = "disabled"))]
+fn call_MyObject_signal_handler_enabled_signal(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosureenabled_signal,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+) {
+    handler.closure()(self_value);
+}
+#[cfg(not(feature = "disabled"))]
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureenabled_signal>,
+    usize
+);
+#[cfg(not(feature = "disabled"))]
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureenabled_signal>,
+    [usize; 2]
+);
+#[cfg(feature = "disabled")]
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "disabled_signal"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    pub fn connect_disabled_signal<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::MyObject_connect_disabled_signal(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosuredisabled_signal,
+                >::new(Box::new(closure)),
+                conn_type,
+            ),
+        )
+    }
+}
+#[cfg(feature = "disabled")]
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "disabled_signal"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    pub fn on_disabled_signal<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::MyObject_connect_disabled_signal(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosuredisabled_signal,
+                >::new(Box::new(closure)),
+                cxx_qt::ConnectionType::AutoConnection,
+            ),
+        )
+    }
+}
+#[cfg(feature = "disabled")]
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosuredisabled_signal {}
+#[cfg(feature = "disabled")]
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for MyObjectCxxQtSignalClosuredisabled_signal
+{
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdisabled_signal"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send;
+}
+#[cfg(feature = "disabled")]
+use core::mem::drop as drop_MyObject_signal_handler_disabled_signal;
+#[cfg(feature = "disabled")]
+fn call_MyObject_signal_handler_disabled_signal(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosuredisabled_signal,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+) {
+    handler.closure()(self_value);
+}
+#[cfg(feature = "disabled")]
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredisabled_signal>,
+    usize
+);
+#[cfg(feature = "disabled")]
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredisabled_signal>,
+    [usize; 2]
+);
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_MyObjectRust() -> std::boxed::Box<MyObjectRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::MyObject {
+    type Target = MyObjectRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::MyObject {
+    type Rust = MyObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_MyObject_unsafeRustMut(self)
+    }
+}
+#[cfg(feature = "disabled")]
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::DisabledObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_DisabledObject_upcast_QObject(this)
+    }
+}
+#[cfg(feature = "disabled")]
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::DisabledObject {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_DisabledObject_downcast_QObject(base)
+    }
+}
+#[cfg(feature = "disabled")]
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_DisabledObjectRust() -> std::boxed::Box<DisabledObjectRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+#[cfg(feature = "disabled")]
+impl ::core::ops::Deref for ffi::DisabledObject {
+    type Target = DisabledObjectRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_DisabledObject_unsafeRust(self)
+    }
+}
+#[cfg(feature = "disabled")]
+impl ::cxx_qt::CxxQtType for ffi::DisabledObject {
+    type Rust = DisabledObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_DisabledObject_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_DisabledObject_unsafeRustMut(self)
+    }
+}
+#[cfg(feature = "disabled")]
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::OtherDisabledObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_OtherDisabledObject_upcast_QObject(this)
+    }
+}
+#[cfg(feature = "disabled")]
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::OtherDisabledObject {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_OtherDisabledObject_downcast_QObject(base)
+    }
+}
+#[cfg(feature = "disabled")]
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_OtherDisabledObjectRust() -> std::boxed::Box<OtherDisabledObjectRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+#[cfg(feature = "disabled")]
+impl ::core::ops::Deref for ffi::OtherDisabledObject {
+    type Target = OtherDisabledObjectRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_OtherDisabledObject_unsafeRust(self)
+    }
+}
+#[cfg(feature = "disabled")]
+impl ::cxx_qt::CxxQtType for ffi::OtherDisabledObject {
+    type Rust = OtherDisabledObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_OtherDisabledObject_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_OtherDisabledObject_unsafeRustMut(self)
+    }
+}
+#[cfg(feature = "disabled")]
+impl ffi::QTimer {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "timeout"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    pub fn connect_timeout<F: FnMut(core::pin::Pin<&mut ffi::QTimer>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::QTimer>,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::QTimer_connect_timeout(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<QTimerCxxQtSignalClosuretimeout>::new(
+                Box::new(closure),
+            ),
+            conn_type,
+        ))
+    }
+}
+#[cfg(feature = "disabled")]
+impl ffi::QTimer {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "timeout"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    pub fn on_timeout<F: FnMut(core::pin::Pin<&mut ffi::QTimer>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::QTimer>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::QTimer_connect_timeout(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<QTimerCxxQtSignalClosuretimeout>::new(
+                Box::new(closure),
+            ),
+            cxx_qt::ConnectionType::AutoConnection,
+        ))
+    }
+}
+#[cfg(feature = "disabled")]
+#[doc(hidden)]
+pub struct QTimerCxxQtSignalClosuretimeout {}
+#[cfg(feature = "disabled")]
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for QTimerCxxQtSignalClosuretimeout {
+    type Id =
+        cxx::type_id!("::cxx_qt::my_object::rust::cxxqtgen1::QTimerCxxQtSignalHandlertimeout");
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::QTimer>) + Send;
+}
+#[cfg(feature = "disabled")]
+use core::mem::drop as drop_QTimer_signal_handler_timeout;
+#[cfg(feature = "disabled")]
+fn call_QTimer_signal_handler_timeout(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<QTimerCxxQtSignalClosuretimeout>,
+    self_value: core::pin::Pin<&mut ffi::QTimer>,
+) {
+    handler.closure()(self_value);
+}
+#[cfg(feature = "disabled")]
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<QTimerCxxQtSignalClosuretimeout>,
+    usize
+);
+#[cfg(feature = "disabled")]
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<QTimerCxxQtSignalClosuretimeout>,
+    [usize; 2]
+);
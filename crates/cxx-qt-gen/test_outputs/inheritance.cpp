@@ -1,5 +1,12 @@
 #include "directory/file_ident.cxxqt.h"
 
+QModelIndex
+MyObject_createIndexCxxQtInherit(const MyObject& self,
+                                 ::std::int32_t row,
+                                 ::std::int32_t column)
+{
+  return self.QAbstractItemModel::createIndex(row, column);
+}
 MyObject::MyObject(QObject* parent)
   : QAbstractItemModel(parent)
   , ::rust::cxxqt1::CxxQtType<MyObjectRust>(::cxx_qt_MyObject::createRs())
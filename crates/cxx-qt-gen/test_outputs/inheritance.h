@@ -6,12 +6,19 @@ class MyObject;
 
 #include "directory/file_ident.cxx.h"
 
+QModelIndex
+MyObject_createIndexCxxQtInherit(const MyObject& self,
+                                 ::std::int32_t row,
+                                 ::std::int32_t column);
 class MyObject
   : public QAbstractItemModel
+  , public QQmlParserStatus
   , public ::rust::cxxqt1::CxxQtType<MyObjectRust>
 {
   Q_OBJECT
 public:
+  Q_INTERFACES(QQmlParserStatus)
+
   virtual ~MyObject() = default;
 
 public:
@@ -19,6 +26,9 @@ public:
                             ::std::int32_t _role) const noexcept override;
   Q_INVOKABLE bool has_children(
     QModelIndex const& _parent) const noexcept override;
+  /// Implements the pure virtual of the QQmlParserStatus interface
+  void classBegin() noexcept override;
+  void componentComplete() noexcept override;
   template<class... Args>
   bool hasChildrenCxxQtInherit(Args... args) const
   {
@@ -35,6 +45,11 @@ public:
     return QAbstractItemModel::fetch_more(args...);
   }
   explicit MyObject(QObject* parent = nullptr);
+
+private:
+  friend QModelIndex MyObject_createIndexCxxQtInherit(const MyObject& self,
+                                                      ::std::int32_t row,
+                                                      ::std::int32_t column);
 };
 
 static_assert(::std::is_base_of<QObject, MyObject>::value,
@@ -7,6 +7,8 @@ mod inheritance {
         include!("cxx-qt-lib/qvariant.h");
         type QVariant = cxx_qt_lib::QVariant;
         type QAbstractItemModel;
+        include ! (< QtQml / QQmlParserStatus >);
+        type QQmlParserStatus;
     }
     unsafe extern "C++" {
         include ! (< QtCore / QObject >);
@@ -25,6 +27,14 @@ mod inheritance {
     unsafe extern "C++" {
         include!("directory/file_ident.cxxqt.h");
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "MyObjectRust"]
@@ -37,6 +47,32 @@ mod inheritance {
     extern "Rust" {
         type MyObjectRust;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcast_QObject(
+            qobject: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcast_QAbstractItemModel(
+            qobject: *const MyObject,
+        ) -> *const QAbstractItemModel;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcast_QAbstractItemModel(
+            base: *const QAbstractItemModel,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         #[cxx_name = "data"]
         #[doc(hidden)]
@@ -47,6 +83,16 @@ mod inheritance {
         #[doc(hidden)]
         fn has_children(self: &MyObject, _parent: &QModelIndex) -> bool;
     }
+    extern "Rust" {
+        #[cxx_name = "classBegin"]
+        #[doc(hidden)]
+        fn class_begin(self: Pin<&mut MyObject>);
+    }
+    extern "Rust" {
+        #[cxx_name = "componentComplete"]
+        #[doc(hidden)]
+        fn component_complete(self: Pin<&mut MyObject>);
+    }
     unsafe extern "C++" {
         #[cxx_name = "hasChildrenCxxQtInherit"]
         #[doc = " Inherited hasChildren from the base class"]
@@ -56,6 +102,15 @@ mod inheritance {
         #[cxx_name = "helloWorldCxxQtInherit"]
         fn hello_world(self: &MyObject, parent: &QModelIndex) -> bool;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "MyObject_createIndexCxxQtInherit"]
+        fn MyObject_inherit_create_index(
+            self_value: &MyObject,
+            row: i32,
+            column: i32,
+        ) -> QModelIndex;
+    }
     extern "C++" {
         #[cxx_name = "fetch_moreCxxQtInherit"]
         #[doc = " Inherited fetchMore from the base class"]
@@ -79,10 +134,36 @@ mod inheritance {
         fn cxx_qt_ffi_MyObject_unsafeRustMut(outer: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
     }
 }
-impl cxx_qt::Upcast<inheritance::QAbstractItemModel> for inheritance::MyObject {}
-#[allow(unused_imports)]
-#[allow(dead_code)]
-use inheritance::QAbstractItemModel as _;
+impl cxx_qt::Upcast<cxx_qt::QObject> for inheritance::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        inheritance::cxx_qt_ffi_MyObject_upcast_QObject(this)
+    }
+}
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for inheritance::MyObject {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        inheritance::cxx_qt_ffi_MyObject_downcast_QObject(base)
+    }
+}
+impl cxx_qt::Upcast<inheritance::QAbstractItemModel> for inheritance::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const inheritance::QAbstractItemModel {
+        inheritance::cxx_qt_ffi_MyObject_upcast_QAbstractItemModel(this)
+    }
+}
+impl cxx_qt::DowncastPtr<inheritance::QAbstractItemModel> for inheritance::MyObject {
+    unsafe fn downcast_ptr(base: *const inheritance::QAbstractItemModel) -> *const Self {
+        inheritance::cxx_qt_ffi_MyObject_downcast_QAbstractItemModel(base)
+    }
+}
+impl inheritance::MyObject {
+    #[doc = " Inherited protected createIndex from the base class"]
+    fn create_index(
+        self: &inheritance::MyObject,
+        row: i32,
+        column: i32,
+    ) -> inheritance::QModelIndex {
+        inheritance::MyObject_inherit_create_index(self, row, column)
+    }
+}
 #[doc(hidden)]
 #[allow(clippy::unnecessary_box_returns)]
 pub fn create_rs_MyObjectRust() -> std::boxed::Box<MyObjectRust> {
@@ -1,6 +1,77 @@
 #include "directory/file_ident.cxxqt.h"
 
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamsinvokable_fetchFinished*>::
+  ~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_MyObject_signal_handler_invokable_fetchFinished(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamsinvokable_fetchFinished*>::
+operator()<cxx_qt::my_object::MyObject&, QPoint>(
+  cxx_qt::my_object::MyObject& self,
+  QPoint result)
+{
+  call_MyObject_signal_handler_invokable_fetchFinished(*this,
+                                                       self,
+                                                       ::std::move(result));
+}
+
+static_assert(
+  alignof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                          MyObjectCxxQtSignalParamsinvokable_fetchFinished*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                         MyObjectCxxQtSignalParamsinvokable_fetchFinished*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_invokable_fetchFinishedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerinvokable_fetchFinished closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MyObject::invokable_fetchFinished,
+    &self,
+    [&, closure = ::std::move(closure)](QPoint result) mutable {
+      closure.template operator()<cxx_qt::my_object::MyObject&, QPoint>(
+        self, ::std::move(result));
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object {
+QFuture<QPoint>
+MyObject::invokable_fetch(::std::int32_t value) const
+{
+  auto promise = ::std::make_unique<MyObjectCxxQtPromiseinvokable_fetch>();
+  auto future = promise->future();
+  const_cast<MyObject*>(this)->invokable_fetchCxxQtSpawn(value,
+                                                         ::std::move(promise));
+  return future;
+}
+
 static_assert(alignof(MyObjectCxxQtThread) <= alignof(::std::size_t),
               "unexpected aligment");
 static_assert(sizeof(MyObjectCxxQtThread) == sizeof(::std::size_t[2]),
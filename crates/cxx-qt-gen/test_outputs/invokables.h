@@ -1,16 +1,35 @@
 #pragma once
 
+#include <cxx-qt/promise.h>
+#include <cxx-qt/signalhandler.h>
 #include <cxx-qt/threading.h>
 #include <cxx-qt/type.h>
 
 namespace cxx_qt::my_object {
 class MyObject;
+using MyObjectCxxQtPromiseinvokable_fetch =
+  ::rust::cxxqt1::CxxQtPromise<QPoint>;
 using MyObjectCxxQtThread = ::rust::cxxqt1::CxxQtThread<MyObject>;
 
 } // namespace cxx_qt::my_object
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlerinvokable_fetchFinished =
+  ::rust::cxxqt1::SignalHandler<
+    struct MyObjectCxxQtSignalParamsinvokable_fetchFinished*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 #include "directory/file_ident.cxx.h"
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_invokable_fetchFinishedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerinvokable_fetchFinished closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object {
 class MyObject
   : public QObject
@@ -23,6 +42,7 @@ public:
 
 public:
   void cpp_method() const noexcept;
+  /// An invokable which can be called from QML
   Q_INVOKABLE void invokable() const noexcept;
   Q_INVOKABLE void invokable_mutable() noexcept;
   Q_INVOKABLE void invokable_parameters(
@@ -36,10 +56,16 @@ public:
   Q_INVOKABLE virtual void invokable_virtual() const noexcept;
   Q_INVOKABLE void invokable_result_tuple() const;
   Q_INVOKABLE ::rust::String invokable_result_type() const;
+  Q_INVOKABLE QFuture<QPoint> invokable_fetch(::std::int32_t value) const;
+  /// Emitted with the result of invokable_fetch once it has finished
+  Q_SIGNAL void invokable_fetchFinished(QPoint result);
   explicit MyObject(::std::int32_t arg0, QString const& arg1);
   explicit MyObject();
 
 private:
+  void invokable_fetchCxxQtSpawn(
+    ::std::int32_t value,
+    ::std::unique_ptr<MyObjectCxxQtPromiseinvokable_fetch> promise) noexcept;
   explicit MyObject(
     ::cxx_qt::my_object::cxx_qt_MyObject::CxxQtConstructorArguments0&& args);
   explicit MyObject(
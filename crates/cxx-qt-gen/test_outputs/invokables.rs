@@ -30,6 +30,14 @@ mod ffi {
     unsafe extern "C++" {
         include!("directory/file_ident.cxxqt.h");
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "MyObjectRust"]
@@ -44,6 +52,20 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type MyObjectRust;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcast_QObject(
+            qobject: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         #[cxx_name = "cpp_method"]
         #[namespace = "cxx_qt::my_object"]
@@ -110,6 +132,59 @@ mod ffi {
         #[doc(hidden)]
         fn invokable_result_type(self: &MyObject) -> Result<String>;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object"]
+        type MyObjectCxxQtPromiseinvokable_fetch;
+        include!("cxx-qt/promise.h");
+        #[doc(hidden)]
+        fn resolve(self: Pin<&mut MyObjectCxxQtPromiseinvokable_fetch>, result: &QPoint);
+    }
+    extern "Rust" {
+        #[cxx_name = "invokable_fetchCxxQtSpawn"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_spawn_invokable_fetch(
+            self: Pin<&mut MyObject>,
+            value: i32,
+            promise: UniquePtr<MyObjectCxxQtPromiseinvokable_fetch>,
+        );
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "invokable_fetchFinished"]
+        #[doc = " Emitted with the result of invokable_fetch once it has finished"]
+        #[namespace = "cxx_qt::my_object"]
+        fn invokable_fetch_finished(self: Pin<&mut MyObject>, result: QPoint);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlerinvokable_fetchFinished =
+            cxx_qt::signalhandler::CxxQtSignalHandler<
+                super::MyObjectCxxQtSignalClosureinvokable_fetchFinished,
+            >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_invokable_fetchFinishedConnect"]
+        fn MyObject_connect_invokable_fetch_finished(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlerinvokable_fetchFinished,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_invokable_fetchFinished(
+            handler: MyObjectCxxQtSignalHandlerinvokable_fetchFinished,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_invokable_fetchFinished(
+            handler: &mut MyObjectCxxQtSignalHandlerinvokable_fetchFinished,
+            self_value: Pin<&mut MyObject>,
+            result: QPoint,
+        );
+    }
     unsafe extern "C++" {
         #[doc(hidden)]
         #[namespace = "cxx_qt::my_object"]
@@ -141,6 +216,12 @@ mod ffi {
         #[cxx_name = "cxxQtThreadIsDestroyed"]
         #[namespace = "rust::cxxqt1"]
         fn cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadIsObjectThread"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadIsObjectThread(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+        ) -> bool;
     }
     extern "Rust" {
         #[namespace = "cxx_qt::my_object::cxx_qt_MyObject"]
@@ -248,6 +329,103 @@ mod ffi {
         fn cxx_qt_ffi_MyObject_unsafeRustMut(outer: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
     }
 }
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObject_upcast_QObject(this)
+    }
+}
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcast_QObject(base)
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    pub fn cxx_qt_ffi_spawn_invokable_fetch(
+        self: core::pin::Pin<&mut Self>,
+        value: i32,
+        mut promise: cxx::UniquePtr<ffi::MyObjectCxxQtPromiseinvokable_fetch>,
+    ) {
+        cxx_qt::LocalQObject::spawn(self, move |qobject| {
+            let handle = qobject.clone();
+            let future = Self::invokable_fetch(qobject, value);
+            async move {
+                let result = future.await;
+                promise.pin_mut().resolve(&result);
+                handle.with(|qobject| qobject.invokable_fetch_finished(result));
+            }
+        });
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "invokable_fetchFinished"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    pub fn connect_invokable_fetch_finished<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>, ffi::QPoint) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_invokable_fetch_finished(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<
+                MyObjectCxxQtSignalClosureinvokable_fetchFinished,
+            >::new(Box::new(closure)),
+            conn_type,
+        ))
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "invokable_fetchFinished"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    pub fn on_invokable_fetch_finished<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>, ffi::QPoint) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_invokable_fetch_finished(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<
+                MyObjectCxxQtSignalClosureinvokable_fetchFinished,
+            >::new(Box::new(closure)),
+            cxx_qt::ConnectionType::AutoConnection,
+        ))
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosureinvokable_fetchFinished {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for MyObjectCxxQtSignalClosureinvokable_fetchFinished
+{
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerinvokable_fetchFinished"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>, ffi::QPoint) + Send;
+}
+use core::mem::drop as drop_MyObject_signal_handler_invokable_fetchFinished;
+fn call_MyObject_signal_handler_invokable_fetchFinished(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosureinvokable_fetchFinished,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+    result: ffi::QPoint,
+) {
+    handler.closure()(self_value, result);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureinvokable_fetchFinished>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureinvokable_fetchFinished>,
+    [usize; 2]
+);
 impl cxx_qt::Threading for ffi::MyObject {
     type BoxedQueuedFn = MyObjectCxxQtThreadQueuedFn;
     type ThreadingTypeId = cxx::type_id!("cxx_qt::my_object::MyObjectCxxQtThread");
@@ -259,6 +437,10 @@ impl cxx_qt::Threading for ffi::MyObject {
         ffi::cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread)
     }
     #[doc(hidden)]
+    fn is_object_thread(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_MyObject_cxxQtThreadIsObjectThread(cxx_qt_thread)
+    }
+    #[doc(hidden)]
     fn queue<F>(
         cxx_qt_thread: &ffi::MyObjectCxxQtThread,
         f: F,
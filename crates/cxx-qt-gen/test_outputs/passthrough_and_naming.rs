@@ -72,6 +72,14 @@ pub mod ffi {
     unsafe extern "C++" {
         include!("directory/file_ident.cxxqt.h");
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "MyObjectRust"]
@@ -86,6 +94,32 @@ pub mod ffi {
         #[namespace = "cxx_qt::multi_object"]
         type MyObjectRust;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcast_QObject(
+            qobject: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcast_QStringListModel(
+            qobject: *const MyObject,
+        ) -> *const QStringListModel;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcast_QStringListModel(
+            base: *const QStringListModel,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         #[cxx_name = "getPropertyName"]
         #[namespace = "cxx_qt::multi_object"]
@@ -196,6 +230,20 @@ pub mod ffi {
         #[namespace = "second_object"]
         type SecondObjectRust;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_SecondObject_upcast_QObject(
+            qobject: *const SecondObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_SecondObject_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const SecondObject;
+    }
     extern "Rust" {
         #[cxx_name = "getPropertyName"]
         #[namespace = "second_object"]
@@ -317,6 +365,20 @@ pub mod ffi {
         #[namespace = "my_namespace"]
         type ThirdObjectRust;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyCxxName_upcast_QObject(
+            qobject: *const MyRustName,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyCxxName_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const MyRustName;
+    }
     extern "Rust" {
         #[cxx_name = "createRs"]
         #[namespace = "my_namespace::cxx_qt_MyRustName"]
@@ -440,10 +502,26 @@ pub mod ffi {
         );
     }
 }
-impl cxx_qt::Upcast<ffi::QStringListModel> for ffi::MyObject {}
-#[allow(unused_imports)]
-#[allow(dead_code)]
-use ffi::QStringListModel as _;
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObject_upcast_QObject(this)
+    }
+}
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcast_QObject(base)
+    }
+}
+impl cxx_qt::Upcast<ffi::QStringListModel> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ffi::QStringListModel {
+        ffi::cxx_qt_ffi_MyObject_upcast_QStringListModel(this)
+    }
+}
+impl cxx_qt::DowncastPtr<ffi::QStringListModel> for ffi::MyObject {
+    unsafe fn downcast_ptr(base: *const ffi::QStringListModel) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcast_QStringListModel(base)
+    }
+}
 impl ffi::MyObject {
     #[doc = "Getter for the Q_PROPERTY "]
     #[doc = "property_name"]
@@ -610,6 +688,16 @@ impl ::cxx_qt::CxxQtType for ffi::MyObject {
         ffi::cxx_qt_ffi_MyObject_unsafeRustMut(self)
     }
 }
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::SecondObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_SecondObject_upcast_QObject(this)
+    }
+}
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::SecondObject {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_SecondObject_downcast_QObject(base)
+    }
+}
 impl ffi::SecondObject {
     #[doc = "Getter for the Q_PROPERTY "]
     #[doc = "property_name"]
@@ -776,6 +864,16 @@ impl ::cxx_qt::CxxQtType for ffi::SecondObject {
         ffi::cxx_qt_ffi_SecondObject_unsafeRustMut(self)
     }
 }
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyRustName {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyCxxName_upcast_QObject(this)
+    }
+}
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::MyRustName {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyCxxName_downcast_QObject(base)
+    }
+}
 #[doc(hidden)]
 #[allow(clippy::unnecessary_box_returns)]
 pub fn create_rs_ThirdObjectRust() -> std::boxed::Box<ThirdObjectRust> {
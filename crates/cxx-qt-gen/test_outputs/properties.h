@@ -1,5 +1,11 @@
 #pragma once
 
+#include <QtCore/QtGlobal>
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+#include <QtCore/QProperty>
+#else
+#error "BINDABLE properties require Qt 6"
+#endif
 #include <cxx-qt/signalhandler.h>
 #include <cxx-qt/type.h>
 
@@ -43,6 +49,12 @@ using MyObjectCxxQtSignalHandlernamed_prop_2Changed =
     struct MyObjectCxxQtSignalParamsnamed_prop_2Changed*>;
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlerbindablePropChanged =
+  ::rust::cxxqt1::SignalHandler<
+    struct MyObjectCxxQtSignalParamsbindablePropChanged*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 using MyObjectCxxQtSignalHandlermy_on_changed =
   ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamsmy_on_changed*>;
@@ -104,6 +116,15 @@ MyObject_named_prop_2ChangedConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_bindablePropChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerbindablePropChanged closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 MyObject_my_on_changedConnect(
@@ -114,12 +135,15 @@ MyObject_my_on_changedConnect(
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
 namespace cxx_qt::my_object {
+/// An object with many kinds of properties
 class MyObject
   : public QObject
   , public ::rust::cxxqt1::CxxQtType<MyObjectRust>
 {
   Q_OBJECT
 public:
+  Q_CLASSINFO("DefaultProperty", "primitive")
+  /// A primitive property
   Q_PROPERTY(::std::int32_t primitive READ getPrimitive WRITE setPrimitive
                NOTIFY primitiveChanged)
   Q_PROPERTY(
@@ -138,10 +162,17 @@ public:
   Q_PROPERTY(::std::int32_t constProp READ getConstProp CONSTANT)
   Q_PROPERTY(::std::int32_t resettableProp READ getResettableProp WRITE
                setResettableProp RESET myResetFn)
+  Q_PROPERTY(::std::int32_t autoResetProp READ getAutoResetProp WRITE
+               setAutoResetProp RESET resetAutoResetProp)
+  Q_PROPERTY(::std::int32_t valuedResetProp READ getValuedResetProp RESET
+               resetValuedResetProp)
   Q_PROPERTY(::std::int32_t requiredProp READ getRequiredProp WRITE
                setRequiredProp REQUIRED)
   Q_PROPERTY(
     ::std::int32_t finalProp READ getFinalProp WRITE setFinalProp FINAL)
+  Q_PROPERTY(::std::int32_t bindableProp READ getBindableProp WRITE
+               setBindableProp NOTIFY bindablePropChanged BINDABLE
+               bindableBindableProp)
 
   virtual ~MyObject() = default;
 
@@ -162,21 +193,69 @@ public:
   ::std::int32_t const& getConstProp() const noexcept;
   ::std::int32_t const& getResettableProp() const noexcept;
   Q_SLOT void setResettableProp(::std::int32_t value) noexcept;
+  ::std::int32_t const& getAutoResetProp() const noexcept;
+  Q_SLOT void setAutoResetProp(::std::int32_t value) noexcept;
+  Q_SLOT void resetAutoResetProp() noexcept;
+  ::std::int32_t const& getValuedResetProp() const noexcept;
+  Q_SLOT void resetValuedResetProp() noexcept;
   ::std::int32_t const& getRequiredProp() const noexcept;
   Q_SLOT void setRequiredProp(::std::int32_t value) noexcept;
   ::std::int32_t const& getFinalProp() const noexcept;
   Q_SLOT void setFinalProp(::std::int32_t value) noexcept;
+  ::std::int32_t const& getBindableProp() const noexcept;
+  Q_SLOT void setBindableProp(::std::int32_t value) noexcept;
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+  QBindable<::std::int32_t> bindableBindableProp()
+  {
+    // Ensure that the bindable starts with the value of the Rust field
+    if (!m_bindablePropBindable.hasBinding()) {
+      m_bindablePropBindable.setValueBypassingBindings(getBindableProp());
+    }
+    return QBindable<::std::int32_t>(&m_bindablePropBindable);
+  }
+  QUntypedBindable cxxQtBindableBindableProp()
+  {
+    return bindableBindableProp();
+  }
+  bool cxxQtBindBindableProp(QUntypedBindable source)
+  {
+    QBindable<::std::int32_t> typed(source);
+    if (!typed.isValid()) {
+      return false;
+    }
+    bindableBindableProp().setBinding([typed]() { return typed.value(); });
+    return true;
+  }
+  void cxxQtUpdateBindablePropBindable()
+  {
+    m_bindablePropBindable.setValue(getBindableProp());
+  }
+#endif
   Q_SIGNAL void primitiveChanged();
   Q_SIGNAL void trivialChanged();
   Q_SIGNAL void propAutoCxxNameChanged();
   Q_SIGNAL void customFunctionPropChanged();
   Q_SIGNAL void renamedPropertyChanged();
   Q_SIGNAL void named_prop_2Changed();
+  Q_SIGNAL void bindablePropChanged();
   ::std::int32_t myGetter() const noexcept;
   void MyCustomSetter(::std::int32_t value) noexcept;
   void myResetFn() noexcept;
   Q_SIGNAL void my_on_changed();
   explicit MyObject(QObject* parent = nullptr);
+
+private:
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+  void bindableBindablePropChanged()
+  {
+    cxxQtSetBindablePropFromBindable(m_bindablePropBindable.value());
+  }
+  void cxxQtSetBindablePropFromBindable(::std::int32_t value) noexcept;
+  Q_OBJECT_BINDABLE_PROPERTY(MyObject,
+                             ::std::int32_t,
+                             m_bindablePropBindable,
+                             &MyObject::bindableBindablePropChanged)
+#endif
 };
 
 static_assert(::std::is_base_of<QObject, MyObject>::value,
@@ -23,6 +23,22 @@ mod ffi {
     unsafe extern "C++" {
         include!("directory/file_ident.cxxqt.h");
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QProperty >);
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQUntypedBindable"]
+        #[allow(dead_code)]
+        type QUntypedBindable = cxx_qt::QUntypedBindable;
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "MyObjectRust"]
@@ -37,6 +53,20 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type MyObjectRust;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcast_QObject(
+            qobject: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         #[cxx_name = "getPrimitive"]
         #[namespace = "cxx_qt::my_object"]
@@ -117,6 +147,31 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         fn set_resettable_prop(self: Pin<&mut MyObject>, value: i32);
     }
+    extern "Rust" {
+        #[cxx_name = "getAutoResetProp"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn auto_reset_prop<'a>(self: &'a MyObject) -> &'a i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "setAutoResetProp"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_auto_reset_prop(self: Pin<&mut MyObject>, value: i32);
+    }
+    extern "Rust" {
+        #[cxx_name = "resetAutoResetProp"]
+        #[namespace = "cxx_qt::my_object"]
+        fn reset_auto_reset_prop(self: Pin<&mut MyObject>);
+    }
+    extern "Rust" {
+        #[cxx_name = "getValuedResetProp"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn valued_reset_prop<'a>(self: &'a MyObject) -> &'a i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "resetValuedResetProp"]
+        #[namespace = "cxx_qt::my_object"]
+        fn reset_valued_reset_prop(self: Pin<&mut MyObject>);
+    }
     extern "Rust" {
         #[cxx_name = "getRequiredProp"]
         #[namespace = "cxx_qt::my_object"]
@@ -137,6 +192,50 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         fn set_final_prop(self: Pin<&mut MyObject>, value: i32);
     }
+    extern "Rust" {
+        #[cxx_name = "getBindableProp"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn bindable_prop<'a>(self: &'a MyObject) -> &'a i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "setBindableProp"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_bindable_prop(self: Pin<&mut MyObject>, value: i32);
+    }
+    unsafe extern "C++" {
+        #[doc = "Returns the bindable of the Q_PROPERTY "]
+        #[doc = "bindable_prop"]
+        #[doc = ", which can be passed to the bind function of another property"]
+        #[cxx_name = "cxxQtBindableBindableProp"]
+        #[namespace = "cxx_qt::my_object"]
+        fn bindable_bindable_prop(self: Pin<&mut MyObject>) -> CxxQtQUntypedBindable;
+        #[doc = "Bind the Q_PROPERTY "]
+        #[doc = "bindable_prop"]
+        #[doc = " to follow the value of the given bindable"]
+        #[doc = ""]
+        #[doc = "Returns false if the bindable is invalid or has a different type to the property."]
+        #[doc = "The binding is removed when the property is set."]
+        #[doc = ""]
+        #[doc = "# Safety"]
+        #[doc = ""]
+        #[doc = "The object which the bindable belongs to must outlive the binding."]
+        #[cxx_name = "cxxQtBindBindableProp"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn bind_bindable_prop(
+            self: Pin<&mut MyObject>,
+            source: CxxQtQUntypedBindable,
+        ) -> bool;
+        #[cxx_name = "cxxQtUpdateBindablePropBindable"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_update_bindable_prop_bindable(self: Pin<&mut MyObject>);
+    }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSetBindablePropFromBindable"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_set_bindable_prop_from_bindable(self: Pin<&mut MyObject>, value: i32);
+    }
     unsafe extern "C++" {
         #[cxx_name = "primitiveChanged"]
         #[doc = "Notify for the Q_PROPERTY"]
@@ -339,6 +438,40 @@ mod ffi {
             self_value: Pin<&mut MyObject>,
         );
     }
+    unsafe extern "C++" {
+        #[cxx_name = "bindablePropChanged"]
+        #[doc = "Notify for the Q_PROPERTY"]
+        #[namespace = "cxx_qt::my_object"]
+        fn bindable_prop_changed(self: Pin<&mut MyObject>);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlerbindablePropChanged =
+            cxx_qt::signalhandler::CxxQtSignalHandler<
+                super::MyObjectCxxQtSignalClosurebindablePropChanged,
+            >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_bindablePropChangedConnect"]
+        fn MyObject_connect_bindable_prop_changed(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlerbindablePropChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_bindablePropChanged(
+            handler: MyObjectCxxQtSignalHandlerbindablePropChanged,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_bindablePropChanged(
+            handler: &mut MyObjectCxxQtSignalHandlerbindablePropChanged,
+            self_value: Pin<&mut MyObject>,
+        );
+    }
     extern "Rust" {
         #[cxx_name = "myGetter"]
         #[namespace = "cxx_qt::my_object"]
@@ -407,6 +540,16 @@ mod ffi {
         fn cxx_qt_ffi_MyObject_unsafeRustMut(outer: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
     }
 }
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObject_upcast_QObject(this)
+    }
+}
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcast_QObject(base)
+    }
+}
 impl ffi::MyObject {
     #[doc = "Getter for the Q_PROPERTY "]
     #[doc = "primitive"]
@@ -553,6 +696,55 @@ impl ffi::MyObject {
         self.as_mut().rust_mut().resettable_prop = value;
     }
 }
+impl ffi::MyObject {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "auto_reset_prop"]
+    pub fn auto_reset_prop(&self) -> &i32 {
+        &self.auto_reset_prop
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "auto_reset_prop"]
+    pub fn set_auto_reset_prop(mut self: core::pin::Pin<&mut Self>, value: i32) {
+        use cxx_qt::CxxQtType;
+        if self.auto_reset_prop == value {
+            return;
+        }
+        self.as_mut().rust_mut().auto_reset_prop = value;
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Reset for the Q_PROPERTY "]
+    #[doc = "auto_reset_prop"]
+    pub fn reset_auto_reset_prop(mut self: core::pin::Pin<&mut Self>) {
+        use cxx_qt::CxxQtType;
+        let value: i32 = <i32 as core::default::Default>::default();
+        if self.auto_reset_prop == value {
+            return;
+        }
+        self.as_mut().rust_mut().auto_reset_prop = value;
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "valued_reset_prop"]
+    pub fn valued_reset_prop(&self) -> &i32 {
+        &self.valued_reset_prop
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Reset for the Q_PROPERTY "]
+    #[doc = "valued_reset_prop"]
+    pub fn reset_valued_reset_prop(mut self: core::pin::Pin<&mut Self>) {
+        use cxx_qt::CxxQtType;
+        let value: i32 = 42;
+        if self.valued_reset_prop == value {
+            return;
+        }
+        self.as_mut().rust_mut().valued_reset_prop = value;
+    }
+}
 impl ffi::MyObject {
     #[doc = "Getter for the Q_PROPERTY "]
     #[doc = "required_prop"]
@@ -589,6 +781,37 @@ impl ffi::MyObject {
         self.as_mut().rust_mut().final_prop = value;
     }
 }
+impl ffi::MyObject {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "bindable_prop"]
+    pub fn bindable_prop(&self) -> &i32 {
+        &self.bindable_prop
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "bindable_prop"]
+    pub fn set_bindable_prop(mut self: core::pin::Pin<&mut Self>, value: i32) {
+        use cxx_qt::CxxQtType;
+        if self.bindable_prop == value {
+            return;
+        }
+        self.as_mut().rust_mut().bindable_prop = value;
+        self.as_mut().cxx_qt_update_bindable_prop_bindable();
+        self.as_mut().bindable_prop_changed();
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    pub fn cxx_qt_set_bindable_prop_from_bindable(mut self: core::pin::Pin<&mut Self>, value: i32) {
+        use cxx_qt::CxxQtType;
+        if self.bindable_prop == value {
+            return;
+        }
+        self.as_mut().rust_mut().bindable_prop = value;
+        self.as_mut().bindable_prop_changed();
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "primitiveChanged"]
@@ -987,6 +1210,74 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurenamed_prop_2Changed>,
     [usize; 2]
 );
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "bindablePropChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    pub fn connect_bindable_prop_changed<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_bindable_prop_changed(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<
+                MyObjectCxxQtSignalClosurebindablePropChanged,
+            >::new(Box::new(closure)),
+            conn_type,
+        ))
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "bindablePropChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    pub fn on_bindable_prop_changed<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_bindable_prop_changed(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<
+                MyObjectCxxQtSignalClosurebindablePropChanged,
+            >::new(Box::new(closure)),
+            cxx_qt::ConnectionType::AutoConnection,
+        ))
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosurebindablePropChanged {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for MyObjectCxxQtSignalClosurebindablePropChanged
+{
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerbindablePropChanged"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send;
+}
+use core::mem::drop as drop_MyObject_signal_handler_bindablePropChanged;
+fn call_MyObject_signal_handler_bindablePropChanged(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosurebindablePropChanged,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+) {
+    handler.closure()(self_value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurebindablePropChanged>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurebindablePropChanged>,
+    [usize; 2]
+);
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "my_on_changed"]
@@ -1,5 +1,6 @@
 #pragma once
 
+#include <QtCore/QFlags>
 #include <QtCore/QObject>
 #include <QtQml/QQmlEngine>
 #include <cstdint>
@@ -40,6 +41,18 @@ enum class MyOtherEnum : ::std::int32_t
 };
 } // namespace my_namespace
 
+namespace cxx_qt::my_object {
+/// QEnums can be combined into QFlags
+enum class MyFlag : ::std::int32_t
+{
+  A = 1,
+  B = 2,
+  C = 4
+};
+Q_DECLARE_FLAGS(MyFlags, MyFlag)
+Q_DECLARE_OPERATORS_FOR_FLAGS(MyFlags)
+} // namespace cxx_qt::my_object
+
 namespace cxx_qt::my_object {
 Q_NAMESPACE
 enum class MyNamespacedEnum : ::std::int32_t
@@ -51,6 +64,30 @@ enum class MyNamespacedEnum : ::std::int32_t
 Q_ENUM_NS(MyNamespacedEnum)
 } // namespace cxx_qt::my_object
 
+namespace cxx_qt::my_object {
+Q_NAMESPACE
+/// QEnums can have explicit values and a repr
+enum class MyValuedEnum : ::std::int16_t
+{
+  A = -1,
+  B = 4,
+  C
+};
+Q_ENUM_NS(MyValuedEnum)
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object {
+Q_NAMESPACE
+enum class MyNamespacedFlag : ::std::uint32_t
+{
+  A = 1,
+  B = 2
+};
+Q_DECLARE_FLAGS(MyNamespacedFlags, MyNamespacedFlag)
+Q_DECLARE_OPERATORS_FOR_FLAGS(MyNamespacedFlags)
+Q_FLAG_NS(MyNamespacedFlags)
+} // namespace cxx_qt::my_object
+
 namespace other_namespace {
 Q_NAMESPACE
 enum class MyOtherNamespacedEnum : ::std::int32_t
@@ -95,12 +132,24 @@ public:
   Q_ENUM(MyOtherEnum)
 #endif
 
+#ifdef Q_MOC_RUN
+  /// QEnums can be combined into QFlags
+  enum class MyFlag : ::std::int32_t{ A = 1, B = 2, C = 4 };
+  Q_DECLARE_FLAGS(MyFlags, MyFlag)
+  Q_FLAG(MyFlags)
+#else
+  using MyFlag = ::cxx_qt::my_object::MyFlag;
+  using MyFlags = ::cxx_qt::my_object::MyFlags;
+  Q_FLAG(MyFlags)
+#endif
+
   virtual ~MyObject() = default;
 
 public:
   Q_INVOKABLE void my_invokable(
     cxx_qt::my_object::MyEnum qenum,
-    my_namespace::MyOtherEnum other_qenum) const noexcept;
+    my_namespace::MyOtherEnum other_qenum,
+    cxx_qt::my_object::MyFlags flags) const noexcept;
   explicit MyObject(QObject* parent = nullptr);
 };
 
@@ -39,6 +39,20 @@ mod ffi {
         type MyOtherEnum;
     }
     #[repr(i32)]
+    #[doc = " QEnums can be combined into QFlags"]
+    #[namespace = "cxx_qt::my_object"]
+    enum MyFlag {
+        A = 1,
+        B = 2,
+        C = 4,
+    }
+    extern "C++" {
+        #[namespace = "cxx_qt::my_object"]
+        type MyFlag;
+        #[namespace = "cxx_qt::my_object"]
+        type MyFlags = super::MyFlags;
+    }
+    #[repr(i32)]
     #[namespace = "cxx_qt::my_object"]
     enum MyNamespacedEnum {
         A,
@@ -49,6 +63,30 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type MyNamespacedEnum;
     }
+    #[repr(i16)]
+    #[doc = " QEnums can have explicit values and a repr"]
+    #[namespace = "cxx_qt::my_object"]
+    enum MyValuedEnum {
+        A = -1,
+        B = 4,
+        C,
+    }
+    extern "C++" {
+        #[namespace = "cxx_qt::my_object"]
+        type MyValuedEnum;
+    }
+    #[repr(u32)]
+    #[namespace = "cxx_qt::my_object"]
+    enum MyNamespacedFlag {
+        A = 1,
+        B = 2,
+    }
+    extern "C++" {
+        #[namespace = "cxx_qt::my_object"]
+        type MyNamespacedFlag;
+        #[namespace = "cxx_qt::my_object"]
+        type MyNamespacedFlags = super::MyNamespacedFlags;
+    }
     #[repr(i32)]
     #[namespace = "other_namespace"]
     enum MyOtherNamespacedEnum {
@@ -70,6 +108,14 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type MyRenamedEnum;
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "MyObjectRust"]
@@ -84,11 +130,25 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type MyObjectRust;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcast_QObject(
+            qobject: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         #[cxx_name = "my_invokable"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        fn my_invokable(self: &MyObject, qenum: MyEnum, other_qenum: MyOtherEnum);
+        fn my_invokable(self: &MyObject, qenum: MyEnum, other_qenum: MyOtherEnum, flags: MyFlags);
     }
     extern "Rust" {
         #[cxx_name = "createRs"]
@@ -124,6 +184,20 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type InternalObject;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_CxxName_upcast_QObject(
+            qobject: *const MyRenamedObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_CxxName_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const MyRenamedObject;
+    }
     extern "Rust" {
         #[cxx_name = "createRs"]
         #[namespace = "cxx_qt::my_object::cxx_qt_MyRenamedObject"]
@@ -144,6 +218,16 @@ mod ffi {
         ) -> Pin<&mut InternalObject>;
     }
 }
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObject_upcast_QObject(this)
+    }
+}
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcast_QObject(base)
+    }
+}
 #[doc(hidden)]
 #[allow(clippy::unnecessary_box_returns)]
 pub fn create_rs_MyObjectRust() -> std::boxed::Box<MyObjectRust> {
@@ -164,6 +248,16 @@ impl ::cxx_qt::CxxQtType for ffi::MyObject {
         ffi::cxx_qt_ffi_MyObject_unsafeRustMut(self)
     }
 }
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyRenamedObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_CxxName_upcast_QObject(this)
+    }
+}
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::MyRenamedObject {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_CxxName_downcast_QObject(base)
+    }
+}
 #[doc(hidden)]
 #[allow(clippy::unnecessary_box_returns)]
 pub fn create_rs_InternalObject() -> std::boxed::Box<InternalObject> {
@@ -184,3 +278,33 @@ impl ::cxx_qt::CxxQtType for ffi::MyRenamedObject {
         ffi::cxx_qt_ffi_CxxName_unsafeRustMut(self)
     }
 }
+#[doc = " OR-combinations of [`ffi::MyFlag`] values"]
+pub type MyFlags = cxx_qt::QFlags<ffi::MyFlag>;
+unsafe impl cxx_qt::QFlag for ffi::MyFlag {
+    type TypeId = cxx::type_id!("cxx_qt::my_object::MyFlags");
+    type Repr = i32;
+    fn to_repr(self) -> Self::Repr {
+        self.repr
+    }
+}
+impl core::ops::BitOr for ffi::MyFlag {
+    type Output = cxx_qt::QFlags<ffi::MyFlag>;
+    fn bitor(self, other: Self) -> Self::Output {
+        cxx_qt::QFlags::from(self) | other
+    }
+}
+#[doc = " OR-combinations of [`ffi::MyNamespacedFlag`] values"]
+pub type MyNamespacedFlags = cxx_qt::QFlags<ffi::MyNamespacedFlag>;
+unsafe impl cxx_qt::QFlag for ffi::MyNamespacedFlag {
+    type TypeId = cxx::type_id!("cxx_qt::my_object::MyNamespacedFlags");
+    type Repr = u32;
+    fn to_repr(self) -> Self::Repr {
+        self.repr
+    }
+}
+impl core::ops::BitOr for ffi::MyNamespacedFlag {
+    type Output = cxx_qt::QFlags<ffi::MyNamespacedFlag>;
+    fn bitor(self, other: Self) -> Self::Output {
+        cxx_qt::QFlags::from(self) | other
+    }
+}
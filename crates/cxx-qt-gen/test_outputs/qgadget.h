@@ -0,0 +1,80 @@
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtQml/QQmlEngine>
+#include <cxx-qt/signalhandler.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_object {
+class MyObject;
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlerpointChanged =
+  ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamspointChanged*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object {
+struct Point;
+} // namespace cxx_qt::my_object
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::my_object {
+/// A point in 2D space
+struct Point
+{
+  Q_GADGET
+  QML_VALUE_TYPE(point)
+  /// The x coordinate
+  Q_PROPERTY(::std::int32_t x MEMBER x)
+  /// The y coordinate
+  Q_PROPERTY(::std::int32_t y MEMBER y)
+
+public:
+  ::std::int32_t x;
+  ::std::int32_t y;
+  Q_INVOKABLE ::std::int32_t lengthSquared() const noexcept;
+  bool operator==(Point const&) const noexcept;
+  bool operator!=(Point const&) const noexcept;
+  using IsRelocatable = ::std::true_type;
+};
+
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::Point)
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_pointChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerpointChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object {
+class MyObject
+  : public QObject
+  , public ::rust::cxxqt1::CxxQtType<MyObjectRust>
+{
+  Q_OBJECT
+public:
+  Q_PROPERTY(cxx_qt::my_object::Point point READ getPoint WRITE setPoint
+               NOTIFY pointChanged)
+
+  virtual ~MyObject() = default;
+
+public:
+  cxx_qt::my_object::Point const& getPoint() const noexcept;
+  Q_SLOT void setPoint(cxx_qt::my_object::Point value) noexcept;
+  Q_SIGNAL void pointChanged();
+  explicit MyObject(QObject* parent = nullptr);
+};
+
+static_assert(::std::is_base_of<QObject, MyObject>::value,
+              "MyObject must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
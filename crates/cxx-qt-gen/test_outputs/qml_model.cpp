@@ -0,0 +1,83 @@
+#include "directory/file_ident.cxxqt.h"
+
+namespace cxx_qt::my_model {
+int
+TodoModel::rowCount(QModelIndex const& parent) const
+{
+  if (parent.isValid()) {
+    return 0;
+  }
+  return cxxQtModelRowCount();
+}
+
+QVariant
+TodoModel::data(QModelIndex const& index, int role) const
+{
+  if (!checkIndex(index,
+                  QAbstractItemModel::CheckIndexOption::IndexIsValid)) {
+    return QVariant();
+  }
+
+  cxx_qt::my_model::TodoItem const& row = cxxQtModelRow(index.row());
+  switch (role) {
+  case Qt::UserRole:
+    return QVariant::fromValue(row.title);
+  case Qt::UserRole + 1:
+    return QVariant::fromValue(row.done);
+  }
+  return QVariant();
+}
+
+bool
+TodoModel::setData(QModelIndex const& index,
+                   QVariant const& value,
+                   int role)
+{
+  if (!checkIndex(index,
+                  QAbstractItemModel::CheckIndexOption::IndexIsValid)) {
+    return false;
+  }
+
+  cxx_qt::my_model::TodoItem row = cxxQtModelRow(index.row());
+  switch (role) {
+  case Qt::UserRole:
+    if (!value.canConvert<QString>()) {
+      return false;
+    }
+    row.title = value.value<QString>();
+    break;
+  case Qt::UserRole + 1:
+    if (!value.canConvert<bool>()) {
+      return false;
+    }
+    row.done = value.value<bool>();
+    break;
+  default:
+    return false;
+  }
+  cxxQtModelSetRow(index.row(), ::std::move(row));
+  return true;
+}
+
+Qt::ItemFlags
+TodoModel::flags(QModelIndex const& index) const
+{
+  return QAbstractListModel::flags(index) | Qt::ItemIsEditable;
+}
+
+QHash<int, QByteArray>
+TodoModel::roleNames() const
+{
+  return { { Qt::UserRole, QByteArrayLiteral("title") },
+           { Qt::UserRole + 1, QByteArrayLiteral("isDone") } };
+}
+
+TodoModel::TodoModel(QObject* parent)
+  : QAbstractListModel(parent)
+  , ::rust::cxxqt1::CxxQtType<TodoModelRust>(
+      ::cxx_qt::my_model::cxx_qt_TodoModel::createRs())
+{
+}
+
+} // namespace cxx_qt::my_model
+
@@ -0,0 +1,74 @@
+#pragma once
+
+#include <QtCore/QAbstractListModel>
+#include <QtCore/QByteArray>
+#include <QtCore/QHash>
+#include <QtCore/QVariant>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_model {
+class TodoModel;
+
+} // namespace cxx_qt::my_model
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::my_model {
+class TodoModel
+  : public QAbstractListModel
+  , public ::rust::cxxqt1::CxxQtType<TodoModelRust>
+{
+  Q_OBJECT
+public:
+  Q_CLASSINFO("QML.Element", "TodoModel")
+
+  virtual ~TodoModel() = default;
+
+public:
+  int rowCount(QModelIndex const& parent = QModelIndex()) const override;
+  QVariant data(QModelIndex const& index,
+                int role = Qt::DisplayRole) const override;
+  bool setData(QModelIndex const& index,
+               QVariant const& value,
+               int role = Qt::EditRole) override;
+  Qt::ItemFlags flags(QModelIndex const& index) const override;
+  QHash<int, QByteArray> roleNames() const override;
+  void cxxQtModelBeginInsertRows(::std::int32_t first, ::std::int32_t last)
+  {
+    beginInsertRows(QModelIndex(), first, last);
+  }
+  void cxxQtModelEndInsertRows() { endInsertRows(); }
+  void cxxQtModelBeginRemoveRows(::std::int32_t first, ::std::int32_t last)
+  {
+    beginRemoveRows(QModelIndex(), first, last);
+  }
+  void cxxQtModelEndRemoveRows() { endRemoveRows(); }
+  bool cxxQtModelBeginMoveRows(::std::int32_t first,
+                               ::std::int32_t last,
+                               ::std::int32_t destination)
+  {
+    return beginMoveRows(
+      QModelIndex(), first, last, QModelIndex(), destination);
+  }
+  void cxxQtModelEndMoveRows() { endMoveRows(); }
+  void cxxQtModelBeginResetModel() { beginResetModel(); }
+  void cxxQtModelEndResetModel() { endResetModel(); }
+  void cxxQtModelRowChanged(::std::int32_t row)
+  {
+    Q_EMIT dataChanged(index(row), index(row));
+  }
+  explicit TodoModel(QObject* parent = nullptr);
+
+private:
+  ::std::int32_t cxxQtModelRowCount() const noexcept;
+  cxx_qt::my_model::TodoItem const& cxxQtModelRow(
+    ::std::int32_t row) const noexcept;
+  void cxxQtModelSetRow(::std::int32_t row,
+                        cxx_qt::my_model::TodoItem value) noexcept;
+};
+
+static_assert(::std::is_base_of<QObject, TodoModel>::value,
+              "TodoModel must inherit from QObject");
+} // namespace cxx_qt::my_model
+
+Q_DECLARE_METATYPE(cxx_qt::my_model::TodoModel*)
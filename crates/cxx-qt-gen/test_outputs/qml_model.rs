@@ -0,0 +1,279 @@
+#[cxx::bridge(namespace = "cxx_qt::my_model")]
+#[allow(unused_unsafe)]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+    #[doc = " A row of the model"]
+    struct TodoItem {
+        title: QString,
+        done: bool,
+        id: i32,
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "TodoModelRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/qobject/generated-qobject.html>"]
+        #[namespace = "cxx_qt::my_model"]
+        type TodoModel;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_model"]
+        type TodoModelRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_TodoModel_upcast_QObject(
+            qobject: *const TodoModel,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_TodoModel_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const TodoModel;
+    }
+    extern "Rust" {
+        #[cxx_name = "cxxQtModelRowCount"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        fn cxx_qt_model_row_count(self: &TodoModel) -> i32;
+        #[cxx_name = "cxxQtModelRow"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        unsafe fn cxx_qt_model_row<'a>(self: &'a TodoModel, row: i32) -> &'a TodoItem;
+        #[cxx_name = "cxxQtModelSetRow"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        fn cxx_qt_model_set_row(self: Pin<&mut TodoModel>, row: i32, value: TodoItem);
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "cxxQtModelBeginInsertRows"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        fn cxx_qt_model_begin_insert_rows(self: Pin<&mut TodoModel>, first: i32, last: i32);
+        #[cxx_name = "cxxQtModelEndInsertRows"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        fn cxx_qt_model_end_insert_rows(self: Pin<&mut TodoModel>);
+        #[cxx_name = "cxxQtModelBeginRemoveRows"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        fn cxx_qt_model_begin_remove_rows(self: Pin<&mut TodoModel>, first: i32, last: i32);
+        #[cxx_name = "cxxQtModelEndRemoveRows"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        fn cxx_qt_model_end_remove_rows(self: Pin<&mut TodoModel>);
+        #[cxx_name = "cxxQtModelBeginMoveRows"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        fn cxx_qt_model_begin_move_rows(
+            self: Pin<&mut TodoModel>,
+            first: i32,
+            last: i32,
+            destination: i32,
+        ) -> bool;
+        #[cxx_name = "cxxQtModelEndMoveRows"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        fn cxx_qt_model_end_move_rows(self: Pin<&mut TodoModel>);
+        #[cxx_name = "cxxQtModelBeginResetModel"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        fn cxx_qt_model_begin_reset_model(self: Pin<&mut TodoModel>);
+        #[cxx_name = "cxxQtModelEndResetModel"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        fn cxx_qt_model_end_reset_model(self: Pin<&mut TodoModel>);
+        #[cxx_name = "cxxQtModelRowChanged"]
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_model"]
+        fn cxx_qt_model_row_changed(self: Pin<&mut TodoModel>, row: i32);
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_model::cxx_qt_TodoModel"]
+        fn create_rs_TodoModelRust() -> Box<TodoModelRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_TodoModel_unsafeRust(outer: &TodoModel) -> &TodoModelRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_TodoModel_unsafeRustMut(
+            outer: Pin<&mut TodoModel>,
+        ) -> Pin<&mut TodoModelRust>;
+    }
+}
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::TodoModel {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_TodoModel_upcast_QObject(this)
+    }
+}
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::TodoModel {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_TodoModel_downcast_QObject(base)
+    }
+}
+impl ffi::TodoModel {
+    #[doc(hidden)]
+    pub fn cxx_qt_model_row_count(&self) -> i32 {
+        self.items.len() as i32
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_model_row(&self, row: i32) -> &ffi::TodoItem {
+        &self.items[row as usize]
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_model_set_row(self: core::pin::Pin<&mut Self>, row: i32, value: ffi::TodoItem) {
+        self.set_row(row as usize, value);
+    }
+    #[doc = r" Insert a row into the model at the given index, shifting all rows after it down"]
+    #[doc = r""]
+    #[doc = r" # Panics"]
+    #[doc = r""]
+    #[doc = r" Panics if `index > len`."]
+    pub fn insert_row(mut self: core::pin::Pin<&mut Self>, index: usize, value: ffi::TodoItem) {
+        use cxx_qt::CxxQtType;
+        assert!(
+            index <= self.items.len(),
+            "insertion index (is {index}) should be <= len (is {len})",
+            len = self.items.len()
+        );
+        self.as_mut()
+            .cxx_qt_model_begin_insert_rows(index as i32, index as i32);
+        self.as_mut().rust_mut().items.insert(index, value);
+        self.as_mut().cxx_qt_model_end_insert_rows();
+    }
+    #[doc = r" Append a row to the end of the model"]
+    pub fn push_row(self: core::pin::Pin<&mut Self>, value: ffi::TodoItem) {
+        let index = self.items.len();
+        self.insert_row(index, value);
+    }
+    #[doc = r" Remove and return the row at the given index, shifting all rows after it up"]
+    #[doc = r""]
+    #[doc = r" # Panics"]
+    #[doc = r""]
+    #[doc = r" Panics if `index` is out of bounds."]
+    pub fn remove_row(mut self: core::pin::Pin<&mut Self>, index: usize) -> ffi::TodoItem {
+        use cxx_qt::CxxQtType;
+        assert!(
+            index < self.items.len(),
+            "removal index (is {index}) should be < len (is {len})",
+            len = self.items.len()
+        );
+        self.as_mut()
+            .cxx_qt_model_begin_remove_rows(index as i32, index as i32);
+        let value = self.as_mut().rust_mut().items.remove(index);
+        self.as_mut().cxx_qt_model_end_remove_rows();
+        value
+    }
+    #[doc = r" Move the row at index `from` so that it ends up at index `to`"]
+    #[doc = r""]
+    #[doc = r" # Panics"]
+    #[doc = r""]
+    #[doc = r" Panics if `from` or `to` are out of bounds."]
+    pub fn move_row(mut self: core::pin::Pin<&mut Self>, from: usize, to: usize) {
+        use cxx_qt::CxxQtType;
+        let len = self.items.len();
+        assert!(
+            from < len,
+            "source index (is {from}) should be < len (is {len})"
+        );
+        assert!(
+            to < len,
+            "destination index (is {to}) should be < len (is {len})"
+        );
+        if from == to {
+            return;
+        }
+        let destination = if to > from { to + 1 } else { to };
+        if !self
+            .as_mut()
+            .cxx_qt_model_begin_move_rows(from as i32, from as i32, destination as i32)
+        {
+            return;
+        }
+        let value = self.as_mut().rust_mut().items.remove(from);
+        self.as_mut().rust_mut().items.insert(to, value);
+        self.as_mut().cxx_qt_model_end_move_rows();
+    }
+    #[doc = r" Replace the row at the given index, notifying views that its data has changed"]
+    #[doc = r""]
+    #[doc = r" # Panics"]
+    #[doc = r""]
+    #[doc = r" Panics if `index` is out of bounds."]
+    pub fn set_row(mut self: core::pin::Pin<&mut Self>, index: usize, value: ffi::TodoItem) {
+        use cxx_qt::CxxQtType;
+        self.as_mut().rust_mut().items[index] = value;
+        self.as_mut().cxx_qt_model_row_changed(index as i32);
+    }
+    #[doc = r" Replace all of the rows in the model"]
+    pub fn reset_rows(mut self: core::pin::Pin<&mut Self>, rows: Vec<ffi::TodoItem>) {
+        use cxx_qt::CxxQtType;
+        self.as_mut().cxx_qt_model_begin_reset_model();
+        self.as_mut().rust_mut().items = rows;
+        self.as_mut().cxx_qt_model_end_reset_model();
+    }
+    #[doc = r" Remove all of the rows in the model"]
+    pub fn clear_rows(self: core::pin::Pin<&mut Self>) {
+        self.reset_rows(Vec::new());
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_TodoModelRust() -> std::boxed::Box<TodoModelRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::TodoModel {
+    type Target = TodoModelRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_TodoModel_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::TodoModel {
+    type Rust = TodoModelRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_TodoModel_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_TodoModel_unsafeRustMut(self)
+    }
+}
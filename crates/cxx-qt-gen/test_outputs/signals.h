@@ -18,6 +18,11 @@ using MyObjectCxxQtSignalHandlerdata_changed =
   ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamsdata_changed*>;
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlervalue_changed =
+  ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamsvalue_changed*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 using MyObjectCxxQtSignalHandlernewData =
   ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamsnewData*>;
@@ -55,6 +60,21 @@ MyObject_data_changedConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+void
+MyObject_value_changedEmit(cxx_qt::my_object::MyObject& self,
+                           ::std::int32_t value);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_value_changedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlervalue_changed
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 MyObject_newDataConnect(
@@ -75,12 +95,21 @@ public:
 
 public:
   Q_INVOKABLE void invokable() noexcept;
+  /// Emitted when the object is ready
   Q_SIGNAL void ready();
   Q_SIGNAL void data_changed(::std::int32_t first,
                              ::std::unique_ptr<Opaque> second,
                              QPoint third,
                              QPoint const& fourth);
+  /// Emitted when the value changes, only MyObject can emit this signal
+  Q_SIGNAL void value_changed(::std::int32_t value, QPrivateSignal);
   explicit MyObject(QObject* parent = nullptr);
+
+private:
+  friend void
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObject_value_changedEmit(
+    cxx_qt::my_object::MyObject& self,
+    ::std::int32_t value);
 };
 
 static_assert(::std::is_base_of<QObject, MyObject>::value,
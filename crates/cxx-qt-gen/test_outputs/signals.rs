@@ -24,6 +24,14 @@ mod ffi {
     unsafe extern "C++" {
         include!("directory/file_ident.cxxqt.h");
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "MyObjectRust"]
@@ -38,6 +46,20 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type MyObjectRust;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcast_QObject(
+            qobject: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcast_QObject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         #[cxx_name = "invokable"]
         #[namespace = "cxx_qt::my_object"]
@@ -46,6 +68,7 @@ mod ffi {
     }
     unsafe extern "C++" {
         #[cxx_name = "ready"]
+        #[doc = " Emitted when the object is ready"]
         #[namespace = "cxx_qt::my_object"]
         fn ready(self: Pin<&mut MyObject>);
     }
@@ -115,6 +138,40 @@ mod ffi {
             fourth: &QPoint,
         );
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_value_changedEmit"]
+        fn MyObject_emit_value_changed(self_value: Pin<&mut MyObject>, value: i32);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlervalue_changed = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::MyObjectCxxQtSignalClosurevalue_changed,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_value_changedConnect"]
+        fn MyObject_connect_value_changed(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlervalue_changed,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_value_changed(
+            handler: MyObjectCxxQtSignalHandlervalue_changed,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_value_changed(
+            handler: &mut MyObjectCxxQtSignalHandlervalue_changed,
+            self_value: Pin<&mut MyObject>,
+            value: i32,
+        );
+    }
     unsafe extern "C++" {
         #[cxx_name = "newData"]
         #[namespace = "cxx_qt::my_object"]
@@ -202,6 +259,16 @@ mod ffi {
         );
     }
 }
+impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObject_upcast_QObject(this)
+    }
+}
+impl cxx_qt::DowncastPtr<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn downcast_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcast_QObject(base)
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "ready"]
@@ -261,6 +328,26 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureready>,
     [usize; 2]
 );
+impl ffi::MyObject {
+    #[doc = "Create a Stream of the arguments of each emission of the signal "]
+    #[doc = "ready"]
+    #[doc = "."]
+    #[doc = "\n"]
+    #[doc = "Note that the signal is disconnected when the stream is dropped."]
+    pub fn ready_stream(self: core::pin::Pin<&mut ffi::MyObject>) -> cxx_qt::QSignalStream<()> {
+        cxx_qt::QSignalStream::new(|sender| self.on_ready(move |_| sender.send(())))
+    }
+    #[doc = "Create a future which resolves to the arguments of the next emission of the signal "]
+    #[doc = "ready"]
+    #[doc = "."]
+    #[doc = "\n"]
+    #[doc = "The future resolves to None if the QObject is destroyed first."]
+    pub fn next_ready(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+    ) -> impl core::future::Future<Output = Option<()>> {
+        self.ready_stream().into_next()
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "data_changed"]
@@ -339,6 +426,65 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredata_changed>,
     [usize; 2]
 );
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "value_changed"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    pub fn connect_value_changed<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>, i32) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_value_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosurevalue_changed > :: new (Box :: new (closure)) , conn_type ,))
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "value_changed"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    pub fn on_value_changed<F: FnMut(core::pin::Pin<&mut ffi::MyObject>, i32) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_value_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosurevalue_changed > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosurevalue_changed {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosurevalue_changed {
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlervalue_changed"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>, i32) + Send;
+}
+use core::mem::drop as drop_MyObject_signal_handler_value_changed;
+fn call_MyObject_signal_handler_value_changed(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosurevalue_changed,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+    value: i32,
+) {
+    handler.closure()(self_value, value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurevalue_changed>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurevalue_changed>,
+    [usize; 2]
+);
+impl ffi::MyObject {
+    #[doc = " Emitted when the value changes, only MyObject can emit this signal"]
+    fn value_changed(self: core::pin::Pin<&mut ffi::MyObject>, value: i32) {
+        ffi::MyObject_emit_value_changed(self, value)
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "newData"]
@@ -118,7 +118,12 @@ fn main() {
         "core/qmap/qmap_qstring_qvariant",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmetaenum",
+        "core/qmetamethod",
+        "core/qmetaobject",
+        "core/qmetaproperty",
         "core/qmodelindex",
+        "core/qobject",
         "core/qpersistentmodelindex",
         "core/qpoint",
         "core/qpointf",
@@ -146,7 +151,9 @@ fn main() {
         "core/qstring",
         "core/qstringlist",
         "core/qt",
+        "core/qthread",
         "core/qtime",
+        "core/qtimer",
         "core/qurl",
         "core/qvariant/mod",
         "core/qvariant/qvariant_bool",
@@ -259,7 +266,9 @@ fn main() {
         "core/qmap/qmap",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmetaobject",
         "core/qmodelindex",
+        "core/qobject",
         "core/qpersistentmodelindex",
         "core/qpoint",
         "core/qpointf",
@@ -270,7 +279,9 @@ fn main() {
         "core/qsizef",
         "core/qstring",
         "core/qstringlist",
+        "core/qthread",
         "core/qtime",
+        "core/qtimer",
         "core/qurl",
         "core/qvariant/qvariant",
         "core/qvector/qvector",
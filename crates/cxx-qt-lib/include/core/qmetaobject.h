@@ -0,0 +1,122 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QMetaObject>
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QMetaObject&
+qobjectMetaObject(const QObject& obj);
+
+QString
+qmetaobjectClassName(const QMetaObject& metaObject);
+QString
+qmetaobjectClassInfoName(const QMetaObject& metaObject, ::std::int32_t index);
+QString
+qmetaobjectClassInfoValue(const QMetaObject& metaObject, ::std::int32_t index);
+::std::int32_t
+qmetaobjectIndexOfEnumerator(const QMetaObject& metaObject, ::rust::Str name);
+::std::int32_t
+qmetaobjectIndexOfMethod(const QMetaObject& metaObject, ::rust::Str signature);
+::std::int32_t
+qmetaobjectIndexOfProperty(const QMetaObject& metaObject, ::rust::Str name);
+::std::int32_t
+qmetaobjectIndexOfSignal(const QMetaObject& metaObject, ::rust::Str signature);
+bool
+qmetaobjectInherits(const QMetaObject& metaObject, const QMetaObject& other);
+const QMetaObject*
+qmetaobjectSuperClass(const QMetaObject& metaObject);
+
+::std::int32_t
+qmetamethodAccess(const QMetaObject& metaObject, ::std::int32_t index);
+::std::int32_t
+qmetamethodMethodType(const QMetaObject& metaObject, ::std::int32_t index);
+QString
+qmetamethodName(const QMetaObject& metaObject, ::std::int32_t index);
+::std::int32_t
+qmetamethodParameterCount(const QMetaObject& metaObject, ::std::int32_t index);
+QString
+qmetamethodParameterName(const QMetaObject& metaObject,
+                         ::std::int32_t index,
+                         ::std::int32_t parameter);
+QString
+qmetamethodParameterTypeName(const QMetaObject& metaObject,
+                             ::std::int32_t index,
+                             ::std::int32_t parameter);
+QString
+qmetamethodReturnTypeName(const QMetaObject& metaObject, ::std::int32_t index);
+QString
+qmetamethodSignature(const QMetaObject& metaObject, ::std::int32_t index);
+
+bool
+qmetapropertyHasNotifySignal(const QMetaObject& metaObject,
+                             ::std::int32_t index);
+bool
+qmetapropertyIsConstant(const QMetaObject& metaObject, ::std::int32_t index);
+bool
+qmetapropertyIsFinal(const QMetaObject& metaObject, ::std::int32_t index);
+bool
+qmetapropertyIsReadable(const QMetaObject& metaObject, ::std::int32_t index);
+bool
+qmetapropertyIsWritable(const QMetaObject& metaObject, ::std::int32_t index);
+QString
+qmetapropertyName(const QMetaObject& metaObject, ::std::int32_t index);
+::std::int32_t
+qmetapropertyNotifySignalIndex(const QMetaObject& metaObject,
+                               ::std::int32_t index);
+bool
+qmetapropertyRead(const QMetaObject& metaObject,
+                  ::std::int32_t index,
+                  const QObject& obj,
+                  QVariant& value);
+QString
+qmetapropertyTypeName(const QMetaObject& metaObject, ::std::int32_t index);
+bool
+qmetapropertyWrite(const QMetaObject& metaObject,
+                   ::std::int32_t index,
+                   QObject& obj,
+                   const QVariant& value);
+
+bool
+qmetaenumIsFlag(const QMetaObject& metaObject, ::std::int32_t index);
+bool
+qmetaenumIsScoped(const QMetaObject& metaObject, ::std::int32_t index);
+QString
+qmetaenumKey(const QMetaObject& metaObject,
+             ::std::int32_t index,
+             ::std::int32_t key);
+::std::int32_t
+qmetaenumKeyCount(const QMetaObject& metaObject, ::std::int32_t index);
+bool
+qmetaenumKeyToValue(const QMetaObject& metaObject,
+                    ::std::int32_t index,
+                    ::rust::Str key,
+                    ::std::int32_t& value);
+QString
+qmetaenumName(const QMetaObject& metaObject, ::std::int32_t index);
+QString
+qmetaenumScope(const QMetaObject& metaObject, ::std::int32_t index);
+::std::int32_t
+qmetaenumValue(const QMetaObject& metaObject,
+               ::std::int32_t index,
+               ::std::int32_t key);
+bool
+qmetaenumValueToKey(const QMetaObject& metaObject,
+                    ::std::int32_t index,
+                    ::std::int32_t value,
+                    QString& key);
+
+}
+}
@@ -0,0 +1,135 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QByteArray>
+#include <QtCore/QDebug>
+#include <QtCore/QList>
+#include <QtCore/QMetaMethod>
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtCore/QThread>
+#include <QtCore/QVariant>
+
+#include "cxx-qt/connection.h"
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QVariant
+qobjectVariantFromArgument(int typeId, const void* data);
+
+// A receiver which forwards the arguments of any signal to a Rust closure
+//
+// This overrides qt_metacall directly so that it can act as a slot
+// for any signal signature, without needing moc.
+template<typename A>
+class QObjectDynamicSlot final : public QObject
+{
+public:
+  QObjectDynamicSlot(QMetaMethod signal,
+                     ::rust::Fn<void(A& slot, const QList<QVariant>& args)> func,
+                     ::rust::Box<A> slot)
+    : m_signal(signal)
+    , m_func(func)
+    , m_slot(::std::move(slot))
+  {
+  }
+
+  int qt_metacall(QMetaObject::Call call, int id, void** args) override
+  {
+    id = QObject::qt_metacall(call, id, args);
+    if (id < 0) {
+      return id;
+    }
+
+    if (call == QMetaObject::InvokeMetaMethod) {
+      if (id == 0) {
+        QList<QVariant> arguments;
+        for (int i = 0; i < m_signal.parameterCount(); i++) {
+          arguments.append(
+            qobjectVariantFromArgument(m_signal.parameterType(i), args[i + 1]));
+        }
+        m_func(*m_slot, arguments);
+      }
+      id -= 1;
+    }
+
+    return id;
+  }
+
+private:
+  QMetaMethod m_signal;
+  ::rust::Fn<void(A& slot, const QList<QVariant>& args)> m_func;
+  ::rust::Box<A> m_slot;
+};
+
+template<typename A>
+::std::unique_ptr<::rust::cxxqt1::QMetaObjectConnectionReceiver>
+qobjectConnectDynamic(QObject& obj,
+                      ::rust::Str signal,
+                      ::rust::Fn<void(A& slot, const QList<QVariant>& args)> func,
+                      ::rust::Box<A> slot,
+                      Qt::ConnectionType type)
+{
+  const auto normalized = QMetaObject::normalizedSignature(
+    QByteArray(signal.data(), static_cast<int>(signal.size())).constData());
+  const auto* metaObject = obj.metaObject();
+  const auto signalIndex = metaObject->indexOfSignal(normalized.constData());
+  if (signalIndex < 0) {
+    qWarning() << "Could not connect as" << normalized
+               << "is not a signal of" << metaObject->className();
+    return ::std::make_unique<::rust::cxxqt1::QMetaObjectConnectionReceiver>(
+      ::QMetaObject::Connection(), &obj, nullptr);
+  }
+
+  // The receiver lives in the thread of the sender, as the context of a
+  // connection to a functor would, and is owned by the connection guard
+  auto receiver = new QObjectDynamicSlot<A>(
+    metaObject->method(signalIndex), func, ::std::move(slot));
+  receiver->moveToThread(obj.thread());
+  // The slot of the receiver is the first method after those of QObject
+  auto connection = QMetaObject::connect(&obj,
+                                         signalIndex,
+                                         receiver,
+                                         QObject::staticMetaObject.methodCount(),
+                                         static_cast<int>(type));
+  if (!connection) {
+    delete receiver;
+    receiver = nullptr;
+  }
+
+  return ::std::make_unique<::rust::cxxqt1::QMetaObjectConnectionReceiver>(
+    ::std::move(connection), &obj, receiver);
+}
+
+bool
+qobjectInvokeMethod(QObject& obj,
+                    ::rust::Str name,
+                    const QList<QVariant>& args,
+                    QVariant& result);
+
+bool
+qobjectMoveToThread(QObject& obj, QThread& thread);
+
+QList<QByteArray>
+qobjectDynamicPropertyNames(const QObject& obj);
+
+QString
+qobjectObjectName(const QObject& obj);
+
+QVariant
+qobjectProperty(const QObject& obj, ::rust::Str name);
+
+void
+qobjectSetObjectName(QObject& obj, const QString& name);
+
+bool
+qobjectSetProperty(QObject& obj, ::rust::Str name, const QVariant& value);
+
+}
+}
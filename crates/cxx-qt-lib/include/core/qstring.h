@@ -50,7 +50,7 @@ qstringRight(const QString& string, ::rust::isize n);
 QStringList
 qstringSplit(const QString& string,
              const QString& sep,
-             Qt::SplitBehaviorFlags behavior,
+             Qt::SplitBehavior behavior,
              Qt::CaseSensitivity cs);
 
 // If Q_COMPILER_REF_QUALIFIERS is set the definition of these is
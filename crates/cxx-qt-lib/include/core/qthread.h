@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QThread>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QThread>
+qthreadNew();
+
+QThread*
+qthreadCurrentThread();
+
+void
+qthreadStart(QThread& thread);
+
+bool
+qthreadWait(QThread& thread);
+
+}
+}
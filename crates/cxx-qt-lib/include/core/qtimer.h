@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QTimer>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTimer>
+qtimerNew();
+
+template<typename A>
+void
+qtimerSingleShot(::std::int32_t msec,
+                 ::rust::Fn<void(::rust::Box<A> arg)> func,
+                 ::rust::Box<A> arg)
+{
+  QTimer::singleShot(
+    static_cast<int>(msec),
+    [func = ::std::move(func), arg = ::std::move(arg)]() mutable {
+      func(::std::move(arg));
+    });
+}
+
+}
+}
@@ -41,9 +41,26 @@ pub use qmarginsf::QMarginsF;
 // Reexport QMetaObjectConnection and guard from cxx-qt
 pub use cxx_qt::{QMetaObjectConnection, QMetaObjectConnectionGuard};
 
+mod qmetaenum;
+pub use qmetaenum::QMetaEnum;
+
+mod qmetamethod;
+pub use qmetamethod::{QMetaMethod, QMetaMethodAccess, QMetaMethodType};
+
+mod qmetaobject;
+pub use qmetaobject::QMetaObject;
+
+mod qmetaproperty;
+pub use qmetaproperty::QMetaProperty;
+
 mod qmodelindex;
 pub use qmodelindex::QModelIndex;
 
+mod qobject;
+pub use qobject::QObjectExt;
+// Reexport QObject from cxx-qt
+pub use cxx_qt::QObject;
+
 mod qpersistentmodelindex;
 pub use qpersistentmodelindex::QPersistentModelIndex;
 
@@ -76,13 +93,19 @@ pub use qstringlist::QStringList;
 mod qt;
 pub use qt::{
     AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, ConnectionType, DateFormat, FillRule,
-    LayoutDirection, PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec,
-    TransformationMode,
+    LayoutDirection, PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehavior,
+    SplitBehaviorFlags, TimeSpec, TimerType, TransformationMode,
 };
 
+mod qthread;
+pub use qthread::QThread;
+
 mod qtime;
 pub use qtime::QTime;
 
+mod qtimer;
+pub use qtimer::QTimer;
+
 #[cfg(not(target_os = "emscripten"))]
 mod qtimezone;
 #[cfg(not(target_os = "emscripten"))]
@@ -0,0 +1,140 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QMetaObject, QString};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qmetaobject.h");
+        type QMetaObject = crate::QMetaObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_is_flag"]
+        fn qmetaenumIsFlag(meta_object: &QMetaObject, index: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_is_scoped"]
+        fn qmetaenumIsScoped(meta_object: &QMetaObject, index: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_key"]
+        fn qmetaenumKey(meta_object: &QMetaObject, index: i32, key: i32) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_key_count"]
+        fn qmetaenumKeyCount(meta_object: &QMetaObject, index: i32) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_key_to_value"]
+        fn qmetaenumKeyToValue(
+            meta_object: &QMetaObject,
+            index: i32,
+            key: &str,
+            value: &mut i32,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_name"]
+        fn qmetaenumName(meta_object: &QMetaObject, index: i32) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_scope"]
+        fn qmetaenumScope(meta_object: &QMetaObject, index: i32) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_value"]
+        fn qmetaenumValue(meta_object: &QMetaObject, index: i32, key: i32) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_value_to_key"]
+        fn qmetaenumValueToKey(
+            meta_object: &QMetaObject,
+            index: i32,
+            value: i32,
+            key: &mut QString,
+        ) -> bool;
+    }
+}
+
+/// The QMetaEnum class provides meta-data about an enumerator.
+///
+/// It is obtained from a [QMetaObject] with [QMetaObject::enumerator]
+/// and borrows the meta-object it describes an enumerator of.
+#[derive(Clone, Copy)]
+pub struct QMetaEnum<'a> {
+    meta_object: &'a QMetaObject,
+    index: i32,
+}
+
+impl<'a> QMetaEnum<'a> {
+    pub(crate) fn new(meta_object: &'a QMetaObject, index: i32) -> Self {
+        Self { meta_object, index }
+    }
+
+    /// Returns the meta-object this enumerator belongs to.
+    pub fn enclosing_meta_object(&self) -> &'a QMetaObject {
+        self.meta_object
+    }
+
+    /// Returns true if this enumerator is used as a flag, otherwise returns false.
+    pub fn is_flag(&self) -> bool {
+        ffi::qmetaenum_is_flag(self.meta_object, self.index)
+    }
+
+    /// Returns true if this enumerator is declared as a C++11 enum class, otherwise returns false.
+    pub fn is_scoped(&self) -> bool {
+        ffi::qmetaenum_is_scoped(self.meta_object, self.index)
+    }
+
+    /// Returns the key with the given index, or None if the index is out of range.
+    pub fn key(&self, index: i32) -> Option<QString> {
+        if (0..self.key_count()).contains(&index) {
+            Some(ffi::qmetaenum_key(self.meta_object, self.index, index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of keys.
+    pub fn key_count(&self) -> i32 {
+        ffi::qmetaenum_key_count(self.meta_object, self.index)
+    }
+
+    /// Returns the integer value of the given enumeration key, or None if the key is not defined.
+    pub fn key_to_value(&self, key: &str) -> Option<i32> {
+        let mut value = 0;
+        if ffi::qmetaenum_key_to_value(self.meta_object, self.index, key, &mut value) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the name of the enumerator, without the scope.
+    pub fn name(&self) -> QString {
+        ffi::qmetaenum_name(self.meta_object, self.index)
+    }
+
+    /// Returns the scope this enumerator was declared in, such as `"Qt"` for `Qt::TimerType`.
+    pub fn scope(&self) -> QString {
+        ffi::qmetaenum_scope(self.meta_object, self.index)
+    }
+
+    /// Returns the value with the given index, or None if the index is out of range.
+    pub fn value(&self, index: i32) -> Option<i32> {
+        if (0..self.key_count()).contains(&index) {
+            Some(ffi::qmetaenum_value(self.meta_object, self.index, index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the enumeration key of the given value, or None if the value is not defined.
+    pub fn value_to_key(&self, value: i32) -> Option<QString> {
+        let mut key = QString::default();
+        if ffi::qmetaenum_value_to_key(self.meta_object, self.index, value, &mut key) {
+            Some(key)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QMetaObject, QString};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qmetaobject.h");
+        type QMetaObject = crate::QMetaObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_access"]
+        fn qmetamethodAccess(meta_object: &QMetaObject, index: i32) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_method_type"]
+        fn qmetamethodMethodType(meta_object: &QMetaObject, index: i32) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_name"]
+        fn qmetamethodName(meta_object: &QMetaObject, index: i32) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_parameter_count"]
+        fn qmetamethodParameterCount(meta_object: &QMetaObject, index: i32) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_parameter_name"]
+        fn qmetamethodParameterName(
+            meta_object: &QMetaObject,
+            index: i32,
+            parameter: i32,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_parameter_type_name"]
+        fn qmetamethodParameterTypeName(
+            meta_object: &QMetaObject,
+            index: i32,
+            parameter: i32,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_return_type_name"]
+        fn qmetamethodReturnTypeName(meta_object: &QMetaObject, index: i32) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_signature"]
+        fn qmetamethodSignature(meta_object: &QMetaObject, index: i32) -> QString;
+    }
+}
+
+/// The kind of a method described by a [QMetaMethod]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QMetaMethodType {
+    /// The method is a plain member function
+    Method,
+    /// The method is a signal
+    Signal,
+    /// The method is a slot
+    Slot,
+    /// The method is a constructor
+    Constructor,
+}
+
+/// The access specification of a method described by a [QMetaMethod]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QMetaMethodAccess {
+    /// The method is private
+    Private,
+    /// The method is protected
+    Protected,
+    /// The method is public
+    Public,
+}
+
+/// The QMetaMethod class provides meta-data about a member function.
+///
+/// It is obtained from a [QMetaObject] with [QMetaObject::method]
+/// and borrows the meta-object it describes a method of.
+#[derive(Clone, Copy)]
+pub struct QMetaMethod<'a> {
+    meta_object: &'a QMetaObject,
+    index: i32,
+}
+
+impl<'a> QMetaMethod<'a> {
+    pub(crate) fn new(meta_object: &'a QMetaObject, index: i32) -> Self {
+        Self { meta_object, index }
+    }
+
+    /// Returns the access specification of this method.
+    pub fn access(&self) -> QMetaMethodAccess {
+        match ffi::qmetamethod_access(self.meta_object, self.index) {
+            0 => QMetaMethodAccess::Private,
+            1 => QMetaMethodAccess::Protected,
+            _ => QMetaMethodAccess::Public,
+        }
+    }
+
+    /// Returns the meta-object this method belongs to.
+    pub fn enclosing_meta_object(&self) -> &'a QMetaObject {
+        self.meta_object
+    }
+
+    /// Returns this method's index.
+    pub fn method_index(&self) -> i32 {
+        self.index
+    }
+
+    /// Returns the signature of this method, such as `"setValue(int)"`.
+    pub fn method_signature(&self) -> QString {
+        ffi::qmetamethod_signature(self.meta_object, self.index)
+    }
+
+    /// Returns the type of this method.
+    pub fn method_type(&self) -> QMetaMethodType {
+        match ffi::qmetamethod_method_type(self.meta_object, self.index) {
+            1 => QMetaMethodType::Signal,
+            2 => QMetaMethodType::Slot,
+            3 => QMetaMethodType::Constructor,
+            _ => QMetaMethodType::Method,
+        }
+    }
+
+    /// Returns the name of this method.
+    pub fn name(&self) -> QString {
+        ffi::qmetamethod_name(self.meta_object, self.index)
+    }
+
+    /// Returns the number of parameters of this method.
+    pub fn parameter_count(&self) -> i32 {
+        ffi::qmetamethod_parameter_count(self.meta_object, self.index)
+    }
+
+    /// Returns the name of the parameter at the given index,
+    /// which is empty if the parameter is unnamed or the index is out of range.
+    pub fn parameter_name(&self, parameter: i32) -> QString {
+        ffi::qmetamethod_parameter_name(self.meta_object, self.index, parameter)
+    }
+
+    /// Returns the name of the type of the parameter at the given index,
+    /// which is empty if the index is out of range.
+    pub fn parameter_type_name(&self, parameter: i32) -> QString {
+        ffi::qmetamethod_parameter_type_name(self.meta_object, self.index, parameter)
+    }
+
+    /// Returns the name of the return type of this method.
+    pub fn return_type_name(&self) -> QString {
+        ffi::qmetamethod_return_type_name(self.meta_object, self.index)
+    }
+}
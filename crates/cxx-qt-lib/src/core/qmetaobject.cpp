@@ -0,0 +1,298 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qmetaobject.h"
+
+#include <QtCore/QByteArray>
+#include <QtCore/QMetaEnum>
+#include <QtCore/QMetaMethod>
+#include <QtCore/QMetaProperty>
+
+namespace {
+
+QByteArray
+toByteArray(::rust::Str string)
+{
+  return QByteArray(string.data(), static_cast<int>(string.size()));
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QMetaObject&
+qobjectMetaObject(const QObject& obj)
+{
+  return *obj.metaObject();
+}
+
+QString
+qmetaobjectClassName(const QMetaObject& metaObject)
+{
+  return QString::fromLatin1(metaObject.className());
+}
+
+QString
+qmetaobjectClassInfoName(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return QString::fromUtf8(metaObject.classInfo(index).name());
+}
+
+QString
+qmetaobjectClassInfoValue(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return QString::fromUtf8(metaObject.classInfo(index).value());
+}
+
+::std::int32_t
+qmetaobjectIndexOfEnumerator(const QMetaObject& metaObject, ::rust::Str name)
+{
+  return metaObject.indexOfEnumerator(toByteArray(name).constData());
+}
+
+::std::int32_t
+qmetaobjectIndexOfMethod(const QMetaObject& metaObject, ::rust::Str signature)
+{
+  const auto normalized =
+    QMetaObject::normalizedSignature(toByteArray(signature).constData());
+  return metaObject.indexOfMethod(normalized.constData());
+}
+
+::std::int32_t
+qmetaobjectIndexOfProperty(const QMetaObject& metaObject, ::rust::Str name)
+{
+  return metaObject.indexOfProperty(toByteArray(name).constData());
+}
+
+::std::int32_t
+qmetaobjectIndexOfSignal(const QMetaObject& metaObject, ::rust::Str signature)
+{
+  const auto normalized =
+    QMetaObject::normalizedSignature(toByteArray(signature).constData());
+  return metaObject.indexOfSignal(normalized.constData());
+}
+
+bool
+qmetaobjectInherits(const QMetaObject& metaObject, const QMetaObject& other)
+{
+  return metaObject.inherits(&other);
+}
+
+const QMetaObject*
+qmetaobjectSuperClass(const QMetaObject& metaObject)
+{
+  return metaObject.superClass();
+}
+
+::std::int32_t
+qmetamethodAccess(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return static_cast<::std::int32_t>(metaObject.method(index).access());
+}
+
+::std::int32_t
+qmetamethodMethodType(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return static_cast<::std::int32_t>(metaObject.method(index).methodType());
+}
+
+QString
+qmetamethodName(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return QString::fromLatin1(metaObject.method(index).name());
+}
+
+::std::int32_t
+qmetamethodParameterCount(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return metaObject.method(index).parameterCount();
+}
+
+QString
+qmetamethodParameterName(const QMetaObject& metaObject,
+                         ::std::int32_t index,
+                         ::std::int32_t parameter)
+{
+  return QString::fromLatin1(
+    metaObject.method(index).parameterNames().value(parameter));
+}
+
+QString
+qmetamethodParameterTypeName(const QMetaObject& metaObject,
+                             ::std::int32_t index,
+                             ::std::int32_t parameter)
+{
+  return QString::fromLatin1(
+    metaObject.method(index).parameterTypes().value(parameter));
+}
+
+QString
+qmetamethodReturnTypeName(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return QString::fromLatin1(metaObject.method(index).typeName());
+}
+
+QString
+qmetamethodSignature(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return QString::fromLatin1(metaObject.method(index).methodSignature());
+}
+
+bool
+qmetapropertyHasNotifySignal(const QMetaObject& metaObject,
+                             ::std::int32_t index)
+{
+  return metaObject.property(index).hasNotifySignal();
+}
+
+bool
+qmetapropertyIsConstant(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return metaObject.property(index).isConstant();
+}
+
+bool
+qmetapropertyIsFinal(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return metaObject.property(index).isFinal();
+}
+
+bool
+qmetapropertyIsReadable(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return metaObject.property(index).isReadable();
+}
+
+bool
+qmetapropertyIsWritable(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return metaObject.property(index).isWritable();
+}
+
+QString
+qmetapropertyName(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return QString::fromLatin1(metaObject.property(index).name());
+}
+
+::std::int32_t
+qmetapropertyNotifySignalIndex(const QMetaObject& metaObject,
+                               ::std::int32_t index)
+{
+  return metaObject.property(index).notifySignalIndex();
+}
+
+bool
+qmetapropertyRead(const QMetaObject& metaObject,
+                  ::std::int32_t index,
+                  const QObject& obj,
+                  QVariant& value)
+{
+  // The property index is only meaningful for instances of this class
+  if (!obj.metaObject()->inherits(&metaObject)) {
+    return false;
+  }
+
+  value = metaObject.property(index).read(&obj);
+  return true;
+}
+
+QString
+qmetapropertyTypeName(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return QString::fromLatin1(metaObject.property(index).typeName());
+}
+
+bool
+qmetapropertyWrite(const QMetaObject& metaObject,
+                   ::std::int32_t index,
+                   QObject& obj,
+                   const QVariant& value)
+{
+  // The property index is only meaningful for instances of this class
+  if (!obj.metaObject()->inherits(&metaObject)) {
+    return false;
+  }
+
+  return metaObject.property(index).write(&obj, value);
+}
+
+bool
+qmetaenumIsFlag(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return metaObject.enumerator(index).isFlag();
+}
+
+bool
+qmetaenumIsScoped(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return metaObject.enumerator(index).isScoped();
+}
+
+QString
+qmetaenumKey(const QMetaObject& metaObject,
+             ::std::int32_t index,
+             ::std::int32_t key)
+{
+  return QString::fromLatin1(metaObject.enumerator(index).key(key));
+}
+
+::std::int32_t
+qmetaenumKeyCount(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return metaObject.enumerator(index).keyCount();
+}
+
+bool
+qmetaenumKeyToValue(const QMetaObject& metaObject,
+                    ::std::int32_t index,
+                    ::rust::Str key,
+                    ::std::int32_t& value)
+{
+  bool ok = false;
+  value =
+    metaObject.enumerator(index).keyToValue(toByteArray(key).constData(), &ok);
+  return ok;
+}
+
+QString
+qmetaenumName(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return QString::fromLatin1(metaObject.enumerator(index).name());
+}
+
+QString
+qmetaenumScope(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return QString::fromLatin1(metaObject.enumerator(index).scope());
+}
+
+::std::int32_t
+qmetaenumValue(const QMetaObject& metaObject,
+               ::std::int32_t index,
+               ::std::int32_t key)
+{
+  return metaObject.enumerator(index).value(key);
+}
+
+bool
+qmetaenumValueToKey(const QMetaObject& metaObject,
+                    ::std::int32_t index,
+                    ::std::int32_t value,
+                    QString& key)
+{
+  const auto* name = metaObject.enumerator(index).valueToKey(value);
+  if (name == nullptr) {
+    return false;
+  }
+
+  key = QString::fromLatin1(name);
+  return true;
+}
+
+}
+}
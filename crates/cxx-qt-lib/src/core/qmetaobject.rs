@@ -0,0 +1,187 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QMetaEnum, QMetaMethod, QMetaMethodType, QMetaProperty, QString};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qmetaobject.h");
+        /// The QMetaObject class contains meta-information about Qt objects.
+        ///
+        /// It describes the class name, super class, properties, methods, signals, enums
+        /// and class info of a QObject subclass.
+        type QMetaObject;
+
+        /// Returns the number of class information items in this class.
+        #[rust_name = "class_info_count"]
+        fn classInfoCount(self: &QMetaObject) -> i32;
+        /// Returns the class information offset for this class.
+        #[rust_name = "class_info_offset"]
+        fn classInfoOffset(self: &QMetaObject) -> i32;
+        /// Returns the number of enumerators in this class.
+        #[rust_name = "enumerator_count"]
+        fn enumeratorCount(self: &QMetaObject) -> i32;
+        /// Returns the enumerator offset for this class.
+        #[rust_name = "enumerator_offset"]
+        fn enumeratorOffset(self: &QMetaObject) -> i32;
+        /// Returns the number of methods in this class, including those of its super classes.
+        #[rust_name = "method_count"]
+        fn methodCount(self: &QMetaObject) -> i32;
+        /// Returns the method offset for this class.
+        #[rust_name = "method_offset"]
+        fn methodOffset(self: &QMetaObject) -> i32;
+        /// Returns the number of properties in this class, including those of its super classes.
+        #[rust_name = "property_count"]
+        fn propertyCount(self: &QMetaObject) -> i32;
+        /// Returns the property offset for this class.
+        #[rust_name = "property_offset"]
+        fn propertyOffset(self: &QMetaObject) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_class_name"]
+        fn qmetaobjectClassName(meta_object: &QMetaObject) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_class_info_name"]
+        fn qmetaobjectClassInfoName(meta_object: &QMetaObject, index: i32) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_class_info_value"]
+        fn qmetaobjectClassInfoValue(meta_object: &QMetaObject, index: i32) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_enumerator"]
+        fn qmetaobjectIndexOfEnumerator(meta_object: &QMetaObject, name: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_method"]
+        fn qmetaobjectIndexOfMethod(meta_object: &QMetaObject, signature: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_property"]
+        fn qmetaobjectIndexOfProperty(meta_object: &QMetaObject, name: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_signal"]
+        fn qmetaobjectIndexOfSignal(meta_object: &QMetaObject, signature: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_inherits"]
+        fn qmetaobjectInherits(meta_object: &QMetaObject, other: &QMetaObject) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_super_class"]
+        fn qmetaobjectSuperClass(meta_object: &QMetaObject) -> *const QMetaObject;
+    }
+}
+
+pub use ffi::QMetaObject;
+
+impl QMetaObject {
+    /// Returns the class name.
+    pub fn class_name(&self) -> QString {
+        ffi::qmetaobject_class_name(self)
+    }
+
+    /// Returns the name and value of the class information item with the given index,
+    /// or None if the index is out of range.
+    pub fn class_info(&self, index: i32) -> Option<(QString, QString)> {
+        if (0..self.class_info_count()).contains(&index) {
+            Some((
+                ffi::qmetaobject_class_info_name(self, index),
+                ffi::qmetaobject_class_info_value(self, index),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the meta-data for the enumerator with the given index,
+    /// or None if the index is out of range.
+    pub fn enumerator(&self, index: i32) -> Option<QMetaEnum<'_>> {
+        if (0..self.enumerator_count()).contains(&index) {
+            Some(QMetaEnum::new(self, index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the enumerators of this class, including those of its super classes.
+    pub fn enumerators(&self) -> impl Iterator<Item = QMetaEnum<'_>> {
+        (0..self.enumerator_count()).map(move |index| QMetaEnum::new(self, index))
+    }
+
+    /// Finds the enumerator with the given name and returns its index, otherwise returns -1.
+    pub fn index_of_enumerator(&self, name: &str) -> i32 {
+        ffi::qmetaobject_index_of_enumerator(self, name)
+    }
+
+    /// Finds the method with the given signature, such as `"setValue(int)"`, and returns its index,
+    /// otherwise returns -1.
+    pub fn index_of_method(&self, signature: &str) -> i32 {
+        ffi::qmetaobject_index_of_method(self, signature)
+    }
+
+    /// Finds the property with the given name and returns its index, otherwise returns -1.
+    pub fn index_of_property(&self, name: &str) -> i32 {
+        ffi::qmetaobject_index_of_property(self, name)
+    }
+
+    /// Finds the signal with the given signature, such as `"valueChanged(int)"`, and returns its index,
+    /// otherwise returns -1.
+    pub fn index_of_signal(&self, signature: &str) -> i32 {
+        ffi::qmetaobject_index_of_signal(self, signature)
+    }
+
+    /// Returns true if the class described by this QMetaObject inherits the type described by other,
+    /// otherwise returns false.
+    ///
+    /// A type is considered to inherit itself.
+    pub fn inherits(&self, other: &QMetaObject) -> bool {
+        ffi::qmetaobject_inherits(self, other)
+    }
+
+    /// Returns the meta-data for the method with the given index,
+    /// or None if the index is out of range.
+    pub fn method(&self, index: i32) -> Option<QMetaMethod<'_>> {
+        if (0..self.method_count()).contains(&index) {
+            Some(QMetaMethod::new(self, index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the methods of this class, including those of its super classes.
+    ///
+    /// This includes signals, slots, invokables and constructors.
+    pub fn methods(&self) -> impl Iterator<Item = QMetaMethod<'_>> {
+        (0..self.method_count()).map(move |index| QMetaMethod::new(self, index))
+    }
+
+    /// Returns an iterator over the properties of this class, including those of its super classes.
+    pub fn properties(&self) -> impl Iterator<Item = QMetaProperty<'_>> {
+        (0..self.property_count()).map(move |index| QMetaProperty::new(self, index))
+    }
+
+    /// Returns the meta-data for the property with the given index,
+    /// or None if the index is out of range.
+    pub fn property(&self, index: i32) -> Option<QMetaProperty<'_>> {
+        if (0..self.property_count()).contains(&index) {
+            Some(QMetaProperty::new(self, index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the signals of this class, including those of its super classes.
+    pub fn signals(&self) -> impl Iterator<Item = QMetaMethod<'_>> {
+        self.methods()
+            .filter(|method| method.method_type() == QMetaMethodType::Signal)
+    }
+
+    /// Returns the meta-object of the super class, or None if there is no such object.
+    pub fn super_class(&self) -> Option<&QMetaObject> {
+        // SAFETY: meta-objects are static data which outlive any object of their class
+        unsafe { ffi::qmetaobject_super_class(self).as_ref() }
+    }
+}
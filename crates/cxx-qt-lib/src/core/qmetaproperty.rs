@@ -0,0 +1,156 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QMetaMethod, QMetaObject, QObject, QString, QVariant};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+        include!("cxx-qt-lib/qmetaobject.h");
+        type QMetaObject = crate::QMetaObject;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_has_notify_signal"]
+        fn qmetapropertyHasNotifySignal(meta_object: &QMetaObject, index: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_is_constant"]
+        fn qmetapropertyIsConstant(meta_object: &QMetaObject, index: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_is_final"]
+        fn qmetapropertyIsFinal(meta_object: &QMetaObject, index: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_is_readable"]
+        fn qmetapropertyIsReadable(meta_object: &QMetaObject, index: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_is_writable"]
+        fn qmetapropertyIsWritable(meta_object: &QMetaObject, index: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_name"]
+        fn qmetapropertyName(meta_object: &QMetaObject, index: i32) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_notify_signal_index"]
+        fn qmetapropertyNotifySignalIndex(meta_object: &QMetaObject, index: i32) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_read"]
+        fn qmetapropertyRead(
+            meta_object: &QMetaObject,
+            index: i32,
+            obj: &QObject,
+            value: &mut QVariant,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_type_name"]
+        fn qmetapropertyTypeName(meta_object: &QMetaObject, index: i32) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_write"]
+        fn qmetapropertyWrite(
+            meta_object: &QMetaObject,
+            index: i32,
+            obj: Pin<&mut QObject>,
+            value: &QVariant,
+        ) -> bool;
+    }
+}
+
+/// The QMetaProperty class provides meta-data about a property.
+///
+/// It is obtained from a [QMetaObject] with [QMetaObject::property]
+/// and borrows the meta-object it describes a property of.
+#[derive(Clone, Copy)]
+pub struct QMetaProperty<'a> {
+    meta_object: &'a QMetaObject,
+    index: i32,
+}
+
+impl<'a> QMetaProperty<'a> {
+    pub(crate) fn new(meta_object: &'a QMetaObject, index: i32) -> Self {
+        Self { meta_object, index }
+    }
+
+    /// Returns the meta-object this property belongs to.
+    pub fn enclosing_meta_object(&self) -> &'a QMetaObject {
+        self.meta_object
+    }
+
+    /// Returns true if this property has a corresponding change notify signal, otherwise returns false.
+    pub fn has_notify_signal(&self) -> bool {
+        ffi::qmetaproperty_has_notify_signal(self.meta_object, self.index)
+    }
+
+    /// Returns true if the property is constant, otherwise returns false.
+    pub fn is_constant(&self) -> bool {
+        ffi::qmetaproperty_is_constant(self.meta_object, self.index)
+    }
+
+    /// Returns true if the property is final, otherwise returns false.
+    pub fn is_final(&self) -> bool {
+        ffi::qmetaproperty_is_final(self.meta_object, self.index)
+    }
+
+    /// Returns true if this property is readable, otherwise returns false.
+    pub fn is_readable(&self) -> bool {
+        ffi::qmetaproperty_is_readable(self.meta_object, self.index)
+    }
+
+    /// Returns true if this property is writable, otherwise returns false.
+    pub fn is_writable(&self) -> bool {
+        ffi::qmetaproperty_is_writable(self.meta_object, self.index)
+    }
+
+    /// Returns this property's name.
+    pub fn name(&self) -> QString {
+        ffi::qmetaproperty_name(self.meta_object, self.index)
+    }
+
+    /// Returns the signal which is emitted when this property changes,
+    /// or None if the property has no notify signal.
+    pub fn notify_signal(&self) -> Option<QMetaMethod<'a>> {
+        self.meta_object
+            .method(ffi::qmetaproperty_notify_signal_index(
+                self.meta_object,
+                self.index,
+            ))
+    }
+
+    /// Returns this property's index.
+    pub fn property_index(&self) -> i32 {
+        self.index
+    }
+
+    /// Reads the property's value from the given object.
+    ///
+    /// Returns None if the object is not an instance of the class this property belongs to,
+    /// or an invalid [QVariant] if the value could not be read.
+    pub fn read(&self, obj: &QObject) -> Option<QVariant> {
+        let mut value = QVariant::default();
+        if ffi::qmetaproperty_read(self.meta_object, self.index, obj, &mut value) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the name of this property's type.
+    pub fn type_name(&self) -> QString {
+        ffi::qmetaproperty_type_name(self.meta_object, self.index)
+    }
+
+    /// Writes the value as the property's value to the given object.
+    ///
+    /// Returns true if the write succeeded, otherwise returns false. This includes when the object
+    /// is not an instance of the class this property belongs to.
+    pub fn write(&self, obj: Pin<&mut QObject>, value: &QVariant) -> bool {
+        ffi::qmetaproperty_write(self.meta_object, self.index, obj, value)
+    }
+}
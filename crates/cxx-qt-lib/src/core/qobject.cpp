@@ -0,0 +1,153 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qobject.h"
+
+#include <vector>
+
+namespace {
+
+bool
+variantConvert(QVariant& variant, int typeId)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return variant.convert(QMetaType(typeId));
+#else
+  return variant.convert(typeId);
+#endif
+}
+
+QVariant
+variantForType(int typeId)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return QVariant(QMetaType(typeId));
+#else
+  return QVariant(typeId, nullptr);
+#endif
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QVariant
+qobjectVariantFromArgument(int typeId, const void* data)
+{
+  if (typeId == QMetaType::QVariant) {
+    return *static_cast<const QVariant*>(data);
+  }
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return QVariant(QMetaType(typeId), data);
+#else
+  return QVariant(typeId, data);
+#endif
+}
+
+bool
+qobjectInvokeMethod(QObject& obj,
+                    ::rust::Str name,
+                    const QList<QVariant>& args,
+                    QVariant& result)
+{
+  const auto methodName =
+    QByteArray(name.data(), static_cast<int>(name.size()));
+  const auto* metaObject = obj.metaObject();
+  result = QVariant();
+
+  for (int index = 0; index < metaObject->methodCount(); index++) {
+    const auto method = metaObject->method(index);
+    if (method.name() != methodName ||
+        method.parameterCount() != static_cast<int>(args.size())) {
+      continue;
+    }
+
+    // Convert the arguments to the parameter types of this overload,
+    // the vector is reserved so that the pointers to its values stay valid
+    ::std::vector<QVariant> arguments;
+    arguments.reserve(args.size());
+    ::std::vector<void*> argv(args.size() + 1, nullptr);
+    bool converted = true;
+    for (int i = 0; i < method.parameterCount(); i++) {
+      const auto typeId = method.parameterType(i);
+      arguments.push_back(args.at(i));
+      if (typeId == QMetaType::QVariant) {
+        argv[i + 1] = &arguments.back();
+      } else if (variantConvert(arguments.back(), typeId)) {
+        argv[i + 1] = arguments.back().data();
+      } else {
+        converted = false;
+        break;
+      }
+    }
+    if (!converted) {
+      continue;
+    }
+
+    const auto returnType = method.returnType();
+    if (returnType == QMetaType::QVariant) {
+      argv[0] = &result;
+    } else if (returnType != QMetaType::Void) {
+      result = variantForType(returnType);
+      argv[0] = result.data();
+    }
+
+    QMetaObject::metacall(
+      &obj, QMetaObject::InvokeMetaMethod, index, argv.data());
+    return true;
+  }
+
+  return false;
+}
+
+bool
+qobjectMoveToThread(QObject& obj, QThread& thread)
+{
+  // QObject::moveToThread can only push an object from its own thread
+  if (obj.thread() != QThread::currentThread()) {
+    return false;
+  }
+
+  obj.moveToThread(&thread);
+  return obj.thread() == &thread;
+}
+
+QList<QByteArray>
+qobjectDynamicPropertyNames(const QObject& obj)
+{
+  return obj.dynamicPropertyNames();
+}
+
+QString
+qobjectObjectName(const QObject& obj)
+{
+  return obj.objectName();
+}
+
+QVariant
+qobjectProperty(const QObject& obj, ::rust::Str name)
+{
+  const QByteArray propertyName(name.data(), static_cast<int>(name.size()));
+  return obj.property(propertyName.constData());
+}
+
+void
+qobjectSetObjectName(QObject& obj, const QString& name)
+{
+  obj.setObjectName(name);
+}
+
+bool
+qobjectSetProperty(QObject& obj, ::rust::Str name, const QVariant& value)
+{
+  const QByteArray propertyName(name.data(), static_cast<int>(name.size()));
+  return obj.setProperty(propertyName.constData(), value);
+}
+
+}
+}
@@ -0,0 +1,273 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    ConnectionType, QByteArray, QList, QMetaObject, QMetaObjectConnectionGuard, QObject, QString,
+    QThread, QVariant,
+};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt/connection.h");
+        #[namespace = "Qt"]
+        type ConnectionType = crate::ConnectionType;
+        #[namespace = "rust::cxxqt1"]
+        type QMetaObjectConnectionReceiver = cxx_qt::QMetaObjectConnectionReceiver;
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QByteArray = crate::QList<crate::QByteArray>;
+        type QList_QVariant = crate::QList<crate::QVariant>;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+        include!("cxx-qt-lib/qmetaobject.h");
+        type QMetaObject = crate::QMetaObject;
+        include!("cxx-qt-lib/qthread.h");
+        type QThread = crate::QThread;
+
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qobject_connect_dynamic"]
+        fn qobjectConnectDynamic(
+            obj: Pin<&mut QObject>,
+            signal: &str,
+            func: fn(&mut QObjectDynamicSlot, &QList_QVariant),
+            slot: Box<QObjectDynamicSlot>,
+            conn_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnectionReceiver>;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_invoke_method"]
+        fn qobjectInvokeMethod(
+            obj: Pin<&mut QObject>,
+            name: &str,
+            args: &QList_QVariant,
+            result: &mut QVariant,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_move_to_thread"]
+        fn qobjectMoveToThread(obj: Pin<&mut QObject>, thread: Pin<&mut QThread>) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_meta_object"]
+        fn qobjectMetaObject(obj: &QObject) -> &QMetaObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_dynamic_property_names"]
+        fn qobjectDynamicPropertyNames(obj: &QObject) -> QList_QByteArray;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_object_name"]
+        fn qobjectObjectName(obj: &QObject) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_property"]
+        fn qobjectProperty(obj: &QObject, name: &str) -> QVariant;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_set_object_name"]
+        fn qobjectSetObjectName(obj: Pin<&mut QObject>, name: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_set_property"]
+        fn qobjectSetProperty(obj: Pin<&mut QObject>, name: &str, value: &QVariant) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QObjectDynamicSlot;
+    }
+}
+
+#[doc(hidden)]
+pub struct QObjectDynamicSlot {
+    // An opaque Rust type is required to be Sized.
+    // https://github.com/dtolnay/cxx/issues/665
+    inner: Box<dyn FnMut(&[QVariant]) + Send>,
+}
+
+/// Methods of [QObject] which use the Qt types of cxx-qt-lib
+///
+/// [QObject] itself is defined in cxx-qt, so that every CXX-Qt QObject can be upcast to it
+/// with [Upcast](cxx_qt::Upcast).
+pub trait QObjectExt {
+    /// Connect the given closure to the signal with the given signature, such as `"valueChanged(int)"`,
+    /// so that when the signal is emitted the closure is called with its arguments as [QVariant]s.
+    ///
+    /// This allows for connecting to signals which are not known at compile time.
+    /// If the object has no such signal, a warning is printed and the returned connection is not connected.
+    ///
+    /// The closure is called by a receiver in the thread of this object, which is deleted once
+    /// the returned guard is dropped, or with this object if the guard is released.
+    fn connect_dynamic<F>(
+        self: Pin<&mut Self>,
+        signal: &str,
+        closure: F,
+        conn_type: ConnectionType,
+    ) -> QMetaObjectConnectionGuard
+    where
+        F: FnMut(&[QVariant]) + Send + 'static;
+
+    /// Returns the names of all properties that were dynamically added to the object
+    /// using [QObjectExt::set_property].
+    fn dynamic_property_names(&self) -> QList<QByteArray>;
+
+    /// Call the slot, invokable or signal with the given name on this object,
+    /// converting the arguments to its parameter types.
+    ///
+    /// Returns the return value of the method, which is an invalid [QVariant] if it returns nothing,
+    /// or None if the object has no method with this name that accepts these arguments.
+    fn invoke_method(self: Pin<&mut Self>, name: &str, args: &[QVariant]) -> Option<QVariant>;
+
+    /// Returns the meta-object of this object, which describes its class,
+    /// properties, methods, signals and enums.
+    fn meta_object(&self) -> &QMetaObject;
+
+    /// Changes the thread affinity of this object and its children to the given thread,
+    /// so that their events and queued signals are then processed on that thread.
+    /// Any [CxxQtThread](cxx_qt::CxxQtThread) of a moved object follows it to the new thread.
+    ///
+    /// This must be called from the thread the object currently lives in and the object must
+    /// not have a parent, otherwise false is returned and the object is not moved.
+    /// Futures spawned with [CxxQtThread::spawn_local](cxx_qt::CxxQtThread::spawn_local) cannot
+    /// follow an object to another thread, so false is also returned while the object or its
+    /// children have unfinished futures.
+    ///
+    /// # Safety
+    ///
+    /// The moved objects are then used by the other thread, so the caller must ensure that
+    /// - the Rust structs of any CXX-Qt objects which are moved are [Send]
+    /// - the moved objects are no longer accessed from this thread, other than through queued
+    ///   signals, a [CxxQtThread](cxx_qt::CxxQtThread) or while the other thread is not running
+    unsafe fn move_to_thread(self: Pin<&mut Self>, thread: Pin<&mut QThread>) -> bool;
+
+    /// Returns the name of this object.
+    fn object_name(&self) -> QString;
+
+    /// Returns the value of the object's property with the given name,
+    /// which may be declared by its class or dynamically added.
+    ///
+    /// If no such property exists, the returned variant is invalid.
+    fn property(&self, name: &str) -> QVariant;
+
+    /// Returns the value of the property with the given name,
+    /// or None if the class of this object declares no such property.
+    fn read_property(&self, name: &str) -> Option<QVariant>;
+
+    /// Sets the name of this object.
+    fn set_object_name(self: Pin<&mut Self>, name: &QString);
+
+    /// Sets the value of the object's property with the given name to value.
+    ///
+    /// If the property is declared by the class of this object, returns true if the write succeeded.
+    /// Otherwise a dynamic property is added to the object and false is returned,
+    /// or removed if the value is an invalid [QVariant].
+    fn set_property(self: Pin<&mut Self>, name: &str, value: &QVariant) -> bool;
+
+    /// Set the value of the property with the given name.
+    ///
+    /// Returns true if the write succeeded, otherwise returns false,
+    /// which includes when the class of this object declares no such property.
+    fn write_property(self: Pin<&mut Self>, name: &str, value: &QVariant) -> bool;
+}
+
+impl QObjectExt for QObject {
+    fn connect_dynamic<F>(
+        self: Pin<&mut Self>,
+        signal: &str,
+        closure: F,
+        conn_type: ConnectionType,
+    ) -> QMetaObjectConnectionGuard
+    where
+        F: FnMut(&[QVariant]) + Send + 'static,
+    {
+        fn call(slot: &mut QObjectDynamicSlot, args: &QList<QVariant>) {
+            let args: Vec<QVariant> = args.iter().cloned().collect();
+            (slot.inner)(&args)
+        }
+
+        QMetaObjectConnectionGuard::from(ffi::qobject_connect_dynamic(
+            self,
+            signal,
+            call,
+            Box::new(QObjectDynamicSlot {
+                inner: Box::new(closure),
+            }),
+            conn_type,
+        ))
+    }
+
+    fn dynamic_property_names(&self) -> QList<QByteArray> {
+        ffi::qobject_dynamic_property_names(self)
+    }
+
+    fn invoke_method(self: Pin<&mut Self>, name: &str, args: &[QVariant]) -> Option<QVariant> {
+        let mut list = QList::<QVariant>::default();
+        list.reserve(args.len() as isize);
+        for arg in args {
+            list.append_clone(arg);
+        }
+
+        let mut result = QVariant::default();
+        if ffi::qobject_invoke_method(self, name, &list, &mut result) {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    fn meta_object(&self) -> &QMetaObject {
+        ffi::qobject_meta_object(self)
+    }
+
+    unsafe fn move_to_thread(self: Pin<&mut Self>, thread: Pin<&mut QThread>) -> bool {
+        if cxx_qt::has_local_tasks(&self) {
+            return false;
+        }
+
+        ffi::qobject_move_to_thread(self, thread)
+    }
+
+    fn object_name(&self) -> QString {
+        ffi::qobject_object_name(self)
+    }
+
+    fn property(&self, name: &str) -> QVariant {
+        ffi::qobject_property(self, name)
+    }
+
+    fn read_property(&self, name: &str) -> Option<QVariant> {
+        let meta_object = self.meta_object();
+        meta_object
+            .property(meta_object.index_of_property(name))?
+            .read(self)
+    }
+
+    fn set_object_name(self: Pin<&mut Self>, name: &QString) {
+        ffi::qobject_set_object_name(self, name);
+    }
+
+    fn set_property(self: Pin<&mut Self>, name: &str, value: &QVariant) -> bool {
+        ffi::qobject_set_property(self, name, value)
+    }
+
+    fn write_property(self: Pin<&mut Self>, name: &str, value: &QVariant) -> bool {
+        // SAFETY: meta-objects are static data which outlive any object of their class,
+        // so the meta-object does not need to borrow the object while it is written to.
+        let meta_object = unsafe { &*(self.meta_object() as *const QMetaObject) };
+        if let Some(property) = meta_object.property(meta_object.index_of_property(name)) {
+            property.write(self, value)
+        } else {
+            false
+        }
+    }
+}
@@ -138,7 +138,7 @@ qstringRight(const QString& string, ::rust::isize n)
 QStringList
 qstringSplit(const QString& string,
              const QString& sep,
-             Qt::SplitBehaviorFlags behavior,
+             Qt::SplitBehavior behavior,
              Qt::CaseSensitivity cs)
 {
   return string.split(sep, behavior, cs);
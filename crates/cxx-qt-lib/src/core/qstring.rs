@@ -14,7 +14,7 @@ mod ffi {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qt.h");
         type CaseSensitivity = crate::CaseSensitivity;
-        type SplitBehaviorFlags = crate::SplitBehaviorFlags;
+        type SplitBehavior = crate::SplitBehavior;
     }
 
     unsafe extern "C++" {
@@ -167,7 +167,7 @@ mod ffi {
         fn qstringSplit(
             string: &QString,
             sep: &QString,
-            behavior: SplitBehaviorFlags,
+            behavior: SplitBehavior,
             cs: CaseSensitivity,
         ) -> QStringList;
         #[doc(hidden)]
@@ -379,10 +379,10 @@ impl QString {
     pub fn split(
         &self,
         sep: &QString,
-        behavior: ffi::SplitBehaviorFlags,
+        behavior: impl Into<ffi::SplitBehavior>,
         cs: ffi::CaseSensitivity,
     ) -> ffi::QStringList {
-        ffi::qstring_split(self, sep, behavior, cs)
+        ffi::qstring_split(self, sep, behavior.into(), cs)
     }
 
     /// Returns a Latin-1 representation of the string as a QByteArray.
@@ -30,6 +30,9 @@ mod ffi {
         RFC2822Date = 8,
     }
 
+    /// This enum specifies how the split() functions should behave with respect to empty strings.
+    ///
+    /// Values can be combined into a [SplitBehavior](crate::SplitBehavior).
     #[repr(i32)]
     enum SplitBehaviorFlags {
         KeepEmptyParts,
@@ -145,6 +148,17 @@ mod ffi {
         RelativeSize,
     }
 
+    /// The timer type indicates how accurate a timer can be.
+    #[repr(i32)]
+    enum TimerType {
+        /// Precise timers try to keep millisecond accuracy
+        PreciseTimer,
+        /// Coarse timers try to keep accuracy within 5% of the desired interval
+        CoarseTimer,
+        /// Very coarse timers only keep full second accuracy
+        VeryCoarseTimer,
+    }
+
     unsafe extern "C++" {
         include!("cxx-qt-lib/qt.h");
         type AspectRatioMode;
@@ -161,14 +175,38 @@ mod ffi {
         type BGMode;
         type ClipOperation;
         type SizeMode;
+        type TimerType;
     }
 }
 
 pub use ffi::{
     AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, DateFormat, FillRule, LayoutDirection,
-    PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec,
+    PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec, TimerType,
     TransformationMode,
 };
 
+/// OR-combinations of [SplitBehaviorFlags] values.
+pub type SplitBehavior = cxx_qt::QFlags<SplitBehaviorFlags>;
+
+// Safety:
+//
+// Qt::SplitBehavior is declared as QFlags<Qt::SplitBehaviorFlags> and the enum is an int.
+unsafe impl cxx_qt::QFlag for SplitBehaviorFlags {
+    type TypeId = cxx::type_id!("Qt::SplitBehavior");
+    type Repr = i32;
+
+    fn to_repr(self) -> Self::Repr {
+        self.repr
+    }
+}
+
+impl std::ops::BitOr for SplitBehaviorFlags {
+    type Output = SplitBehavior;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        SplitBehavior::from(self) | other
+    }
+}
+
 // Reexport ConnectionType from cxx-qt
 pub use cxx_qt::ConnectionType;
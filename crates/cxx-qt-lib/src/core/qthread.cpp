@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qthread.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QThread>
+qthreadNew()
+{
+  return ::std::make_unique<QThread>();
+}
+
+QThread*
+qthreadCurrentThread()
+{
+  return QThread::currentThread();
+}
+
+void
+qthreadStart(QThread& thread)
+{
+  thread.start();
+}
+
+bool
+qthreadWait(QThread& thread)
+{
+  return thread.wait();
+}
+
+}
+}
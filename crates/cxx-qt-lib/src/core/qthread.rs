@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qthread.h");
+
+        /// The QThread class provides a platform-independent way to manage threads.
+        ///
+        /// Note that a QThread must not be dropped while it is still running,
+        /// call [QThread::quit] and [QThread::wait] before dropping it.
+        type QThread;
+
+        /// Returns true if the thread is finished; otherwise returns false.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QThread) -> bool;
+
+        /// Returns true if the thread is running; otherwise returns false.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QThread) -> bool;
+
+        /// Tells the thread's event loop to exit with return code 0 (success).
+        ///
+        /// This function does nothing if the thread does not have an event loop.
+        fn quit(self: Pin<&mut QThread>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthread_new"]
+        fn qthreadNew() -> UniquePtr<QThread>;
+
+        #[doc(hidden)]
+        #[rust_name = "qthread_current_thread"]
+        fn qthreadCurrentThread() -> *mut QThread;
+
+        #[doc(hidden)]
+        #[rust_name = "qthread_start"]
+        fn qthreadStart(thread: Pin<&mut QThread>);
+
+        #[doc(hidden)]
+        #[rust_name = "qthread_wait"]
+        fn qthreadWait(thread: Pin<&mut QThread>) -> bool;
+    }
+
+    // QThread is not trivial to CXX as it is a QObject,
+    // so we need to use references or pointers.
+    impl UniquePtr<QThread> {}
+}
+
+pub use ffi::QThread;
+
+impl QThread {
+    /// Returns a pointer to the QThread which manages the currently executing thread.
+    pub fn current_thread() -> *mut Self {
+        ffi::qthread_current_thread()
+    }
+
+    /// Constructs a new QThread to manage a new thread.
+    ///
+    /// The new thread does not begin executing until [QThread::start] is called.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qthread_new()
+    }
+
+    /// Begins execution of the thread, which runs an event loop until [QThread::quit] is called.
+    ///
+    /// If the thread is already running, this function does nothing.
+    pub fn start(self: Pin<&mut Self>) {
+        ffi::qthread_start(self);
+    }
+
+    /// Blocks until the thread has finished execution.
+    ///
+    /// Returns true if the thread has finished, or false if it was called from the thread itself.
+    pub fn wait(self: Pin<&mut Self>) -> bool {
+        ffi::qthread_wait(self)
+    }
+}
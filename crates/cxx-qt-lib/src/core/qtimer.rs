@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::time::Duration;
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qtimer.h");
+        /// The QTimer class provides repetitive and single-shot timers.
+        #[qobject]
+        type QTimer;
+
+        /// This signal is emitted when the timer times out.
+        #[qsignal]
+        pub(self) fn timeout(self: Pin<&mut QTimer>);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        #[namespace = "Qt"]
+        type TimerType = crate::TimerType;
+
+        /// Returns the timeout interval in milliseconds.
+        fn interval(self: &QTimer) -> i32;
+
+        /// Returns true if the timer is running; otherwise returns false.
+        #[rust_name = "is_active"]
+        fn isActive(self: &QTimer) -> bool;
+
+        /// Returns whether the timer is a single-shot timer.
+        #[rust_name = "is_single_shot"]
+        fn isSingleShot(self: &QTimer) -> bool;
+
+        /// Returns the remaining time in milliseconds.
+        ///
+        /// If the timer is inactive, the returned value will be -1.
+        /// If the timer is overdue, the returned value will be 0.
+        #[rust_name = "remaining_time"]
+        fn remainingTime(self: &QTimer) -> i32;
+
+        /// Sets the timeout interval in milliseconds.
+        #[rust_name = "set_interval"]
+        fn setInterval(self: Pin<&mut QTimer>, msec: i32);
+
+        /// Sets whether the timer is a single-shot timer.
+        ///
+        /// A single-shot timer fires only once, non-single-shot timers fire every interval milliseconds.
+        #[rust_name = "set_single_shot"]
+        fn setSingleShot(self: Pin<&mut QTimer>, single_shot: bool);
+
+        /// Sets the accuracy of the timer.
+        #[rust_name = "set_timer_type"]
+        fn setTimerType(self: Pin<&mut QTimer>, timer_type: TimerType);
+
+        /// Starts or restarts the timer with the timeout specified in interval.
+        fn start(self: Pin<&mut QTimer>);
+
+        /// Starts or restarts the timer with a timeout interval of msec milliseconds.
+        #[cxx_name = "start"]
+        fn start_msec(self: Pin<&mut QTimer>, msec: i32);
+
+        /// Stops the timer.
+        fn stop(self: Pin<&mut QTimer>);
+
+        /// Returns the accuracy of the timer.
+        #[rust_name = "timer_type"]
+        fn timerType(self: &QTimer) -> TimerType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtimer_new"]
+        fn qtimerNew() -> UniquePtr<QTimer>;
+
+        #[doc(hidden)]
+        #[rust_name = "qtimer_single_shot"]
+        fn qtimerSingleShot(
+            msec: i32,
+            func: fn(Box<QTimerSingleShotFn>),
+            arg: Box<QTimerSingleShotFn>,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QTimerSingleShotFn;
+    }
+
+    // QTimer is not trivial to CXX as it is a QObject,
+    // so we need to use references or pointers.
+    impl UniquePtr<QTimer> {}
+}
+
+pub use ffi::QTimer;
+
+#[doc(hidden)]
+pub struct QTimerSingleShotFn {
+    // An opaque Rust type is required to be Sized.
+    // https://github.com/dtolnay/cxx/issues/665
+    inner: Box<dyn FnOnce()>,
+}
+
+impl QTimer {
+    /// Create a new QTimer
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qtimer_new()
+    }
+
+    /// Call the given closure on the event loop of the current thread after the given duration.
+    ///
+    /// The duration is rounded down to milliseconds.
+    pub fn single_shot<F>(duration: Duration, f: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        #[allow(clippy::boxed_local)]
+        fn call(arg: Box<QTimerSingleShotFn>) {
+            (arg.inner)()
+        }
+
+        let msec = i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+        ffi::qtimer_single_shot(
+            msec,
+            call,
+            Box::new(QTimerSingleShotFn { inner: Box::new(f) }),
+        );
+    }
+}
@@ -18,7 +18,10 @@ fn write_headers() {
     std::fs::create_dir_all(header_dir()).expect("Failed to create include directory");
 
     for file_path in [
+        "casting.h",
         "connection.h",
+        "promise.h",
+        "qobject.h",
         "signalhandler.h",
         "thread.h",
         "threading.h",
@@ -39,8 +42,8 @@ fn main() {
 
     let mut builder = CxxQtBuilder::library(interface);
 
-    let cpp_files = ["src/connection.cpp"];
-    let rust_bridges = ["src/connection.rs"];
+    let cpp_files = ["src/connection.cpp", "src/qobject.cpp"];
+    let rust_bridges = ["src/connection.rs", "src/qobject.rs"];
 
     for bridge in &rust_bridges {
         builder = builder.file(bridge);
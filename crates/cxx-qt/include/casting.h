@@ -0,0 +1,30 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+
+namespace rust {
+namespace cxxqt1 {
+
+template<typename Sub, typename Base>
+const Base*
+upcastPtr(const Sub* sub)
+{
+  return static_cast<const Base*>(sub);
+}
+
+// Checks the type of the object at runtime against the staticMetaObject of Sub,
+// returning nullptr if the object is not an instance of Sub
+template<typename Base, typename Sub>
+const Sub*
+downcastPtr(const Base* base)
+{
+  return qobject_cast<const Sub*>(base);
+}
+
+}
+}
@@ -6,6 +6,9 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <memory>
+
+#include <QtCore/QMutex>
 #include <QtCore/QObject>
 #include <QtCore/Qt>
 
@@ -26,6 +29,42 @@ namespace cxxqt1 {
 
 using QMetaObjectConnection = ::QMetaObject::Connection;
 
+// A connection to a receiver which only exists for this connection,
+// such as a receiver which calls a Rust closure
+//
+// The receiver is deleted once this is destroyed, unless it has been released,
+// and it is always deleted with the sender of the connection.
+class QMetaObjectConnectionReceiver
+{
+public:
+  QMetaObjectConnectionReceiver(::QMetaObject::Connection connection,
+                                QObject* sender,
+                                QObject* receiver);
+  ~QMetaObjectConnectionReceiver();
+
+  const ::QMetaObject::Connection& connection() const;
+  void release();
+
+private:
+  // The receiver may be destroyed by another thread, so unlike a QPointer
+  // the pointer is cleared under a lock
+  struct Receiver
+  {
+    QMutex mutex;
+    QObject* object;
+  };
+
+  ::QMetaObject::Connection m_connection;
+  ::std::shared_ptr<Receiver> m_receiver;
+};
+
+::QMetaObject::Connection
+qmetaobjectconnectionreceiverConnection(
+  const QMetaObjectConnectionReceiver& receiver);
+
+void
+qmetaobjectconnectionreceiverRelease(QMetaObjectConnectionReceiver& receiver);
+
 ::QMetaObject::Connection
 qmetaobjectconnectionDefault();
 
@@ -0,0 +1,82 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#pragma once
+
+#include <utility>
+
+#include <QtCore/QFuture>
+#include <QtCore/QFutureInterface>
+#include <QtCore/QFutureWatcher>
+#include <QtCore/QObject>
+
+namespace rust::cxxqt1 {
+
+template<typename T>
+class CxxQtPromiseBase
+{
+public:
+  CxxQtPromiseBase() { m_interface.reportStarted(); }
+
+  // If the Rust future is dropped before it has finished, for example as its
+  // QObject has been destroyed, then the QFuture is canceled instead
+  ~CxxQtPromiseBase()
+  {
+    if (!m_interface.isFinished()) {
+      m_interface.reportCanceled();
+      m_interface.reportFinished();
+    }
+  }
+
+  CxxQtPromiseBase(const CxxQtPromiseBase&) = delete;
+  CxxQtPromiseBase& operator=(const CxxQtPromiseBase&) = delete;
+
+  QFuture<T> future() { return m_interface.future(); }
+
+protected:
+  QFutureInterface<T> m_interface;
+};
+
+// Resolves the QFuture returned by an async invokable once its Rust future
+// has finished
+template<typename T>
+class CxxQtPromise : public CxxQtPromiseBase<T>
+{
+public:
+  void resolve(const T& result)
+  {
+    this->m_interface.reportResult(result);
+    this->m_interface.reportFinished();
+  }
+};
+
+template<>
+class CxxQtPromise<void> : public CxxQtPromiseBase<void>
+{
+public:
+  void resolve() { m_interface.reportFinished(); }
+};
+
+// Call the given callback with the future once it has finished, unless it has
+// been canceled or the context object has been destroyed
+template<typename T, typename F>
+void
+cxxQtOnFinished(QObject* context, const QFuture<T>& future, F callback)
+{
+  auto* watcher = new QFutureWatcher<T>(context);
+  QObject::connect(watcher,
+                   &QFutureWatcherBase::finished,
+                   watcher,
+                   [watcher, callback = ::std::move(callback)]() mutable {
+                     if (!watcher->isCanceled()) {
+                       callback(watcher->future());
+                     }
+                     watcher->deleteLater();
+                   });
+  watcher->setFuture(future);
+}
+
+}
@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstddef>
+
+#include <QtCore/QObject>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqt1 {
+
+bool
+qobjectAdoptChild(QObject& parent, QObject& child);
+
+QObject*
+qobjectChild(const QObject& obj, ::std::size_t index);
+
+::std::size_t
+qobjectChildCount(const QObject& obj);
+
+void
+qobjectDeleteLater(QObject& obj);
+
+QObject*
+qobjectFindChild(const QObject& obj, ::rust::Str name);
+
+::rust::Vec<::std::size_t>
+qobjectFindChildren(const QObject& obj, ::rust::Str name);
+
+void
+qobjectOnDestroyed(const QObject& obj,
+                   ::rust::Fn<void(::std::size_t)> callback);
+
+QObject*
+qobjectParent(const QObject& obj);
+
+void
+qobjectSetParent(QObject& obj, QObject* parent);
+
+}
+}
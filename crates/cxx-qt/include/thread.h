@@ -15,6 +15,7 @@
 
 #include <QtCore/QDebug>
 #include <QtCore/QMetaObject>
+#include <QtCore/QThread>
 
 #include "rust/cxx.h"
 
@@ -53,6 +54,13 @@ public:
     return m_obj->ptr == nullptr;
   }
 
+  bool isObjectThread() const
+  {
+    const auto guard = ::std::shared_lock(m_obj->mutex);
+    return m_obj->ptr != nullptr &&
+           m_obj->ptr->thread() == QThread::currentThread();
+  }
+
   template<typename A>
   ::std::uint8_t queue(::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
                        ::rust::Box<A> arg) const
@@ -124,6 +132,13 @@ cxxQtThreadIsDestroyed(const CxxQtThread<T>& cxxQtThread)
   return cxxQtThread.isDestroyed();
 }
 
+template<typename T>
+bool
+cxxQtThreadIsObjectThread(const CxxQtThread<T>& cxxQtThread)
+{
+  return cxxQtThread.isObjectThread();
+}
+
 } // namespace cxxqt1
 } // namespace rust
 
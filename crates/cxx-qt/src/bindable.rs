@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::ffi::c_void;
+
+/// A type-erased handle to the bindable of a Qt 6 property.
+///
+/// This is returned by the `bindable_PROPERTY_NAME` function of a `#[qproperty]` marked as `BINDABLE`
+/// and can be passed to the `bind_PROPERTY_NAME` function of another property,
+/// so that the other property follows the value of this one.
+///
+/// The bindable refers to the property of the object it was created from,
+/// so it must not be used after that object has been destroyed.
+///
+/// Note that writing to the Rust field of the property through `rust_mut()` bypasses the bindable,
+/// so bindings are not notified; use the setter of the property instead.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct QUntypedBindable {
+    _iface: *const c_void,
+    _data: *mut c_void,
+}
+
+// Safety:
+//
+// QUntypedBindable is a pair of pointers to the interface and data of the property,
+// which is trivially copyable and destructible in C++.
+unsafe impl ExternType for QUntypedBindable {
+    type Id = type_id!("QUntypedBindable");
+    type Kind = cxx::kind::Trivial;
+}
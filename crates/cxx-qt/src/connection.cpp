@@ -45,5 +45,64 @@ qmetaobjectconnectionDrop(::QMetaObject::Connection& connection)
   connection.~QMetaObjectConnection();
 }
 
+QMetaObjectConnectionReceiver::QMetaObjectConnectionReceiver(
+  ::QMetaObject::Connection connection,
+  QObject* sender,
+  QObject* receiver)
+  : m_connection(::std::move(connection))
+{
+  if (receiver) {
+    m_receiver = ::std::make_shared<Receiver>();
+    m_receiver->object = receiver;
+    QObject::connect(
+      receiver, &QObject::destroyed, [state = m_receiver](QObject*) {
+        const QMutexLocker locker(&state->mutex);
+        state->object = nullptr;
+      });
+
+    // The receiver is not a child of the sender, as a parent must live in the
+    // same thread, so it is deleted once the sender is destroyed instead
+    QObject::connect(
+      sender, &QObject::destroyed, receiver, &QObject::deleteLater);
+  }
+}
+
+QMetaObjectConnectionReceiver::~QMetaObjectConnectionReceiver()
+{
+  // The receiver may be in another thread or in the middle of a call,
+  // so it is deleted later by its own event loop
+  if (m_receiver) {
+    const QMutexLocker locker(&m_receiver->mutex);
+    if (m_receiver->object) {
+      m_receiver->object->deleteLater();
+    }
+  }
+}
+
+const ::QMetaObject::Connection&
+QMetaObjectConnectionReceiver::connection() const
+{
+  return m_connection;
+}
+
+void
+QMetaObjectConnectionReceiver::release()
+{
+  m_receiver.reset();
+}
+
+::QMetaObject::Connection
+qmetaobjectconnectionreceiverConnection(
+  const QMetaObjectConnectionReceiver& receiver)
+{
+  return receiver.connection();
+}
+
+void
+qmetaobjectconnectionreceiverRelease(QMetaObjectConnectionReceiver& receiver)
+{
+  receiver.release();
+}
+
 }
 }
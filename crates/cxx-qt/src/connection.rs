@@ -25,8 +25,23 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qmetaobjectconnection_drop"]
         fn qmetaobjectconnectionDrop(connection: &mut QMetaObjectConnection);
+
+        #[doc(hidden)]
+        type QMetaObjectConnectionReceiver;
+
+        #[doc(hidden)]
+        #[rust_name = "qmetaobjectconnectionreceiver_connection"]
+        fn qmetaobjectconnectionreceiverConnection(
+            receiver: &QMetaObjectConnectionReceiver,
+        ) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qmetaobjectconnectionreceiver_release"]
+        fn qmetaobjectconnectionreceiverRelease(receiver: Pin<&mut QMetaObjectConnectionReceiver>);
     }
 
+    impl UniquePtr<QMetaObjectConnectionReceiver> {}
+
     /// This enum describes the types of connection that can be used with signals.
     ///
     /// Note that UniqueConnection is not supported.
@@ -95,3 +110,18 @@ unsafe impl ExternType for QMetaObjectConnection {
 }
 
 pub use ffi::ConnectionType;
+
+/// A connection to a receiver which only exists for this connection,
+/// which is deleted once a [QMetaObjectConnectionGuard](crate::QMetaObjectConnectionGuard)
+/// that owns it is dropped
+#[doc(hidden)]
+pub use ffi::QMetaObjectConnectionReceiver;
+
+// SAFETY: the receiver is only deleted through a thread-safe deleteLater, under a lock which
+// its destruction also takes, and copying the connection is thread-safe.
+unsafe impl Send for QMetaObjectConnectionReceiver {}
+unsafe impl Sync for QMetaObjectConnectionReceiver {}
+
+pub(crate) use ffi::{
+    qmetaobjectconnectionreceiver_connection, qmetaobjectconnectionreceiver_release,
+};
@@ -3,7 +3,14 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::QMetaObjectConnection;
+use crate::{
+    connection::{
+        qmetaobjectconnectionreceiver_connection, qmetaobjectconnectionreceiver_release,
+        QMetaObjectConnectionReceiver,
+    },
+    QMetaObjectConnection,
+};
+use cxx::UniquePtr;
 
 /// Represents a guard to a signal-slot (or signal-functor) connection.
 ///
@@ -15,16 +22,37 @@ use crate::QMetaObjectConnection;
 #[must_use]
 pub struct QMetaObjectConnectionGuard {
     connection: QMetaObjectConnection,
+    receiver: Option<UniquePtr<QMetaObjectConnectionReceiver>>,
 }
 
 impl From<QMetaObjectConnection> for QMetaObjectConnectionGuard {
     fn from(connection: QMetaObjectConnection) -> Self {
-        Self { connection }
+        Self {
+            connection,
+            receiver: None,
+        }
+    }
+}
+
+impl From<UniquePtr<QMetaObjectConnectionReceiver>> for QMetaObjectConnectionGuard {
+    /// Guard a connection to a receiver which only exists for this connection,
+    /// so that the receiver is deleted once the guard is dropped
+    fn from(receiver: UniquePtr<QMetaObjectConnectionReceiver>) -> Self {
+        let Some(receiver_ref) = receiver.as_ref() else {
+            return Self::from(QMetaObjectConnection::default());
+        };
+
+        Self {
+            connection: qmetaobjectconnectionreceiver_connection(receiver_ref),
+            receiver: Some(receiver),
+        }
     }
 }
 
 impl Drop for QMetaObjectConnectionGuard {
     /// Disconnect and deconstruct the connection
+    ///
+    /// Any receiver which is owned by the guard is deleted afterwards.
     fn drop(&mut self) {
         self.connection.disconnect();
     }
@@ -32,7 +60,13 @@ impl Drop for QMetaObjectConnectionGuard {
 
 impl QMetaObjectConnectionGuard {
     /// Release the connection without disconnecting
+    ///
+    /// Any receiver which is owned by the guard is then deleted with the sender instead.
     pub fn release(mut self) -> QMetaObjectConnection {
+        if let Some(mut receiver) = self.receiver.take() {
+            qmetaobjectconnectionreceiver_release(receiver.pin_mut());
+        }
+
         // Take the connection as our Drop implementation disconnects automatically
         // whereas we just want to release
         core::mem::take(&mut self.connection)
@@ -0,0 +1,350 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicU64, Ordering},
+    task::{Context, Waker},
+};
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::{Arc, Mutex},
+    task::Wake,
+};
+
+use crate::{qobject, CxxQtThread, QObject, Threading, ThreadingQueueError, Upcast};
+
+/// A future spawned onto the Qt event loop and the QObject it belongs to
+struct LocalTask {
+    qobject: *const QObject,
+    future: Pin<Box<dyn Future<Output = ()>>>,
+}
+
+static NEXT_TASK_ID: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// The tasks spawned onto the event loop of this thread which have not finished yet
+    static TASKS: RefCell<HashMap<u64, LocalTask>> = RefCell::new(HashMap::new());
+
+    /// The QObject of the task which is currently being polled on this thread
+    static CURRENT_QOBJECT: Cell<*mut ()> = const { Cell::new(core::ptr::null_mut()) };
+
+    /// The QObjects of the tasks which are currently being polled on this thread, including
+    /// tasks which run a nested event loop. An entry is cleared if its QObject is destroyed.
+    static POLLED_QOBJECTS: RefCell<Vec<*const QObject>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The addresses of the QObjects whose tasks are dropped once they are destroyed
+///
+/// This is not thread local, as the QObject may have been moved to another thread since.
+static WATCHED_QOBJECTS: Mutex<BTreeSet<usize>> = Mutex::new(BTreeSet::new());
+
+/// Restores the previous value of [CURRENT_QOBJECT] when dropped
+struct CurrentQObjectGuard(*mut ());
+
+impl CurrentQObjectGuard {
+    fn replace(qobject: *mut ()) -> Self {
+        Self(CURRENT_QOBJECT.with(|current| current.replace(qobject)))
+    }
+}
+
+impl Drop for CurrentQObjectGuard {
+    fn drop(&mut self) {
+        CURRENT_QOBJECT.with(|current| current.set(self.0));
+    }
+}
+
+/// A handle to the QObject of a future spawned onto the Qt event loop
+///
+/// A `Pin<&mut T>` cannot be held across an `.await`, as other code on the Qt event loop may
+/// access the QObject while the future is suspended. Instead the future keeps this handle and
+/// borrows the QObject again with [LocalQObject::with] whenever it is needed.
+pub struct LocalQObject<T> {
+    qobject: *mut T,
+}
+
+impl<T> LocalQObject<T> {
+    /// Borrow the QObject for the duration of the given closure
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a poll of the future this handle was given to, or if the
+    /// QObject is already borrowed by an outer call to [LocalQObject::with].
+    pub fn with<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(Pin<&mut T>) -> R,
+    {
+        let qobject = self.qobject as *mut ();
+        let current = CURRENT_QOBJECT.with(Cell::get);
+        assert!(
+            current == qobject,
+            "LocalQObject can only be accessed while its future is polled by the Qt event loop"
+        );
+
+        // Clear the current QObject so that it cannot be borrowed twice
+        let _guard = CurrentQObjectGuard::replace(core::ptr::null_mut());
+        // SAFETY: The QObject is currently being polled on this thread, so the pointer is valid
+        // and no other reference to it exists while the closure runs.
+        f(unsafe { Pin::new_unchecked(&mut *(current as *mut T)) })
+    }
+}
+
+impl<T> LocalQObject<T>
+where
+    T: Threading + Upcast<QObject> + 'static,
+{
+    /// Spawn a future onto the Qt event loop of the given QObject, from the thread it lives in
+    ///
+    /// Unlike [CxxQtThread::spawn_local] the closure is called straight away, so neither the
+    /// closure nor the future need to be [Send]. The future is first polled once control has
+    /// returned to the Qt event loop, as the QObject is still borrowed until then.
+    ///
+    /// If the QObject is destroyed before the future has finished, the future is dropped.
+    /// The future cannot follow the QObject to another thread, so the QObject cannot be moved
+    /// to another thread until the future has finished.
+    pub fn spawn<F, Fut>(qobject: Pin<&mut T>, f: F)
+    where
+        F: FnOnce(LocalQObject<T>) -> Fut,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let qt_thread = qobject.qt_thread();
+        // SAFETY: The pointer is only dereferenced by LocalQObject::with while the
+        // task is polled on this thread, which is after the given borrow has ended.
+        let qobject = unsafe { qobject.get_unchecked_mut() } as *mut T;
+        let id = insert_task(qobject, Box::pin(f(LocalQObject { qobject })));
+
+        Waker::from(Arc::new(TaskWaker { qt_thread, id })).wake();
+    }
+}
+
+impl<T> Clone for LocalQObject<T> {
+    fn clone(&self) -> Self {
+        Self {
+            qobject: self.qobject,
+        }
+    }
+}
+
+/// Wakes a task by queueing another poll of it onto the thread of its QObject
+struct TaskWaker<T>
+where
+    T: Threading,
+{
+    qt_thread: CxxQtThread<T>,
+    id: u64,
+}
+
+impl<T> Wake for TaskWaker<T>
+where
+    T: Threading + 'static,
+{
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let qt_thread = self.qt_thread.clone();
+        let id = self.id;
+        // If the QObject has been destroyed its task has already been dropped
+        self.qt_thread
+            .queue(move |qobject| {
+                // SAFETY: The pointer is only used while polling the task below.
+                let qobject = unsafe { qobject.get_unchecked_mut() } as *mut T;
+                poll_task(qobject, qt_thread, id);
+            })
+            .ok();
+    }
+}
+
+/// Poll the task with the given id once, if it has not finished yet
+fn poll_task<T>(qobject: *mut T, qt_thread: CxxQtThread<T>, id: u64)
+where
+    T: Threading + 'static,
+{
+    // Remove the task while it is polled, so that it can spawn further tasks
+    let Some(mut task) = TASKS.with(|tasks| tasks.borrow_mut().remove(&id)) else {
+        return;
+    };
+
+    let waker = Waker::from(Arc::new(TaskWaker { qt_thread, id }));
+    let mut context = Context::from_waker(&waker);
+    POLLED_QOBJECTS.with(|polled| polled.borrow_mut().push(task.qobject));
+    let poll = {
+        let _guard = CurrentQObjectGuard::replace(qobject as *mut ());
+        task.future.as_mut().poll(&mut context)
+    };
+    let polled = POLLED_QOBJECTS.with(|polled| polled.borrow_mut().pop());
+
+    // The task is dropped if its QObject was destroyed while it was polled
+    let destroyed = polled.unwrap_or_else(core::ptr::null).is_null();
+    if poll.is_pending() && !destroyed {
+        TASKS.with(|tasks| tasks.borrow_mut().insert(id, task));
+    }
+}
+
+/// Add a task for the given QObject, which is dropped once the QObject is destroyed
+fn insert_task<T>(qobject: *mut T, future: Pin<Box<dyn Future<Output = ()>>>) -> u64
+where
+    T: Upcast<QObject>,
+{
+    // SAFETY: The QObject is alive as this is called on its thread with a pointer to it.
+    let base = unsafe { &*T::upcast_ptr(qobject) };
+    drop_tasks_on_destroyed(base);
+
+    let id = NEXT_TASK_ID.fetch_add(1, Ordering::Relaxed);
+    TASKS.with(|tasks| {
+        tasks.borrow_mut().insert(
+            id,
+            LocalTask {
+                qobject: base,
+                future,
+            },
+        )
+    });
+    id
+}
+
+/// Drop the tasks of the given QObject once it is destroyed, as they could never be woken again
+fn drop_tasks_on_destroyed(qobject: &QObject) {
+    let address = qobject as *const QObject as usize;
+    if WATCHED_QOBJECTS.lock().unwrap().insert(address) {
+        qobject::on_destroyed(qobject, drop_tasks);
+    }
+}
+
+/// Drop the tasks of the QObject at the given address which is being destroyed
+fn drop_tasks(address: usize) {
+    WATCHED_QOBJECTS.lock().unwrap().remove(&address);
+
+    // The thread locals are gone if the QObject is destroyed while its thread exits,
+    // in which case the tasks have already been dropped.
+    let qobject = address as *const QObject;
+    POLLED_QOBJECTS
+        .try_with(|polled| {
+            for polled in polled.borrow_mut().iter_mut() {
+                if *polled == qobject {
+                    *polled = core::ptr::null();
+                }
+            }
+        })
+        .ok();
+    let tasks = TASKS
+        .try_with(|tasks| {
+            let mut tasks = tasks.borrow_mut();
+            let ids: Vec<u64> = tasks
+                .iter()
+                .filter(|(_, task)| task.qobject == qobject)
+                .map(|(id, _)| *id)
+                .collect();
+            ids.iter()
+                .filter_map(|id| tasks.remove(id))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    // Drop the futures once the tasks are no longer borrowed, as they may drop further QObjects
+    drop(tasks);
+}
+
+/// Returns whether the given QObject or any of its children have unfinished tasks on this thread
+///
+/// These tasks cannot follow the QObject to another thread, so it must not be moved while they
+/// exist.
+pub fn has_local_tasks(qobject: &QObject) -> bool {
+    let target = qobject as *const QObject;
+    let is_within_target = |mut object: *const QObject| {
+        // SAFETY: The QObjects of tasks are alive, as their tasks are dropped once they are
+        // destroyed, and so are their parents.
+        while let Some(current) = unsafe { object.as_ref() } {
+            if object == target {
+                return true;
+            }
+            object = current.parent();
+        }
+        false
+    };
+
+    POLLED_QOBJECTS.with(|polled| {
+        polled
+            .borrow()
+            .iter()
+            .any(|polled| is_within_target(*polled))
+    }) || TASKS.with(|tasks| {
+        tasks
+            .borrow()
+            .values()
+            .any(|task| is_within_target(task.qobject))
+    })
+}
+
+impl<T> CxxQtThread<T>
+where
+    T: Threading + Upcast<QObject> + 'static,
+{
+    /// Spawn a future onto the Qt event loop of this QObject
+    ///
+    /// The given closure is called on the thread of the QObject to create the future, which is
+    /// then polled on that thread whenever it is woken. This allows for futures which are not
+    /// [Send], and the [LocalQObject] handle gives the future access to the QObject between
+    /// awaits.
+    ///
+    /// If the QObject is destroyed before the future has finished, the future is dropped.
+    /// The future cannot follow the QObject to another thread, so the QObject cannot be moved
+    /// to another thread until the future has finished.
+    pub fn spawn_local<F, Fut>(&self, f: F) -> Result<(), ThreadingQueueError>
+    where
+        F: FnOnce(LocalQObject<T>) -> Fut,
+        F: Send + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let qt_thread = self.clone();
+        self.queue(move |qobject| {
+            // SAFETY: The pointer is only dereferenced by LocalQObject::with while the
+            // task is polled on this thread.
+            let qobject = unsafe { qobject.get_unchecked_mut() } as *mut T;
+            let future = {
+                let _guard = CurrentQObjectGuard::replace(qobject as *mut ());
+                Box::pin(f(LocalQObject { qobject }))
+            };
+            let id = insert_task(qobject, future);
+            poll_task(qobject, qt_thread, id);
+        })
+    }
+}
+
+/// Run the Qt event loop, such as `QGuiApplication::exec`, within the context of a tokio runtime
+///
+/// This allows futures spawned with [CxxQtThread::spawn_local] on the main thread to use tokio
+/// IO and timers, and `tokio::spawn` to be called from invokables, while the worker threads of
+/// the runtime drive them alongside the Qt event loop.
+///
+/// ```rust,ignore
+/// let runtime = tokio::runtime::Runtime::new().unwrap();
+/// let mut app = QGuiApplication::new();
+/// cxx_qt::exec_with_tokio(runtime.handle(), || app.pin_mut().exec());
+/// ```
+///
+/// The runtime must be a multi-thread runtime, as nothing drives the IO and timers of a
+/// current-thread runtime while the Qt event loop is running.
+///
+/// # Panics
+///
+/// Panics if the runtime is a current-thread runtime.
+#[cfg(feature = "tokio")]
+pub fn exec_with_tokio<F, R>(handle: &tokio::runtime::Handle, exec: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    assert!(
+        handle.runtime_flavor() != tokio::runtime::RuntimeFlavor::CurrentThread,
+        "exec_with_tokio requires a multi-thread tokio runtime"
+    );
+
+    // Entering the runtime makes it the context of everything which runs on the Qt event loop,
+    // while its worker threads drive the IO and timers and wake the futures.
+    let _guard = handle.enter();
+    exec()
+}
@@ -11,17 +11,31 @@
 
 use std::{fs::File, io::Write, path::Path};
 
+mod bindable;
 mod connection;
 mod connectionguard;
+mod executor;
+mod qflags;
+mod qobject;
 #[doc(hidden)]
 pub mod signalhandler;
+mod signalstream;
 mod threading;
 
 pub use cxx_qt_macro::bridge;
 pub use cxx_qt_macro::qobject;
 
-pub use connection::{ConnectionType, QMetaObjectConnection};
+pub use bindable::QUntypedBindable;
+pub use connection::{ConnectionType, QMetaObjectConnection, QMetaObjectConnectionReceiver};
 pub use connectionguard::QMetaObjectConnectionGuard;
+#[cfg(feature = "tokio")]
+pub use executor::exec_with_tokio;
+#[doc(hidden)]
+pub use executor::has_local_tasks;
+pub use executor::LocalQObject;
+pub use qflags::{QFlag, QFlagRepr, QFlags};
+pub use qobject::QObject;
+pub use signalstream::{QSignalStream, QSignalStreamSender};
 pub use threading::{CxxQtThread, ThreadingQueueError};
 
 // Export static assertions that can then be used in cxx-qt-gen generation
@@ -108,6 +122,9 @@ pub trait Threading: Sized {
     #[doc(hidden)]
     fn is_destroyed(cxx_qt_thread: &CxxQtThread<Self>) -> bool;
 
+    #[doc(hidden)]
+    fn is_object_thread(cxx_qt_thread: &CxxQtThread<Self>) -> bool;
+
     #[doc(hidden)]
     fn queue<F>(cxx_qt_thread: &CxxQtThread<Self>, f: F) -> Result<(), ThreadingQueueError>
     where
@@ -121,10 +138,96 @@ pub trait Threading: Sized {
     fn threading_drop(cxx_qt_thread: &mut CxxQtThread<Self>);
 }
 
-/// Placeholder for upcasting objects, suppresses dead code warning
-#[allow(dead_code)]
+/// This trait is automatically implemented by CXX-Qt for a QObject and each of its base classes,
+/// so that it can be used where a reference to a base class is expected.
+///
+/// Every QObject generated by CXX-Qt can be upcast to [QObject], as well as to each class in its
+/// `#[base]` chain, as far as the chain is made up of QObjects of the same bridge.
+///
+/// The chain stops at the first base class from outside of the bridge, such as a type from an
+/// `extern "C++Qt"` block or a QObject of another bridge, as its own base classes are not known.
+/// If that class implements [Upcast] itself, its base classes can be reached by upcasting again.
+///
+/// ```rust,ignore
+/// let qobject: &cxx_qt::QObject = my_object.upcast();
+/// ```
+///
+/// The reverse direction is provided by [Downcast].
+pub trait Upcast<T> {
+    #[doc(hidden)]
+    /// # Safety
+    ///
+    /// The pointer must be a valid pointer to Self.
+    unsafe fn upcast_ptr(this: *const Self) -> *const T;
+
+    /// Upcast a reference to a reference to the base class
+    fn upcast(&self) -> &T {
+        // SAFETY: the base class is part of the same C++ object, so it lives as long as self
+        unsafe { &*Self::upcast_ptr(self) }
+    }
+
+    /// Upcast a pinned mutable reference to a pinned mutable reference to the base class
+    fn upcast_pin(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut T> {
+        // SAFETY: the base class is part of the same C++ object, so it is pinned and exclusively
+        // borrowed for as long as self is.
+        unsafe {
+            let this = self.get_unchecked_mut() as *mut Self;
+            core::pin::Pin::new_unchecked(&mut *(Self::upcast_ptr(this) as *mut T))
+        }
+    }
+}
+
 #[doc(hidden)]
-pub trait Upcast<T> {}
+/// This trait is automatically implemented by CXX-Qt alongside [Upcast] to check the type of
+/// an object at runtime when it is downcast with [Downcast].
+pub trait DowncastPtr<T>: Upcast<T> {
+    /// # Safety
+    ///
+    /// The pointer must be a valid pointer to T.
+    /// Returns a null pointer if the object is not an instance of Self.
+    unsafe fn downcast_ptr(base: *const T) -> *const Self;
+}
+
+/// This trait is implemented for all types, so that a base class such as [QObject] can be
+/// downcast to any type which CXX-Qt implements [Upcast] to it for.
+///
+/// The cast is checked at runtime with `qobject_cast` against the `staticMetaObject` of the
+/// sub class, so None is returned if the object is not an instance of the sub class.
+///
+/// ```rust,ignore
+/// if let Some(my_object) = qobject.downcast::<qobject::MyObject>() {
+///     my_object.set_number(42);
+/// }
+/// ```
+pub trait Downcast: Sized {
+    /// Downcast a pinned mutable reference to a pinned mutable reference to the sub class,
+    /// or None if the object is not an instance of the sub class
+    fn downcast<Sub>(self: core::pin::Pin<&mut Self>) -> Option<core::pin::Pin<&mut Sub>>
+    where
+        Sub: DowncastPtr<Self>,
+    {
+        // SAFETY: the sub class is the same C++ object, so it is pinned and exclusively
+        // borrowed for as long as self is.
+        unsafe {
+            let base = self.get_unchecked_mut() as *mut Self;
+            (Sub::downcast_ptr(base) as *mut Sub)
+                .as_mut()
+                .map(|sub| core::pin::Pin::new_unchecked(sub))
+        }
+    }
+
+    /// Downcast a reference to a reference to the sub class,
+    /// or None if the object is not an instance of the sub class
+    fn downcast_ref<Sub>(&self) -> Option<&Sub>
+    where
+        Sub: DowncastPtr<Self>,
+    {
+        // SAFETY: the sub class is the same C++ object, so it lives as long as self
+        unsafe { Sub::downcast_ptr(self).as_ref() }
+    }
+}
+
+impl<T> Downcast for T {}
 
 /// This trait can be implemented on any [CxxQtType] to define a
 /// custom constructor in C++ for the QObject.
@@ -331,6 +434,52 @@ where
     }
 }
 
+/// This trait can be implemented on a `#[qml_singleton]` [CxxQtType] to create the singleton
+/// instance itself, instead of the QML engine default-constructing it.
+///
+/// The `Engine` is the type of the QML engine which is given to the factory. It must be declared
+/// inside the [cxx_qt::bridge](bridge) macro, usually as `cxx_qt_lib::QQmlEngine`, but any base
+/// class of `QQmlEngine` such as `QJSEngine` may also be used.
+///
+/// Ensure that the `impl cxx_qt::SingletonFactory<Engine> for ... {}` is declared inside the
+/// CXX-Qt bridge, which generates the static `create(QQmlEngine*, QJSEngine*)` method in C++.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     unsafe extern "C++" {
+///         include!("cxx-qt-lib/qqmlengine.h");
+///         type QQmlEngine = cxx_qt_lib::QQmlEngine;
+///     }
+///
+///     extern "RustQt" {
+///         #[qobject]
+///         #[qml_element]
+///         #[qml_singleton]
+///         type MySingleton = super::MySingletonRust;
+///     }
+///
+///     impl cxx_qt::SingletonFactory<QQmlEngine> for MySingleton {}
+/// }
+///
+/// impl cxx_qt::SingletonFactory<qobject::QQmlEngine> for qobject::MySingleton {
+///     fn create(engine: core::pin::Pin<&mut qobject::QQmlEngine>) -> *mut Self {
+///         // Return an instance which has been created before the engine, for example
+///         APP_STATE.with(|state| state.singleton())
+///     }
+/// }
+/// ```
+pub trait SingletonFactory<Engine>: CxxQtType {
+    /// This function is called by the QML engine the first time the singleton is used.
+    ///
+    /// The QML engine takes ownership of the returned QObject, unless its ownership has been
+    /// set to `QJSEngine::CppOwnership`. As the same QObject can only be owned by one engine,
+    /// an existing instance should only be returned for a single engine.
+    fn create(engine: core::pin::Pin<&mut Engine>) -> *mut Self;
+}
+
 #[doc(hidden)]
 // Write the cxx-qt headers to the specified directory.
 pub fn write_headers(directory: impl AsRef<Path>) {
@@ -0,0 +1,346 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for i32 {}
+    impl Sealed for u32 {}
+}
+
+/// The integer types which can be used to store a [QFlags].
+///
+/// This matches the `Int` type of a C++ `QFlags<T>`, which is either a signed or unsigned int.
+pub trait QFlagRepr:
+    private::Sealed
+    + Copy
+    + Default
+    + Eq
+    + Hash
+    + fmt::Debug
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+{
+}
+
+impl QFlagRepr for i32 {}
+impl QFlagRepr for u32 {}
+
+/// This trait is implemented for enums which can be combined into a [QFlags].
+///
+/// It is automatically implemented by CXX-Qt for a `#[qenum]` with a `#[qflags(...)]` attribute.
+///
+/// # Safety
+///
+/// `TypeId` must be the [cxx::type_id] of a C++ `QFlags<T>` for the C++ enum of this type
+/// and `Repr` must be the underlying integer type of the enum.
+pub unsafe trait QFlag: Copy {
+    /// The [cxx::type_id] of the C++ `QFlags<T>` type for this enum
+    type TypeId;
+    /// The integer type used to store the flags
+    type Repr: QFlagRepr;
+
+    /// Convert the enum value into its integer representation
+    fn to_repr(self) -> Self::Repr;
+}
+
+/// A type-safe way of storing OR-combinations of enum values.
+///
+/// This has the same layout as the C++ `QFlags<T>` so can be passed by value over the bridge.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     #[qenum]
+///     #[qflags(Alignment)]
+///     #[namespace = "my_namespace"]
+///     enum AlignmentFlag {
+///         Left = 1,
+///         Right = 2,
+///         Top = 4,
+///     }
+/// }
+///
+/// use qobject::{Alignment, AlignmentFlag};
+///
+/// let alignment: Alignment = AlignmentFlag::Left | AlignmentFlag::Top;
+/// assert!(alignment.test_flag(AlignmentFlag::Top));
+/// assert!(!alignment.test_flag(AlignmentFlag::Right));
+/// ```
+#[repr(transparent)]
+pub struct QFlags<T: QFlag> {
+    repr: T::Repr,
+}
+
+impl<T: QFlag> QFlags<T> {
+    /// Construct [QFlags] with no flags set
+    pub fn empty() -> Self {
+        Self::from_bits(T::Repr::default())
+    }
+
+    /// Construct [QFlags] from the raw integer representation
+    pub fn from_bits(bits: T::Repr) -> Self {
+        Self { repr: bits }
+    }
+
+    /// Returns the raw integer representation of the flags
+    pub fn bits(&self) -> T::Repr {
+        self.repr
+    }
+
+    /// Returns true if no flags are set
+    pub fn is_empty(&self) -> bool {
+        self.repr == T::Repr::default()
+    }
+
+    /// Returns true if all of the flags in `other` are set
+    pub fn contains(&self, other: impl Into<Self>) -> bool {
+        let other = other.into().repr;
+        self.repr & other == other
+    }
+
+    /// Returns true if any of the flags in `other` are set
+    pub fn intersects(&self, other: impl Into<Self>) -> bool {
+        self.repr & other.into().repr != T::Repr::default()
+    }
+
+    /// Returns true if the flag is set
+    ///
+    /// As with C++ `QFlags::testFlag`, a flag with a value of zero is only set when no flags are set.
+    pub fn test_flag(&self, flag: T) -> bool {
+        let flag = flag.to_repr();
+        if flag == T::Repr::default() {
+            self.is_empty()
+        } else {
+            self.repr & flag == flag
+        }
+    }
+
+    /// Set all of the flags in `other`
+    pub fn insert(&mut self, other: impl Into<Self>) {
+        self.repr = self.repr | other.into().repr;
+    }
+
+    /// Clear all of the flags in `other`
+    pub fn remove(&mut self, other: impl Into<Self>) {
+        self.repr = self.repr & !other.into().repr;
+    }
+
+    /// Set or clear the flag depending on `on`
+    pub fn set_flag(&mut self, flag: T, on: bool) {
+        if on {
+            self.insert(flag);
+        } else {
+            self.remove(flag);
+        }
+    }
+}
+
+impl<T: QFlag> Clone for QFlags<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: QFlag> Copy for QFlags<T> {}
+
+impl<T: QFlag> Default for QFlags<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T: QFlag> PartialEq for QFlags<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.repr == other.repr
+    }
+}
+
+impl<T: QFlag> Eq for QFlags<T> {}
+
+impl<T: QFlag> Hash for QFlags<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.repr.hash(state);
+    }
+}
+
+impl<T: QFlag> fmt::Debug for QFlags<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("QFlags").field(&self.repr).finish()
+    }
+}
+
+impl<T: QFlag> From<T> for QFlags<T> {
+    fn from(flag: T) -> Self {
+        Self::from_bits(flag.to_repr())
+    }
+}
+
+impl<T: QFlag> FromIterator<T> for QFlags<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::empty(), |flags, flag| flags | flag)
+    }
+}
+
+impl<T: QFlag> Not for QFlags<T> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::from_bits(!self.repr)
+    }
+}
+
+macro_rules! impl_qflags_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $token:tt) => {
+        impl<T: QFlag, Rhs: Into<QFlags<T>>> $op<Rhs> for QFlags<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: Rhs) -> Self {
+                Self::from_bits(self.repr $token rhs.into().repr)
+            }
+        }
+
+        impl<T: QFlag, Rhs: Into<QFlags<T>>> $op_assign<Rhs> for QFlags<T> {
+            fn $method_assign(&mut self, rhs: Rhs) {
+                *self = *self $token rhs;
+            }
+        }
+    };
+}
+
+impl_qflags_op!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+impl_qflags_op!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+impl_qflags_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+// Safety:
+//
+// QFlags is a transparent wrapper around the integer type of the enum,
+// which matches the single `Int` member of the C++ QFlags<T>.
+unsafe impl<T: QFlag> cxx::ExternType for QFlags<T> {
+    type Id = T::TypeId;
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    enum TestFlag {
+        None = 0,
+        A = 1,
+        B = 2,
+        C = 4,
+    }
+
+    // SAFETY: TestFlag is only used from Rust, so it is not passed to C++ as a QFlags<T>
+    unsafe impl QFlag for TestFlag {
+        type TypeId = ();
+        type Repr = i32;
+
+        fn to_repr(self) -> i32 {
+            self as i32
+        }
+    }
+
+    // As generated by CXX-Qt for a #[qenum] with #[qflags(...)]
+    impl BitOr for TestFlag {
+        type Output = TestFlags;
+
+        fn bitor(self, other: Self) -> TestFlags {
+            QFlags::from(self) | other
+        }
+    }
+
+    type TestFlags = QFlags<TestFlag>;
+
+    #[test]
+    fn qflags_contains() {
+        let flags = TestFlag::A | TestFlag::B;
+        assert!(flags.contains(TestFlag::A));
+        assert!(flags.contains(TestFlag::A | TestFlag::B));
+        assert!(!flags.contains(TestFlag::A | TestFlag::C));
+        assert!(flags.intersects(TestFlag::A | TestFlag::C));
+        assert!(!flags.intersects(TestFlag::C));
+        // Every set of flags contains the empty set
+        assert!(flags.contains(TestFlag::None));
+        assert!(TestFlags::empty().contains(TestFlag::None));
+    }
+
+    #[test]
+    fn qflags_test_flag() {
+        let flags = TestFlag::A | TestFlag::C;
+        assert!(flags.test_flag(TestFlag::A));
+        assert!(!flags.test_flag(TestFlag::B));
+        assert!(flags.test_flag(TestFlag::C));
+
+        // A zero flag is only set when no flags are set
+        assert!(!flags.test_flag(TestFlag::None));
+        assert!(TestFlags::empty().test_flag(TestFlag::None));
+    }
+
+    #[test]
+    fn qflags_insert_remove() {
+        let mut flags = TestFlags::empty();
+        assert!(flags.is_empty());
+
+        flags.insert(TestFlag::A | TestFlag::B);
+        assert_eq!(flags.bits(), 3);
+        flags.remove(TestFlag::A);
+        assert_eq!(flags.bits(), 2);
+        // Removing a flag which is not set has no effect
+        flags.remove(TestFlag::C);
+        assert_eq!(flags.bits(), 2);
+
+        flags.set_flag(TestFlag::C, true);
+        assert_eq!(flags.bits(), 6);
+        flags.set_flag(TestFlag::B, false);
+        assert_eq!(flags.bits(), 4);
+        flags.set_flag(TestFlag::B, false);
+        assert_eq!(flags.bits(), 4);
+    }
+
+    #[test]
+    fn qflags_operators() {
+        let flags = TestFlag::A | TestFlag::B;
+        assert_eq!(flags.bits(), 3);
+        assert_eq!((flags & TestFlag::B).bits(), 2);
+        assert_eq!((flags & TestFlag::C).bits(), 0);
+        assert_eq!((flags ^ (TestFlag::B | TestFlag::C)).bits(), 5);
+        assert_eq!((!flags).bits(), !3);
+        assert_eq!(!!flags, flags);
+
+        let mut flags = TestFlags::from(TestFlag::A);
+        flags |= TestFlag::C;
+        assert_eq!(flags.bits(), 5);
+        flags &= TestFlag::C | TestFlag::B;
+        assert_eq!(flags.bits(), 4);
+        flags ^= TestFlag::A | TestFlag::C;
+        assert_eq!(flags.bits(), 1);
+    }
+
+    #[test]
+    fn qflags_from() {
+        assert_eq!(TestFlags::from(TestFlag::B).bits(), 2);
+        assert_eq!(TestFlags::from(TestFlag::None), TestFlags::empty());
+        assert_eq!(TestFlags::from_bits(6).bits(), 6);
+        assert_eq!(TestFlags::default(), TestFlags::empty());
+
+        let flags: TestFlags = [TestFlag::A, TestFlag::C, TestFlag::A]
+            .into_iter()
+            .collect();
+        assert_eq!(flags.bits(), 5);
+        let flags: TestFlags = core::iter::empty().collect();
+        assert!(flags.is_empty());
+    }
+}
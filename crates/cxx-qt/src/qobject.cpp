@@ -0,0 +1,84 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt/qobject.h"
+
+#include <QtCore/QString>
+
+namespace rust {
+namespace cxxqt1 {
+
+bool
+qobjectAdoptChild(QObject& parent, QObject& child)
+{
+  // Qt cannot parent objects across threads, in which case setParent
+  // prints a warning and leaves the child without a parent
+  if (child.thread() != parent.thread()) {
+    return false;
+  }
+
+  child.setParent(&parent);
+  return true;
+}
+
+QObject*
+qobjectChild(const QObject& obj, ::std::size_t index)
+{
+  return obj.children().at(static_cast<int>(index));
+}
+
+::std::size_t
+qobjectChildCount(const QObject& obj)
+{
+  return static_cast<::std::size_t>(obj.children().size());
+}
+
+void
+qobjectDeleteLater(QObject& obj)
+{
+  obj.deleteLater();
+}
+
+QObject*
+qobjectFindChild(const QObject& obj, ::rust::Str name)
+{
+  return obj.findChild<QObject*>(
+    QString::fromUtf8(name.data(), static_cast<int>(name.size())));
+}
+
+::rust::Vec<::std::size_t>
+qobjectFindChildren(const QObject& obj, ::rust::Str name)
+{
+  ::rust::Vec<::std::size_t> children;
+  for (QObject* child : obj.findChildren<QObject*>(
+         QString::fromUtf8(name.data(), static_cast<int>(name.size())))) {
+    children.push_back(reinterpret_cast<::std::size_t>(child));
+  }
+  return children;
+}
+
+void
+qobjectOnDestroyed(const QObject& obj,
+                   ::rust::Fn<void(::std::size_t)> callback)
+{
+  QObject::connect(&obj, &QObject::destroyed, [callback](QObject* object) {
+    callback(reinterpret_cast<::std::size_t>(object));
+  });
+}
+
+QObject*
+qobjectParent(const QObject& obj)
+{
+  return obj.parent();
+}
+
+void
+qobjectSetParent(QObject& obj, QObject* parent)
+{
+  obj.setParent(parent);
+}
+
+}
+}
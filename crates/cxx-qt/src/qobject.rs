@@ -0,0 +1,219 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use crate::Upcast;
+use core::pin::Pin;
+use cxx::{memory::UniquePtrTarget, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt/qobject.h");
+
+        /// The QObject class is the base class of all Qt objects.
+        ///
+        /// Every CXX-Qt QObject can be upcast to this type with [Upcast](crate::Upcast),
+        /// and downcast from it with [Downcast](crate::Downcast).
+        /// Further methods which use Qt types such as `QString` and `QVariant`
+        /// are available in cxx-qt-lib.
+        type QObject;
+
+        /// If block is true, signals emitted by this object are blocked
+        /// (i.e., emitting a signal will not invoke anything connected to it).
+        /// If block is false, no such blocking will occur.
+        ///
+        /// The return value is the previous value of [QObject::signals_blocked].
+        #[rust_name = "block_signals"]
+        fn blockSignals(self: Pin<&mut QObject>, block: bool) -> bool;
+
+        /// Returns true if signals are blocked; otherwise returns false.
+        ///
+        /// Signals are not blocked by default.
+        #[rust_name = "signals_blocked"]
+        fn signalsBlocked(self: &QObject) -> bool;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qobject_adopt_child"]
+        fn qobjectAdoptChild(parent: Pin<&mut QObject>, child: Pin<&mut QObject>) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_child"]
+        fn qobjectChild(obj: &QObject, index: usize) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_child_count"]
+        fn qobjectChildCount(obj: &QObject) -> usize;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_delete_later"]
+        unsafe fn qobjectDeleteLater(obj: Pin<&mut QObject>);
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_find_child"]
+        fn qobjectFindChild(obj: &QObject, name: &str) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_find_children"]
+        fn qobjectFindChildren(obj: &QObject, name: &str) -> Vec<usize>;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_on_destroyed"]
+        fn qobjectOnDestroyed(obj: &QObject, callback: fn(usize));
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_parent"]
+        fn qobjectParent(obj: &QObject) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_set_parent"]
+        unsafe fn qobjectSetParent(obj: Pin<&mut QObject>, parent: *mut QObject);
+    }
+}
+
+pub use ffi::QObject;
+
+impl QObject {
+    /// Makes the given child a child of this object, which will then delete it when it is destroyed.
+    ///
+    /// This is the safe alternative to [QObject::set_parent], as the ownership of the child
+    /// is passed from Rust to this object.
+    ///
+    /// If the child is null or lives in a different thread to this object, it cannot be adopted
+    /// and is returned as the error.
+    pub fn adopt_child<T>(self: Pin<&mut Self>, child: UniquePtr<T>) -> Result<(), UniquePtr<T>>
+    where
+        T: Upcast<QObject> + UniquePtrTarget,
+    {
+        if child.is_null() {
+            return Err(child);
+        }
+
+        let child = child.into_raw();
+        // SAFETY: the child is a valid object, as it came from a UniquePtr, and it is either owned
+        // by this object once it has been adopted or returned to a UniquePtr otherwise.
+        unsafe {
+            let qobject = Pin::new_unchecked(&mut *(T::upcast_ptr(child) as *mut QObject));
+            if ffi::qobject_adopt_child(self, qobject) {
+                Ok(())
+            } else {
+                Err(UniquePtr::from_raw(child))
+            }
+        }
+    }
+
+    /// Returns the direct children of this object.
+    pub fn children(&self) -> Vec<&QObject> {
+        self.child_ptrs()
+            .into_iter()
+            // SAFETY: children are valid for as long as their parent is borrowed
+            .filter_map(|child| unsafe { child.as_ref() })
+            .collect()
+    }
+
+    /// Returns the direct children of this object as pinned mutable references.
+    pub fn children_mut(self: Pin<&mut Self>) -> Vec<Pin<&mut QObject>> {
+        self.child_ptrs()
+            .into_iter()
+            // SAFETY: children are valid for as long as their parent is borrowed,
+            // and each child is a separate object, so they can be borrowed mutably at once.
+            .filter_map(|child| unsafe { child.as_mut().map(|child| Pin::new_unchecked(child)) })
+            .collect()
+    }
+
+    /// Schedules this object for deletion.
+    ///
+    /// The object will be deleted when control returns to the event loop.
+    ///
+    /// # Safety
+    ///
+    /// The object must not be owned by Rust, such as by a `UniquePtr`,
+    /// and must not be used after control returns to the event loop.
+    pub unsafe fn delete_later(self: Pin<&mut Self>) {
+        ffi::qobject_delete_later(self);
+    }
+
+    /// Returns the child of this object with the given object name,
+    /// or None if there is no such object.
+    ///
+    /// The search is performed recursively. If there is more than one child matching the search,
+    /// the most direct ancestor is returned.
+    pub fn find_child(&self, name: &str) -> Option<&QObject> {
+        // SAFETY: children are valid for as long as their parent is borrowed
+        unsafe { ffi::qobject_find_child(self, name).as_ref() }
+    }
+
+    /// Returns the child of this object with the given object name as a pinned mutable reference,
+    /// or None if there is no such object.
+    ///
+    /// The search is performed in the same way as [QObject::find_child].
+    pub fn find_child_mut(self: Pin<&mut Self>, name: &str) -> Option<Pin<&mut QObject>> {
+        // SAFETY: children are valid for as long as their parent is borrowed
+        unsafe {
+            ffi::qobject_find_child(&self, name)
+                .as_mut()
+                .map(|child| Pin::new_unchecked(child))
+        }
+    }
+
+    /// Returns all children of this object with the given object name, recursively.
+    pub fn find_children(&self, name: &str) -> Vec<&QObject> {
+        self.find_children_ptrs(name)
+            .into_iter()
+            // SAFETY: children are valid for as long as their parent is borrowed
+            .filter_map(|child| unsafe { child.as_ref() })
+            .collect()
+    }
+
+    /// Returns all children of this object with the given object name, recursively,
+    /// as pinned mutable references.
+    pub fn find_children_mut(self: Pin<&mut Self>, name: &str) -> Vec<Pin<&mut QObject>> {
+        self.find_children_ptrs(name)
+            .into_iter()
+            // SAFETY: children are valid for as long as their parent is borrowed,
+            // and each child is a separate object, so they can be borrowed mutably at once.
+            .filter_map(|child| unsafe { child.as_mut().map(|child| Pin::new_unchecked(child)) })
+            .collect()
+    }
+
+    /// Returns a pointer to the parent object, which is null if the object has no parent.
+    ///
+    /// This is a pointer rather than a reference, as borrowing this object does not
+    /// prevent the parent from being destroyed, which would destroy this object too.
+    pub fn parent(&self) -> *mut QObject {
+        ffi::qobject_parent(self)
+    }
+
+    /// Makes the object a child of parent, which will then delete it when it is destroyed.
+    ///
+    /// Objects owned by Rust can be passed to a parent safely with [QObject::adopt_child].
+    ///
+    /// # Safety
+    ///
+    /// The parent must be null or a valid pointer to a QObject.
+    /// If it is not null, the object must not be owned by Rust, such as by a `UniquePtr`,
+    /// as it is now owned by its parent.
+    pub unsafe fn set_parent(self: Pin<&mut Self>, parent: *mut QObject) {
+        ffi::qobject_set_parent(self, parent);
+    }
+
+    fn child_ptrs(&self) -> Vec<*mut QObject> {
+        (0..ffi::qobject_child_count(self))
+            .map(|index| ffi::qobject_child(self, index))
+            .collect()
+    }
+
+    fn find_children_ptrs(&self, name: &str) -> Vec<*mut QObject> {
+        ffi::qobject_find_children(self, name)
+            .into_iter()
+            .map(|child| child as *mut QObject)
+            .collect()
+    }
+}
+
+/// Call the given function with the address of the QObject once it is being destroyed
+pub(crate) fn on_destroyed(qobject: &QObject, callback: fn(usize)) {
+    ffi::qobject_on_destroyed(qobject, callback);
+}
@@ -0,0 +1,117 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{
+    future::{poll_fn, Future},
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use futures_core::Stream;
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use crate::QMetaObjectConnectionGuard;
+
+/// The emissions which have not been taken from a [QSignalStream] yet
+struct QSignalStreamState<T> {
+    queue: VecDeque<T>,
+    waker: Option<Waker>,
+    /// Whether the connected closure has been dropped, eg as the QObject has been destroyed
+    closed: bool,
+}
+
+/// A [Stream] of the arguments of each emission of a signal.
+///
+/// This is created by the `<signal>_stream` methods which CXX-Qt generates for signals with a
+/// `#[stream]` attribute. Each item is the argument of the signal, or a tuple of the arguments
+/// when the signal has more than one, with any references cloned into owned values.
+///
+/// Note that when this struct is dropped the signal is disconnected.
+/// The stream ends once the QObject has been destroyed.
+#[must_use = "streams do nothing unless polled"]
+pub struct QSignalStream<T> {
+    state: Arc<Mutex<QSignalStreamState<T>>>,
+    _guard: QMetaObjectConnectionGuard,
+}
+
+impl<T> QSignalStream<T> {
+    /// Create a stream, where the `connect` function connects the given sender to the signal
+    #[doc(hidden)]
+    pub fn new<F>(connect: F) -> Self
+    where
+        F: FnOnce(QSignalStreamSender<T>) -> QMetaObjectConnectionGuard,
+    {
+        let state = Arc::new(Mutex::new(QSignalStreamState {
+            queue: VecDeque::new(),
+            waker: None,
+            closed: false,
+        }));
+        let guard = connect(QSignalStreamSender {
+            state: state.clone(),
+        });
+        Self {
+            state,
+            _guard: guard,
+        }
+    }
+
+    /// Wait for the next emission of the signal.
+    ///
+    /// If the QObject has been destroyed the future resolves to [None].
+    pub fn next(&mut self) -> impl Future<Output = Option<T>> + '_ {
+        poll_fn(move |cx| Pin::new(&mut *self).poll_next(cx))
+    }
+
+    /// Wait for the next emission of the signal, taking ownership of the stream.
+    ///
+    /// The signal stays connected until the returned future is dropped.
+    pub fn into_next(mut self) -> impl Future<Output = Option<T>> {
+        poll_fn(move |cx| Pin::new(&mut self).poll_next(cx))
+    }
+}
+
+impl<T> Stream for QSignalStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(value) = state.queue.pop_front() {
+            Poll::Ready(Some(value))
+        } else if state.closed {
+            Poll::Ready(None)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// The sending half of a [QSignalStream], which is moved into the closure connected to the signal
+#[doc(hidden)]
+pub struct QSignalStreamSender<T> {
+    state: Arc<Mutex<QSignalStreamState<T>>>,
+}
+
+impl<T> QSignalStreamSender<T> {
+    /// Queue the arguments of an emission of the signal and wake the stream
+    pub fn send(&self, value: T) {
+        let mut state = self.state.lock().unwrap();
+        state.queue.push_back(value);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for QSignalStreamSender<T> {
+    fn drop(&mut self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
@@ -3,8 +3,19 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use core::{marker::PhantomData, mem::MaybeUninit, pin::Pin};
+use core::{
+    future::Future,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    pin::{pin, Pin},
+    task::{Context, Poll, Waker},
+};
 use cxx::ExternType;
+use std::{
+    sync::{Arc, Mutex},
+    task::Wake,
+    thread::{self, Thread},
+};
 use thiserror::Error;
 
 use crate::Threading;
@@ -19,6 +30,9 @@ pub enum ThreadingQueueError {
     /// Threading failed calling invokeMethod on the object
     #[error("Cannot queue function pointer as invokeMethod on object failed")]
     InvokeMethodFailed,
+    /// Blocking on the closure would deadlock as it was called from the thread of the object
+    #[error("Cannot block on queued function as it was called from the thread of the object")]
+    WouldDeadlock,
     /// Threading failed with unknown error
     #[error("Cannot queue as an unknown error occurred")]
     Unknown,
@@ -107,6 +121,61 @@ where
         T::queue(self, f)
     }
 
+    /// Queue the given closure onto the Qt event loop for this QObject and block until it has
+    /// run, returning the value from the closure
+    ///
+    /// If the QObject is destroyed before the closure runs, [ThreadingQueueError::ObjectDestroyed]
+    /// is returned.
+    ///
+    /// This must not be called from the thread the QObject lives in, as the closure could never
+    /// run while that thread is blocked. In this case [ThreadingQueueError::WouldDeadlock] is
+    /// returned instead.
+    pub fn queue_blocking<F, R>(&self, f: F) -> Result<R, crate::ThreadingQueueError>
+    where
+        F: FnOnce(Pin<&mut T>) -> R,
+        F: Send + 'static,
+        R: Send + 'static,
+    {
+        if T::is_object_thread(self) {
+            return Err(ThreadingQueueError::WouldDeadlock);
+        }
+
+        block_on(self.queue_async(f))
+    }
+
+    /// Queue the given closure onto the Qt event loop for this QObject and return a [Future]
+    /// which resolves to the value from the closure once it has run
+    ///
+    /// The closure is queued immediately, the returned [Future] only waits for the result.
+    /// If the QObject is destroyed before the closure runs, the [Future] resolves to
+    /// [ThreadingQueueError::ObjectDestroyed].
+    pub fn queue_async<F, R>(
+        &self,
+        f: F,
+    ) -> impl Future<Output = Result<R, crate::ThreadingQueueError>> + Send + 'static
+    where
+        F: FnOnce(Pin<&mut T>) -> R,
+        F: Send + 'static,
+        R: Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(QueuedState {
+            result: None,
+            waker: None,
+        }));
+        let sender = QueuedSender {
+            state: Arc::clone(&state),
+            sent: false,
+        };
+
+        // If queueing fails the closure, and therefore the sender, has already been dropped.
+        // Replace the result with the actual reason for the failure.
+        if let Err(err) = self.queue(move |qobject| sender.send(f(qobject))) {
+            state.lock().unwrap().result = Some(Err(err));
+        }
+
+        QueuedFuture { state }
+    }
+
     /// Checks whether the associated `QObject` has been destroyed.
     ///
     /// This method only confirms if the `QObject` has already been destroyed.
@@ -140,3 +209,86 @@ where
         T::is_destroyed(self)
     }
 }
+
+/// The result of a closure queued by [CxxQtThread::queue_async] shared with its [QueuedFuture]
+struct QueuedState<R> {
+    result: Option<Result<R, ThreadingQueueError>>,
+    waker: Option<Waker>,
+}
+
+/// Sends the result of a queued closure to its [QueuedFuture]
+///
+/// If this is dropped without sending, the closure never ran as the QObject was destroyed.
+struct QueuedSender<R> {
+    state: Arc<Mutex<QueuedState<R>>>,
+    sent: bool,
+}
+
+impl<R> QueuedSender<R> {
+    fn send(mut self, value: R) {
+        self.complete(Ok(value));
+        self.sent = true;
+    }
+
+    fn complete(&self, result: Result<R, ThreadingQueueError>) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            state.result = Some(result);
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<R> Drop for QueuedSender<R> {
+    fn drop(&mut self) {
+        if !self.sent {
+            self.complete(Err(ThreadingQueueError::ObjectDestroyed));
+        }
+    }
+}
+
+/// A [Future] which resolves once a queued closure has run, see [CxxQtThread::queue_async]
+struct QueuedFuture<R> {
+    state: Arc<Mutex<QueuedState<R>>>,
+}
+
+impl<R> Future for QueuedFuture<R> {
+    type Output = Result<R, ThreadingQueueError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Wakes a thread which is blocked in [block_on]
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run the given future to completion on the current thread
+fn block_on<Fut: Future>(future: Fut) -> Fut::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
@@ -643,6 +643,45 @@ impl QtBuild {
         Err(())
     }
 
+    /// Find the metatypes.json files of the linked Qt modules
+    ///
+    /// These are passed to qmltyperegistrar as foreign types, so that Qt types can be referred to
+    /// by QML_FOREIGN, QML_EXTENDED or QML_ATTACHED and as types of properties.
+    fn qt_metatypes_json(&self) -> Vec<String> {
+        // Qt 6.5 and later install the metatypes into the arch data directory, before that
+        // and in Qt 5 they are located within the library directory
+        let metatypes_dirs = ["QT_INSTALL_ARCHDATA", "QT_INSTALL_LIBS"]
+            .map(|var| PathBuf::from(self.qmake_query(var)).join("metatypes"));
+        let prefixes = self
+            .qt_modules
+            .iter()
+            .map(|qt_module| {
+                format!(
+                    "qt{}{}_",
+                    self.version.major,
+                    qt_module.to_ascii_lowercase()
+                )
+            })
+            .collect::<Vec<String>>();
+
+        metatypes_dirs
+            .iter()
+            .filter_map(|dir| std::fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .map(|name| name.to_string_lossy())
+                    .is_some_and(|name| {
+                        name.ends_with("metatypes.json")
+                            && prefixes.iter().any(|prefix| name.starts_with(prefix))
+                    })
+            })
+            .map(|path| path.to_string_lossy().to_string())
+            .collect()
+    }
+
     /// Run moc on a C++ header file and save the output into [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
     /// The return value contains the path to the generated C++ file, which can then be passed to [cc::Build::files](https://docs.rs/cc/latest/cc/struct.Build.html#method.file),
     /// as well as the path to the generated metatypes.json file, which can be passed to [register_qml_module](Self::register_qml_module).
@@ -927,6 +966,10 @@ prefer :/qt/qml/{qml_uri_dirs}/
                 "-o".to_string(),
                 qmltyperegistrar_output_path.to_string_lossy().to_string(),
             ];
+            let foreign_types = self.qt_metatypes_json();
+            if !foreign_types.is_empty() {
+                args.push(format!("--foreign-types={}", foreign_types.join(",")));
+            }
             args.extend(metatypes_json);
             let cmd = Command::new(self.qmltyperegistrar_executable.as_ref().unwrap())
                 .args(args)
@@ -126,7 +126,7 @@ pub mod qobject {
     // Create Rust bindings for C++ functions of the base class (QAbstractItemModel)
     extern "RustQt" {
         /// Inherited beginInsertRows from the base class
-        #[inherit]
+        #[inherit(protected)]
         #[cxx_name = "beginInsertRows"]
         unsafe fn begin_insert_rows(
             self: Pin<&mut CustomBaseClass>,
@@ -135,12 +135,12 @@ pub mod qobject {
             last: i32,
         );
         /// Inherited endInsertRows from the base class
-        #[inherit]
+        #[inherit(protected)]
         #[cxx_name = "endInsertRows"]
         unsafe fn end_insert_rows(self: Pin<&mut CustomBaseClass>);
 
         /// Inherited beginRemoveRows from the base class
-        #[inherit]
+        #[inherit(protected)]
         #[cxx_name = "beginRemoveRows"]
         unsafe fn begin_remove_rows(
             self: Pin<&mut CustomBaseClass>,
@@ -149,16 +149,16 @@ pub mod qobject {
             last: i32,
         );
         /// Inherited endRemoveRows from the base class
-        #[inherit]
+        #[inherit(protected)]
         #[cxx_name = "endRemoveRows"]
         unsafe fn end_remove_rows(self: Pin<&mut CustomBaseClass>);
 
         /// Inherited beginResetModel from the base class
-        #[inherit]
+        #[inherit(protected)]
         #[cxx_name = "beginResetModel"]
         unsafe fn begin_reset_model(self: Pin<&mut CustomBaseClass>);
         /// Inherited endResetModel from the base class
-        #[inherit]
+        #[inherit(protected)]
         #[cxx_name = "endResetModel"]
         unsafe fn end_reset_model(self: Pin<&mut CustomBaseClass>);
     }
@@ -12,6 +12,7 @@
 
 #include "basic_cxx_qt/src/data.cxxqt.h"
 #include "basic_cxx_qt/src/empty.cxxqt.h"
+#include "basic_cxx_qt/src/executor.cxxqt.h"
 #include "basic_cxx_qt/src/lib.cxxqt.h"
 #include "basic_cxx_qt/src/naming.cxxqt.h"
 #include "basic_cxx_qt/src/types.cxxqt.h"
@@ -129,6 +130,134 @@ private Q_SLOTS:
     QTRY_COMPARE(obj.fetchUpdateCallCount(), 100);
   }
 
+  // CXX-Qt allows Rust code to block a thread until a queued request returns
+  // its value
+  void test_queue_blocking()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.setNumber(2);
+    obj.queueBlockingTest();
+    QCOMPARE(obj.fetchQueuedResult(), 0);
+    QTRY_COMPARE(obj.fetchQueuedResult(), 4);
+  }
+
+  // CXX-Qt refuses to block the thread of the object on a queued request
+  void test_queue_blocking_would_deadlock()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QVERIFY(obj.queueBlockingWouldDeadlock());
+    QTest::qWait(10);
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+  }
+
+  // CXX-Qt allows Rust code to wait on a future for the value of a queued
+  // request
+  void test_queue_async()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.setNumber(3);
+    obj.queueAsyncTest();
+    QCOMPARE(obj.fetchQueuedResult(), 0);
+    QTRY_COMPARE(obj.fetchQueuedResult(), 9);
+  }
+
+  // CXX-Qt resolves the future of a queued request with an error if the object
+  // is destroyed before the request runs
+  void test_queue_async_object_destroyed()
+  {
+    const auto destroyed = cxx_qt::my_object::queueDestroyedCount();
+    {
+      cxx_qt::my_object::MyObject obj;
+      obj.queueAsyncDestroyedTest();
+    }
+
+    QTRY_COMPARE(cxx_qt::my_object::queueDestroyedCount(), destroyed + 1);
+  }
+
+  // CXX-Qt allows Rust futures to wait on the Qt event loop until they are
+  // woken
+  void test_executor_spawn_and_wake()
+  {
+    cxx_qt::executor::ExecutorObject obj;
+    obj.spawnTask();
+    QCOMPARE(obj.startedCount(), 0);
+    QTRY_COMPARE(obj.startedCount(), 1);
+
+    // The task waits until the gate is opened
+    QTest::qWait(10);
+    QCOMPARE(obj.finishedCount(), 0);
+
+    const auto dropped = cxx_qt::executor::droppedTaskCount();
+    obj.openGate();
+    QTRY_COMPARE(obj.finishedCount(), 1);
+    QCOMPARE(cxx_qt::executor::droppedTaskCount(), dropped + 1);
+  }
+
+  // CXX-Qt drops the unfinished futures of a QObject once it is destroyed
+  void test_executor_drop_on_destroy()
+  {
+    const auto dropped = cxx_qt::executor::droppedTaskCount();
+    {
+      cxx_qt::executor::ExecutorObject obj;
+      obj.spawnTask();
+      obj.spawnTask();
+      QTRY_COMPARE(obj.startedCount(), 2);
+      QCOMPARE(cxx_qt::executor::droppedTaskCount(), dropped);
+    }
+
+    QCOMPARE(cxx_qt::executor::droppedTaskCount(), dropped + 2);
+  }
+
+  // CXX-Qt resolves the QFuture of an async invokable with its result
+  void test_executor_async_invokable()
+  {
+    cxx_qt::executor::ExecutorObject obj;
+    QSignalSpy finishedSpy(&obj,
+                           &cxx_qt::executor::ExecutorObject::gatedValueFinished);
+    auto future = obj.gatedValue(42);
+    QVERIFY(!future.isFinished());
+    QTRY_COMPARE(obj.startedCount(), 1);
+
+    obj.openGate();
+    QTRY_VERIFY(future.isFinished());
+    QVERIFY(!future.isCanceled());
+    QCOMPARE(future.result(), 42);
+    QCOMPARE(obj.finishedCount(), 1);
+    QCOMPARE(finishedSpy.count(), 1);
+    QCOMPARE(finishedSpy.at(0).at(0).toInt(), 42);
+  }
+
+  // CXX-Qt cancels the QFuture of an async invokable if its QObject is destroyed
+  void test_executor_async_invokable_cancel_on_destroy()
+  {
+    const auto dropped = cxx_qt::executor::droppedTaskCount();
+    QFuture<::std::int32_t> future;
+    {
+      cxx_qt::executor::ExecutorObject obj;
+      future = obj.gatedValue(42);
+      QTRY_COMPARE(obj.startedCount(), 1);
+    }
+
+    QCOMPARE(cxx_qt::executor::droppedTaskCount(), dropped + 1);
+    QVERIFY(future.isFinished());
+    QVERIFY(future.isCanceled());
+  }
+
+  // CXX-Qt does not move a QObject to another thread while it has futures
+  void test_executor_reject_move_to_thread()
+  {
+    cxx_qt::executor::ExecutorObject obj;
+    QVERIFY(obj.moveToCurrentThread());
+
+    obj.spawnTask();
+    QTRY_COMPARE(obj.startedCount(), 1);
+    QVERIFY(!obj.moveToCurrentThread());
+
+    obj.openGate();
+    QTRY_COMPARE(obj.finishedCount(), 1);
+    QVERIFY(obj.moveToCurrentThread());
+  }
+
   // CXX-Qt types are exposed to C++ correctly
   void test_primitive_types()
   {
@@ -0,0 +1,154 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge(namespace = "cxx_qt::executor")]
+mod qobject {
+    unsafe extern "RustQt" {
+        #[qobject]
+        type ExecutorObject = super::ExecutorObjectRust;
+    }
+
+    impl cxx_qt::Threading for ExecutorObject {}
+
+    // Note that we are only testing with C++ here so only async methods need qinvokable
+    unsafe extern "RustQt" {
+        #[cxx_name = "spawnTask"]
+        fn spawn_task(self: &ExecutorObject);
+
+        #[cxx_name = "openGate"]
+        fn open_gate(self: Pin<&mut ExecutorObject>);
+
+        #[cxx_name = "startedCount"]
+        fn started_count(self: &ExecutorObject) -> i32;
+
+        #[cxx_name = "finishedCount"]
+        fn finished_count(self: &ExecutorObject) -> i32;
+
+        #[cxx_name = "moveToCurrentThread"]
+        fn move_to_current_thread(self: Pin<&mut ExecutorObject>) -> bool;
+
+        #[qinvokable]
+        #[cxx_name = "gatedValue"]
+        async fn gated_value(self: &ExecutorObject, value: i32) -> i32;
+    }
+
+    extern "Rust" {
+        #[cxx_name = "droppedTaskCount"]
+        fn dropped_task_count() -> i32;
+    }
+}
+
+use core::{
+    cell::RefCell,
+    future::poll_fn,
+    pin::Pin,
+    sync::atomic::{AtomicI32, Ordering},
+    task::{Poll, Waker},
+};
+use cxx_qt::{CxxQtType, LocalQObject, Threading, Upcast};
+use cxx_qt_lib::{QObjectExt, QThread};
+use std::rc::Rc;
+
+/// The number of tasks which have been dropped, whether they finished or not
+static DROPPED_TASKS: AtomicI32 = AtomicI32::new(0);
+
+fn dropped_task_count() -> i32 {
+    DROPPED_TASKS.load(Ordering::Relaxed)
+}
+
+/// Counts the task it is held by as dropped once it is dropped
+struct DropCounter;
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        DROPPED_TASKS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// A gate which tasks wait on until it is opened
+#[derive(Default)]
+struct Gate {
+    open: bool,
+    wakers: Vec<Waker>,
+}
+
+/// Wait until the given gate has been opened
+async fn wait_for_gate(gate: &RefCell<Gate>) {
+    poll_fn(|cx| {
+        let mut gate = gate.borrow_mut();
+        if gate.open {
+            Poll::Ready(())
+        } else {
+            gate.wakers.push(cx.waker().clone());
+            Poll::Pending
+        }
+    })
+    .await;
+}
+
+#[derive(Default)]
+pub struct ExecutorObjectRust {
+    gate: Rc<RefCell<Gate>>,
+    started: i32,
+    finished: i32,
+}
+
+impl qobject::ExecutorObject {
+    fn spawn_task(&self) {
+        self.qt_thread()
+            .spawn_local(|qobject| async move {
+                let _counter = DropCounter;
+                let gate = qobject.with(|mut qobject| {
+                    qobject.as_mut().rust_mut().started += 1;
+                    qobject.gate.clone()
+                });
+
+                wait_for_gate(&gate).await;
+
+                qobject.with(|qobject| qobject.rust_mut().finished += 1);
+            })
+            .unwrap();
+    }
+
+    async fn gated_value(qobject: LocalQObject<Self>, value: i32) -> i32 {
+        let _counter = DropCounter;
+        let gate = qobject.with(|mut qobject| {
+            qobject.as_mut().rust_mut().started += 1;
+            qobject.gate.clone()
+        });
+
+        wait_for_gate(&gate).await;
+
+        qobject.with(|qobject| qobject.rust_mut().finished += 1);
+        value
+    }
+
+    fn open_gate(self: Pin<&mut Self>) {
+        let wakers = {
+            let mut gate = self.gate.borrow_mut();
+            gate.open = true;
+            std::mem::take(&mut gate.wakers)
+        };
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    fn started_count(&self) -> i32 {
+        self.started
+    }
+
+    fn finished_count(&self) -> i32 {
+        self.finished
+    }
+
+    fn move_to_current_thread(self: Pin<&mut Self>) -> bool {
+        // SAFETY: the object already lives in the current thread, so it is not moved
+        unsafe {
+            let thread = Pin::new_unchecked(&mut *QThread::current_thread());
+            self.upcast_pin().move_to_thread(thread)
+        }
+    }
+}
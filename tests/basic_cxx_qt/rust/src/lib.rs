@@ -6,6 +6,7 @@
 
 mod data;
 mod empty;
+mod executor;
 mod naming;
 mod types;
 
@@ -44,24 +45,84 @@ mod qobject {
         #[cxx_name = "queueTestMultiThread"]
         fn queue_test_multi_thread(self: Pin<&mut MyObject>);
 
+        #[cxx_name = "queueBlockingTest"]
+        fn queue_blocking_test(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "queueBlockingWouldDeadlock"]
+        fn queue_blocking_would_deadlock(self: Pin<&mut MyObject>) -> bool;
+
+        #[cxx_name = "queueAsyncTest"]
+        fn queue_async_test(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "queueAsyncDestroyedTest"]
+        fn queue_async_destroyed_test(self: Pin<&mut MyObject>);
+
         #[cxx_name = "fetchUpdateCallCount"]
         fn fetch_update_call_count(self: &MyObject) -> i32;
 
+        #[cxx_name = "fetchQueuedResult"]
+        fn fetch_queued_result(self: &MyObject) -> i32;
+
         #[cfg(not(target_family = "wasm"))]
         #[cxx_name = "throwException"]
         fn throw_exception(self: &MyObject) -> Result<i32>;
     }
+
+    extern "Rust" {
+        #[cxx_name = "queueDestroyedCount"]
+        fn queue_destroyed_count() -> i32;
+    }
 }
 
-use core::pin::Pin;
-use cxx_qt::{CxxQtType, Threading};
+use core::{
+    future::Future,
+    pin::{pin, Pin},
+    sync::atomic::{AtomicI32, Ordering},
+    task::{Context, Poll, Waker},
+};
+use cxx_qt::{CxxQtType, Threading, ThreadingQueueError};
 use cxx_qt_lib::QString;
+use std::{
+    sync::Arc,
+    task::Wake,
+    thread::{self, Thread},
+};
+
+/// The number of queued closures which failed as their QObject was destroyed
+static QUEUE_DESTROYED: AtomicI32 = AtomicI32::new(0);
+
+fn queue_destroyed_count() -> i32 {
+    QUEUE_DESTROYED.load(Ordering::Relaxed)
+}
+
+/// Wakes the thread which is blocked in [block_on]
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run the given future to completion on the current thread
+fn block_on<Fut: Future>(future: Fut) -> Fut::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
 
 pub struct MyObjectRust {
     number: i32,
     string: QString,
 
     pub(crate) update_call_count: i32,
+    queued_result: i32,
 }
 
 impl Default for MyObjectRust {
@@ -70,6 +131,7 @@ impl Default for MyObjectRust {
             number: 0,
             string: QString::from(""),
             update_call_count: 0,
+            queued_result: 0,
         }
     }
 }
@@ -126,10 +188,66 @@ impl qobject::MyObject {
         );
     }
 
+    fn queue_blocking_test(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        // The worker thread blocks until the closure has run on the Qt event loop,
+        // then queues the value it received back onto the object
+        thread::spawn(move || {
+            let value = qt_thread
+                .queue_blocking(|qobject| qobject.number() * 2)
+                .unwrap();
+            qt_thread
+                .queue(move |qobject| {
+                    qobject.rust_mut().queued_result = value;
+                })
+                .unwrap();
+        });
+    }
+
+    fn queue_blocking_would_deadlock(self: Pin<&mut Self>) -> bool {
+        let qt_thread = self.qt_thread();
+        matches!(
+            qt_thread.queue_blocking(|qobject| {
+                qobject.rust_mut().update_call_count += 1;
+            }),
+            Err(ThreadingQueueError::WouldDeadlock)
+        )
+    }
+
+    fn queue_async_test(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        let future = qt_thread.queue_async(|qobject| qobject.number() * 3);
+        thread::spawn(move || {
+            let value = block_on(future).unwrap();
+            qt_thread
+                .queue(move |qobject| {
+                    qobject.rust_mut().queued_result = value;
+                })
+                .unwrap();
+        });
+    }
+
+    fn queue_async_destroyed_test(self: Pin<&mut Self>) {
+        // The closure cannot run before the object is destroyed,
+        // as the object is destroyed before the Qt event loop runs again
+        let future = self
+            .qt_thread()
+            .queue_async(|qobject| qobject.rust_mut().update_call_count += 1);
+        thread::spawn(move || {
+            if let Err(ThreadingQueueError::ObjectDestroyed) = block_on(future) {
+                QUEUE_DESTROYED.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+    }
+
     fn fetch_update_call_count(&self) -> i32 {
         self.update_call_count
     }
 
+    fn fetch_queued_result(&self) -> i32 {
+        self.queued_result
+    }
+
     #[cfg(not(target_family = "wasm"))]
     fn throw_exception(&self) -> Result<i32, String> {
         Err("RustException".to_string())
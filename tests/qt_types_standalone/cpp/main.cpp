@@ -24,8 +24,10 @@
 #include "qmap.h"
 #include "qmargins.h"
 #include "qmarginsf.h"
+#include "qmetaobject.h"
 #include "qmetaobjectconnection.h"
 #include "qmodelindex.h"
+#include "qobject.h"
 #include "qpen.h"
 #include "qpersistentmodelindex.h"
 #include "qpoint.h"
@@ -42,7 +44,9 @@
 #include "qsizef.h"
 #include "qstring.h"
 #include "qstringlist.h"
+#include "qthread.h"
 #include "qtime.h"
+#include "qtimer.h"
 #include "qtimezone.h"
 #include "qurl.h"
 #include "qvariant.h"
@@ -79,8 +83,10 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QMapTest));
   runTest(QScopedPointer<QObject>(new QMarginsTest));
   runTest(QScopedPointer<QObject>(new QMarginsFTest));
+  runTest(QScopedPointer<QObject>(new QMetaObjectTest));
   runTest(QScopedPointer<QObject>(new QMetaObjectConnectionTest));
   runTest(QScopedPointer<QObject>(new QModelIndexTest));
+  runTest(QScopedPointer<QObject>(new QObjectTest));
   runTest(QScopedPointer<QObject>(new QPersistentModelIndexTest));
   runTest(QScopedPointer<QObject>(new QPointTest));
   runTest(QScopedPointer<QObject>(new QPointFTest));
@@ -93,7 +99,9 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QSizeFTest));
   runTest(QScopedPointer<QObject>(new QStringTest));
   runTest(QScopedPointer<QObject>(new QStringListTest));
+  runTest(QScopedPointer<QObject>(new QThreadTest));
   runTest(QScopedPointer<QObject>(new QTimeTest));
+  runTest(QScopedPointer<QObject>(new QTimerTest));
   runTest(QScopedPointer<QObject>(new QTimeZoneTest));
   runTest(QScopedPointer<QObject>(new QUrlTest));
   runTest(QScopedPointer<QObject>(new QVariantTest));
@@ -0,0 +1,139 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QTimer>
+#include <QtTest/QSignalSpy>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qmetaobject.cxx.h"
+
+class QMetaObjectTestObject : public QObject
+{
+  Q_OBJECT
+  Q_CLASSINFO("author", "KDAB")
+  Q_PROPERTY(int value READ value WRITE setValue NOTIFY valueChanged)
+  Q_PROPERTY(int constant READ constant CONSTANT)
+
+public:
+  enum class Colour
+  {
+    Red,
+    Green,
+  };
+  Q_ENUM(Colour)
+
+  int value() const { return m_value; }
+  void setValue(int value)
+  {
+    if (m_value != value) {
+      m_value = value;
+      Q_EMIT valueChanged();
+    }
+  }
+
+  int constant() const { return 42; }
+
+Q_SIGNALS:
+  void valueChanged();
+
+private:
+  int m_value = 0;
+};
+
+class QMetaObjectTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void className()
+  {
+    QMetaObjectTestObject obj;
+    QCOMPARE(meta_object_class_name(obj),
+             QStringLiteral("QMetaObjectTestObject"));
+    QCOMPARE(meta_object_super_class_name(obj), QStringLiteral("QObject"));
+
+    QObject base;
+    QCOMPARE(meta_object_super_class_name(base), QString());
+    QVERIFY(meta_object_inherits(obj, base));
+    QVERIFY(!meta_object_inherits(base, obj));
+  }
+
+  void classInfo()
+  {
+    QMetaObjectTestObject obj;
+    QCOMPARE(meta_object_class_info(obj, QStringLiteral("author")),
+             QStringLiteral("KDAB"));
+    QCOMPARE(meta_object_class_info(obj, QStringLiteral("missing")),
+             QString());
+  }
+
+  void enumerator()
+  {
+    QMetaObjectTestObject obj;
+    QCOMPARE(meta_object_enum_key(obj, "Colour", 1), QStringLiteral("Green"));
+    QCOMPARE(meta_object_enum_key(obj, "Colour", 2), QString());
+    QCOMPARE(meta_object_enum_key(obj, "Missing", 0), QString());
+  }
+
+  void signalsAndProperties()
+  {
+    QMetaObjectTestObject obj;
+    QVERIFY(meta_object_signal_signatures(obj).contains(
+      QStringLiteral("valueChanged()")));
+    // The properties and signals of the super classes are included
+    QVERIFY(meta_object_signal_signatures(obj).contains(
+      QStringLiteral("destroyed(QObject*)")));
+    QCOMPARE(meta_object_property_names(obj),
+             QStringList({ QStringLiteral("objectName"),
+                           QStringLiteral("value"),
+                           QStringLiteral("constant") }));
+
+    QCOMPARE(meta_object_property_notify_signal(obj, "value"),
+             QStringLiteral("valueChanged()"));
+    QCOMPARE(meta_object_property_notify_signal(obj, "constant"), QString());
+  }
+
+  void readWriteProperty()
+  {
+    QMetaObjectTestObject obj;
+    QSignalSpy spy(&obj, &QMetaObjectTestObject::valueChanged);
+    QCOMPARE(meta_property_read_int(obj, "value"), 0);
+
+    QVERIFY(meta_property_write_int(obj, "value", 2));
+    QCOMPARE(obj.value(), 2);
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(meta_property_read_int(obj, "value"), 2);
+
+    // Constant properties have no setter
+    QCOMPARE(meta_property_read_int(obj, "constant"), 42);
+    QVERIFY(!meta_property_write_int(obj, "constant", 1));
+    QCOMPARE(meta_property_read_int(obj, "constant"), 42);
+
+    // Unknown properties can neither be read nor written
+    QCOMPARE(meta_property_read_int(obj, "missing"), -1);
+    QVERIFY(!meta_property_write_int(obj, "missing", 1));
+  }
+
+  void readWritePropertyOfOtherClass()
+  {
+    QMetaObjectTestObject obj;
+    QTimer timer;
+
+    QVERIFY(meta_property_read_other(obj, obj, "value"));
+    QVERIFY(!meta_property_read_other(obj, timer, "value"));
+
+    QVERIFY(meta_property_write_other(obj, obj, "value", 3));
+    QCOMPARE(obj.value(), 3);
+    QVERIFY(!meta_property_write_other(obj, timer, "value", 4));
+
+    // Properties of a super class can be used with its sub classes
+    QObject base;
+    QVERIFY(meta_property_read_other(base, timer, "objectName"));
+    QVERIFY(!meta_property_read_other(timer, base, "objectName"));
+  }
+};
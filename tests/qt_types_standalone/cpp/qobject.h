@@ -0,0 +1,133 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+#include <QtCore/QThread>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qobject.cxx.h"
+
+class QObjectTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void connectDynamic()
+  {
+    const auto liveSlots = live_dynamic_slots();
+    QObject obj;
+    auto connection =
+      connect_dynamic_qobject(obj, "objectNameChanged(QString)");
+    QCOMPARE(live_dynamic_slots(), liveSlots + 1);
+    // The receiver of the closure is not a child of the sender
+    QVERIFY(obj.children().isEmpty());
+
+    obj.setObjectName(QStringLiteral("name"));
+    QCOMPARE(dynamic_connection_calls(*connection), 1);
+    QCOMPARE(dynamic_connection_last_name(*connection),
+             QStringLiteral("name"));
+
+    // Dropping the guard disconnects and deletes the receiver
+    disconnect_dynamic_connection(*connection);
+    obj.setObjectName(QStringLiteral("other"));
+    QCOMPARE(dynamic_connection_calls(*connection), 1);
+    QTRY_COMPARE(live_dynamic_slots(), liveSlots);
+  }
+
+  void connectDynamicRelease()
+  {
+    const auto liveSlots = live_dynamic_slots();
+    auto obj = new QObject;
+    auto connection =
+      connect_dynamic_qobject(*obj, "objectNameChanged(QString)");
+
+    // A released connection stays connected until the sender is destroyed
+    release_dynamic_connection(*connection);
+    obj->setObjectName(QStringLiteral("name"));
+    QCOMPARE(dynamic_connection_calls(*connection), 1);
+    QCOMPARE(live_dynamic_slots(), liveSlots + 1);
+
+    delete obj;
+    QTRY_COMPARE(live_dynamic_slots(), liveSlots);
+  }
+
+  void connectDynamicOtherThread()
+  {
+    const auto liveSlots = live_dynamic_slots();
+    QThread thread;
+    thread.start();
+
+    QObject obj;
+    obj.moveToThread(&thread);
+    auto connection =
+      connect_dynamic_qobject(obj, "objectNameChanged(QString)");
+
+    QMetaObject::invokeMethod(
+      &obj,
+      [&obj]() { obj.setObjectName(QStringLiteral("name")); },
+      Qt::BlockingQueuedConnection);
+    QCOMPARE(dynamic_connection_calls(*connection), 1);
+    QCOMPARE(dynamic_connection_last_name(*connection),
+             QStringLiteral("name"));
+
+    // The receiver is deleted by the event loop of the thread of the sender
+    disconnect_dynamic_connection(*connection);
+    QTRY_COMPARE(live_dynamic_slots(), liveSlots);
+
+    thread.quit();
+    QVERIFY(thread.wait());
+  }
+
+  void connectDynamicInvalidSignal()
+  {
+    const auto liveSlots = live_dynamic_slots();
+    QObject obj;
+    QTest::ignoreMessage(
+      QtWarningMsg,
+      "Could not connect as \"notASignal(int)\" is not a signal of QObject");
+    auto connection = connect_dynamic_qobject(obj, "notASignal(int)");
+    QCOMPARE(live_dynamic_slots(), liveSlots);
+  }
+
+  void invokeMethod()
+  {
+    QPointer<QObject> obj(new QObject);
+    QVERIFY(invoke_qobject_missing_method(*obj));
+
+    QVERIFY(invoke_qobject_void_method(*obj, "deleteLater"));
+    QTRY_VERIFY(obj.isNull());
+  }
+
+  void children()
+  {
+    QObject parent;
+    auto child = new QObject(&parent);
+    auto grandchild = new QObject(child);
+    QVERIFY(qobject_parent_is(*child, parent));
+    QVERIFY(qobject_parent_is(*grandchild, *child));
+    QVERIFY(!qobject_parent_is(parent, parent));
+
+    rename_qobject_children(parent, QStringLiteral("child"));
+    QCOMPARE(child->objectName(), QStringLiteral("child"));
+    QCOMPARE(grandchild->objectName(), QString());
+
+    grandchild->setObjectName(QStringLiteral("child"));
+    QVERIFY(
+      rename_qobject_found_child(parent, "child", QStringLiteral("found")));
+    QCOMPARE(child->objectName(), QStringLiteral("found"));
+    QVERIFY(
+      !rename_qobject_found_child(parent, "missing", QStringLiteral("found")));
+
+    child->setObjectName(QStringLiteral("child"));
+    QCOMPARE(rename_qobject_found_children(
+               parent, "child", QStringLiteral("renamed")),
+             static_cast<::std::size_t>(2));
+    QCOMPARE(child->objectName(), QStringLiteral("renamed"));
+    QCOMPARE(grandchild->objectName(), QStringLiteral("renamed"));
+  }
+};
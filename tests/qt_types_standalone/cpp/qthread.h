@@ -0,0 +1,69 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QThread>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qthread.cxx.h"
+
+class QThreadTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void currentThread()
+  {
+    QVERIFY(is_current_qthread(*QThread::currentThread()));
+
+    auto thread = construct_qthread();
+    QVERIFY(!is_current_qthread(*thread));
+  }
+
+  void startAndStop()
+  {
+    auto thread = construct_qthread();
+    QVERIFY(!thread->isRunning());
+    QVERIFY(!thread->isFinished());
+
+    start_qthread(*thread);
+    QTRY_VERIFY(thread->isRunning());
+
+    QVERIFY(stop_qthread(*thread));
+    QVERIFY(!thread->isRunning());
+    QVERIFY(thread->isFinished());
+  }
+
+  void moveToThread()
+  {
+    auto thread = construct_qthread();
+    start_qthread(*thread);
+
+    QObject obj;
+    QVERIFY(move_qobject_to_qthread(obj, *thread));
+    QCOMPARE(obj.thread(), thread.get());
+
+    // The object can only be moved from the thread it lives in
+    QVERIFY(!move_qobject_to_qthread(obj, *QThread::currentThread()));
+    QCOMPARE(obj.thread(), thread.get());
+
+    QVERIFY(stop_qthread(*thread));
+  }
+
+  void moveToThreadWithParent()
+  {
+    auto thread = construct_qthread();
+    start_qthread(*thread);
+
+    QObject parent;
+    QObject child(&parent);
+    QVERIFY(!move_qobject_to_qthread(child, *thread));
+    QCOMPARE(child.thread(), QThread::currentThread());
+
+    QVERIFY(stop_qthread(*thread));
+  }
+};
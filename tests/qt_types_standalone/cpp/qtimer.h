@@ -0,0 +1,59 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QTimer>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qtimer.cxx.h"
+
+class QTimerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    auto timer = construct_qtimer();
+    QVERIFY(timer != nullptr);
+    QVERIFY(!timer->isActive());
+  }
+
+  void configure()
+  {
+    auto timer = construct_qtimer();
+    configure_qtimer(*timer, 50, true);
+    QCOMPARE(timer->interval(), 50);
+    QVERIFY(timer->isSingleShot());
+    QCOMPARE(timer->timerType(), Qt::PreciseTimer);
+  }
+
+  void timeout()
+  {
+    const auto count = qtimer_timeout_count();
+    auto timer = construct_qtimer();
+    configure_qtimer(*timer, 0, true);
+    connect_qtimer_timeout(*timer);
+
+    start_qtimer(*timer, 10);
+    QVERIFY(timer->isActive());
+    QCOMPARE(timer->interval(), 10);
+
+    QTRY_COMPARE(qtimer_timeout_count(), count + 1);
+    QVERIFY(!timer->isActive());
+  }
+
+  void singleShot()
+  {
+    const auto count = qtimer_single_shot_count();
+    single_shot_qtimer(0);
+
+    // The closure is called from the event loop rather than immediately
+    QCOMPARE(qtimer_single_shot_count(), count);
+    QTRY_COMPARE(qtimer_single_shot_count(), count + 1);
+  }
+};
@@ -23,8 +23,10 @@ fn main() {
         .file("src/qmap.rs")
         .file("src/qmargins.rs")
         .file("src/qmarginsf.rs")
+        .file("src/qmetaobject.rs")
         .file("src/qmetaobjectconnection.rs")
         .file("src/qmodelindex.rs")
+        .file("src/qobject.rs")
         .file("src/qpen.rs")
         .file("src/qpersistentmodelindex.rs")
         .file("src/qpoint.rs")
@@ -41,7 +43,9 @@ fn main() {
         .file("src/qsizef.rs")
         .file("src/qstring.rs")
         .file("src/qstringlist.rs")
+        .file("src/qthread.rs")
         .file("src/qtime.rs")
+        .file("src/qtimer.rs")
         .file("src/qtimezone.rs")
         .file("src/qurl.rs")
         .file("src/qvariant.rs")
@@ -20,8 +20,10 @@ mod qlist;
 mod qmap;
 mod qmargins;
 mod qmarginsf;
+mod qmetaobject;
 mod qmetaobjectconnection;
 mod qmodelindex;
+mod qobject;
 mod qpen;
 mod qpersistentmodelindex;
 mod qpoint;
@@ -38,7 +40,9 @@ mod qsize;
 mod qsizef;
 mod qstring;
 mod qstringlist;
+mod qthread;
 mod qtime;
+mod qtimer;
 mod qtimezone;
 mod qurl;
 mod qvariant;
@@ -0,0 +1,126 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QList, QObject, QObjectExt, QString, QStringList, QVariant};
+
+#[cxx::bridge]
+mod qmetaobject_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+    }
+
+    extern "Rust" {
+        fn meta_object_class_name(obj: &QObject) -> QString;
+        fn meta_object_super_class_name(obj: &QObject) -> QString;
+        fn meta_object_class_info(obj: &QObject, key: &QString) -> QString;
+        fn meta_object_enum_key(obj: &QObject, name: &str, value: i32) -> QString;
+        fn meta_object_signal_signatures(obj: &QObject) -> QStringList;
+        fn meta_object_property_names(obj: &QObject) -> QStringList;
+        fn meta_object_property_notify_signal(obj: &QObject, name: &str) -> QString;
+        fn meta_object_inherits(obj: &QObject, other: &QObject) -> bool;
+        fn meta_property_read_int(obj: &QObject, name: &str) -> i32;
+        fn meta_property_write_int(obj: Pin<&mut QObject>, name: &str, value: i32) -> bool;
+        fn meta_property_read_other(owner: &QObject, obj: &QObject, name: &str) -> bool;
+        fn meta_property_write_other(
+            owner: &QObject,
+            obj: Pin<&mut QObject>,
+            name: &str,
+            value: i32,
+        ) -> bool;
+    }
+}
+
+fn meta_object_class_name(obj: &QObject) -> QString {
+    obj.meta_object().class_name()
+}
+
+fn meta_object_super_class_name(obj: &QObject) -> QString {
+    obj.meta_object()
+        .super_class()
+        .map(|super_class| super_class.class_name())
+        .unwrap_or_default()
+}
+
+fn meta_object_class_info(obj: &QObject, key: &QString) -> QString {
+    let meta_object = obj.meta_object();
+    (0..meta_object.class_info_count())
+        .filter_map(|index| meta_object.class_info(index))
+        .find_map(|(name, value)| (&name == key).then_some(value))
+        .unwrap_or_default()
+}
+
+fn meta_object_enum_key(obj: &QObject, name: &str, value: i32) -> QString {
+    let meta_object = obj.meta_object();
+    meta_object
+        .enumerator(meta_object.index_of_enumerator(name))
+        .and_then(|enumerator| enumerator.value_to_key(value))
+        .unwrap_or_default()
+}
+
+fn meta_object_signal_signatures(obj: &QObject) -> QStringList {
+    let mut signatures = QList::<QString>::default();
+    for signal in obj.meta_object().signals() {
+        signatures.append(signal.method_signature());
+    }
+    QStringList::from(&signatures)
+}
+
+fn meta_object_property_names(obj: &QObject) -> QStringList {
+    let mut names = QList::<QString>::default();
+    for property in obj.meta_object().properties() {
+        names.append(property.name());
+    }
+    QStringList::from(&names)
+}
+
+fn meta_object_property_notify_signal(obj: &QObject, name: &str) -> QString {
+    let meta_object = obj.meta_object();
+    meta_object
+        .property(meta_object.index_of_property(name))
+        .and_then(|property| property.notify_signal())
+        .map(|signal| signal.method_signature())
+        .unwrap_or_default()
+}
+
+fn meta_object_inherits(obj: &QObject, other: &QObject) -> bool {
+    obj.meta_object().inherits(other.meta_object())
+}
+
+fn meta_property_read_int(obj: &QObject, name: &str) -> i32 {
+    obj.read_property(name)
+        .and_then(|value| value.value::<i32>())
+        .unwrap_or(-1)
+}
+
+fn meta_property_write_int(obj: Pin<&mut QObject>, name: &str, value: i32) -> bool {
+    obj.write_property(name, &QVariant::from(&value))
+}
+
+fn meta_property_read_other(owner: &QObject, obj: &QObject, name: &str) -> bool {
+    let meta_object = owner.meta_object();
+    meta_object
+        .property(meta_object.index_of_property(name))
+        .and_then(|property| property.read(obj))
+        .is_some()
+}
+
+fn meta_property_write_other(
+    owner: &QObject,
+    obj: Pin<&mut QObject>,
+    name: &str,
+    value: i32,
+) -> bool {
+    let meta_object = owner.meta_object();
+    meta_object
+        .property(meta_object.index_of_property(name))
+        .is_some_and(|property| property.write(obj, &QVariant::from(&value)))
+}
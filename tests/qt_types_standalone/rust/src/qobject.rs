@@ -0,0 +1,164 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{
+    pin::Pin,
+    sync::atomic::{AtomicI32, Ordering},
+};
+use cxx_qt_lib::{
+    ConnectionType, QMetaObjectConnectionGuard, QObject, QObjectExt, QString, QVariant,
+};
+use std::sync::{Arc, Mutex};
+
+#[cxx::bridge]
+mod qobject_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        type DynamicConnection;
+
+        fn connect_dynamic_qobject(obj: Pin<&mut QObject>, signal: &str) -> Box<DynamicConnection>;
+        fn dynamic_connection_calls(connection: &DynamicConnection) -> i32;
+        fn dynamic_connection_last_name(connection: &DynamicConnection) -> QString;
+        fn disconnect_dynamic_connection(connection: &mut DynamicConnection);
+        fn release_dynamic_connection(connection: &mut DynamicConnection);
+        fn live_dynamic_slots() -> i32;
+        fn invoke_qobject_void_method(obj: Pin<&mut QObject>, name: &str) -> bool;
+        fn invoke_qobject_missing_method(obj: Pin<&mut QObject>) -> bool;
+        fn rename_qobject_children(obj: Pin<&mut QObject>, name: &QString);
+        fn rename_qobject_found_child(
+            obj: Pin<&mut QObject>,
+            name: &str,
+            new_name: &QString,
+        ) -> bool;
+        fn rename_qobject_found_children(
+            obj: Pin<&mut QObject>,
+            name: &str,
+            new_name: &QString,
+        ) -> usize;
+        fn qobject_parent_is(obj: &QObject, parent: &QObject) -> bool;
+    }
+}
+
+/// The number of closures connected with connect_dynamic_qobject which have not been dropped
+static LIVE_SLOTS: AtomicI32 = AtomicI32::new(0);
+
+/// Counts the closure it is captured by as live until it is dropped
+struct LiveSlot;
+
+impl LiveSlot {
+    fn new() -> Self {
+        LIVE_SLOTS.fetch_add(1, Ordering::Relaxed);
+        Self
+    }
+}
+
+impl Drop for LiveSlot {
+    fn drop(&mut self) {
+        LIVE_SLOTS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+pub struct DynamicConnection {
+    guard: Option<QMetaObjectConnectionGuard>,
+    names: Arc<Mutex<Vec<QString>>>,
+}
+
+fn connect_dynamic_qobject(obj: Pin<&mut QObject>, signal: &str) -> Box<DynamicConnection> {
+    let names = Arc::new(Mutex::new(vec![]));
+    let guard = obj.connect_dynamic(
+        signal,
+        {
+            let names = names.clone();
+            let live_slot = LiveSlot::new();
+            move |args: &[QVariant]| {
+                let _ = &live_slot;
+                let name = args
+                    .first()
+                    .and_then(QVariant::value::<QString>)
+                    .unwrap_or_default();
+                names.lock().unwrap().push(name);
+            }
+        },
+        ConnectionType::AutoConnection,
+    );
+
+    Box::new(DynamicConnection {
+        guard: Some(guard),
+        names,
+    })
+}
+
+fn dynamic_connection_calls(connection: &DynamicConnection) -> i32 {
+    connection.names.lock().unwrap().len() as i32
+}
+
+fn dynamic_connection_last_name(connection: &DynamicConnection) -> QString {
+    connection
+        .names
+        .lock()
+        .unwrap()
+        .last()
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn disconnect_dynamic_connection(connection: &mut DynamicConnection) {
+    connection.guard.take();
+}
+
+fn release_dynamic_connection(connection: &mut DynamicConnection) {
+    if let Some(guard) = connection.guard.take() {
+        guard.release();
+    }
+}
+
+fn live_dynamic_slots() -> i32 {
+    LIVE_SLOTS.load(Ordering::Relaxed)
+}
+
+fn invoke_qobject_void_method(obj: Pin<&mut QObject>, name: &str) -> bool {
+    // Methods which return nothing result in an invalid variant
+    obj.invoke_method(name, &[])
+        .is_some_and(|result| !result.is_valid())
+}
+
+fn invoke_qobject_missing_method(obj: Pin<&mut QObject>) -> bool {
+    obj.invoke_method("notAMethod", &[QVariant::from(&1_i32)])
+        .is_none()
+}
+
+fn rename_qobject_children(obj: Pin<&mut QObject>, name: &QString) {
+    for child in obj.children_mut() {
+        child.set_object_name(name);
+    }
+}
+
+fn rename_qobject_found_child(obj: Pin<&mut QObject>, name: &str, new_name: &QString) -> bool {
+    if let Some(child) = obj.find_child_mut(name) {
+        child.set_object_name(new_name);
+        true
+    } else {
+        false
+    }
+}
+
+fn rename_qobject_found_children(obj: Pin<&mut QObject>, name: &str, new_name: &QString) -> usize {
+    let children = obj.find_children_mut(name);
+    let count = children.len();
+    for child in children {
+        child.set_object_name(new_name);
+    }
+    count
+}
+
+fn qobject_parent_is(obj: &QObject, parent: &QObject) -> bool {
+    core::ptr::eq(obj.parent(), parent)
+}
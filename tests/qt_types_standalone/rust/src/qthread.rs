@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QObject, QObjectExt, QThread};
+
+#[cxx::bridge]
+mod qthread_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qthread.h");
+        type QThread = cxx_qt_lib::QThread;
+
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+    }
+
+    extern "Rust" {
+        fn construct_qthread() -> UniquePtr<QThread>;
+        fn is_current_qthread(thread: &QThread) -> bool;
+        fn start_qthread(thread: Pin<&mut QThread>);
+        fn stop_qthread(thread: Pin<&mut QThread>) -> bool;
+        fn move_qobject_to_qthread(obj: Pin<&mut QObject>, thread: Pin<&mut QThread>) -> bool;
+    }
+}
+
+fn construct_qthread() -> cxx::UniquePtr<QThread> {
+    QThread::new()
+}
+
+fn is_current_qthread(thread: &QThread) -> bool {
+    core::ptr::eq(QThread::current_thread(), thread)
+}
+
+fn start_qthread(thread: Pin<&mut QThread>) {
+    thread.start();
+}
+
+fn stop_qthread(mut thread: Pin<&mut QThread>) -> bool {
+    thread.as_mut().quit();
+    thread.wait()
+}
+
+fn move_qobject_to_qthread(obj: Pin<&mut QObject>, thread: Pin<&mut QThread>) -> bool {
+    // SAFETY: the C++ test only accesses the object again once the thread has stopped
+    unsafe { obj.move_to_thread(thread) }
+}
@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{
+    pin::Pin,
+    sync::atomic::{AtomicI32, Ordering},
+};
+use cxx_qt_lib::{QTimer, TimerType};
+use std::time::Duration;
+
+#[cxx::bridge]
+mod qtimer_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtimer.h");
+        type QTimer = cxx_qt_lib::QTimer;
+    }
+
+    extern "Rust" {
+        fn construct_qtimer() -> UniquePtr<QTimer>;
+        fn configure_qtimer(timer: Pin<&mut QTimer>, msec: i32, single_shot: bool);
+        fn connect_qtimer_timeout(timer: Pin<&mut QTimer>);
+        fn start_qtimer(timer: Pin<&mut QTimer>, msec: i32);
+        fn qtimer_timeout_count() -> i32;
+        fn single_shot_qtimer(msec: i32);
+        fn qtimer_single_shot_count() -> i32;
+    }
+}
+
+/// The number of timeouts of all timers connected with connect_qtimer_timeout
+static TIMEOUT_COUNT: AtomicI32 = AtomicI32::new(0);
+
+/// The number of closures which have been called by single_shot_qtimer
+static SINGLE_SHOT_COUNT: AtomicI32 = AtomicI32::new(0);
+
+fn construct_qtimer() -> cxx::UniquePtr<QTimer> {
+    QTimer::new()
+}
+
+fn configure_qtimer(mut timer: Pin<&mut QTimer>, msec: i32, single_shot: bool) {
+    timer.as_mut().set_interval(msec);
+    timer.as_mut().set_single_shot(single_shot);
+    timer.set_timer_type(TimerType::PreciseTimer);
+}
+
+fn connect_qtimer_timeout(timer: Pin<&mut QTimer>) {
+    timer
+        .on_timeout(|_| {
+            TIMEOUT_COUNT.fetch_add(1, Ordering::Relaxed);
+        })
+        .release();
+}
+
+fn start_qtimer(timer: Pin<&mut QTimer>, msec: i32) {
+    timer.start_msec(msec);
+}
+
+fn qtimer_timeout_count() -> i32 {
+    TIMEOUT_COUNT.load(Ordering::Relaxed)
+}
+
+fn single_shot_qtimer(msec: i32) {
+    QTimer::single_shot(Duration::from_millis(msec as u64), || {
+        SINGLE_SHOT_COUNT.fetch_add(1, Ordering::Relaxed);
+    });
+}
+
+fn qtimer_single_shot_count() -> i32 {
+    SINGLE_SHOT_COUNT.load(Ordering::Relaxed)
+}